@@ -109,4 +109,66 @@ module 0x1::Bench {
             i = i + 1;
         }
     }
+
+    //
+    // `generics` benchmark
+    //
+    struct Box<T> has copy, drop { value: T }
+
+    struct Pair<T1, T2> has copy, drop { first: T1, second: T2 }
+
+    fun id<T>(x: T): T {
+        x
+    }
+
+    fun box_up<T>(value: T): Box<T> {
+        Box { value: id(value) }
+    }
+
+    fun unbox<T>(b: Box<T>): T {
+        let Box { value } = b;
+        value
+    }
+
+    fun swap<T1, T2>(p: Pair<T1, T2>): Pair<T2, T1> {
+        let Pair { first, second } = p;
+        Pair { first: id(second), second: id(first) }
+    }
+
+    fun fill<T: copy + drop>(x: T, n: u64): vector<Box<T>> {
+        let v = vector::empty();
+        let i = 0;
+        while (i < n) {
+            vector::push_back(&mut v, box_up(x));
+            i = i + 1;
+        };
+        v
+    }
+
+    fun drain<T: drop>(v: vector<Box<T>>): u64 {
+        let n = 0;
+        while (!vector::is_empty(&v)) {
+            unbox(vector::pop_back(&mut v));
+            n = n + 1;
+        };
+        n
+    }
+
+    fun test_generics<T1: copy + drop, T2: copy + drop>(x1: T1, x2: T2) {
+        let Pair { first, second } = swap(swap(Pair { first: box_up(x1), second: box_up(x2) }));
+        check(drain(fill(unbox(first), 8)) == 8, 300);
+        check(drain(fill(unbox(second), 8)) == 8, 301);
+    }
+
+    public fun generics() {
+        let i = 0;
+        // 100 is the number of loops to make the benchmark run for a couple of minutes, which is an eternity.
+        // Adjust according to your needs, it's just a reference
+        while (i < 100) {
+            test_generics<u64, bool>(1, true);
+            test_generics<address, vector<u8>>(@0x1, vector::empty());
+            test_generics<Box<u8>, Pair<u64, u128>>(box_up(1u8), Pair { first: 1, second: 2 });
+            i = i + 1;
+        }
+    }
 }
@@ -184,12 +184,16 @@ impl<'r, 'l, S: MoveResolver> AsyncSession<'r, 'l, S> {
             .vm_session
             .load_type(&state_type_tag)
             .map_err(vm_error_to_async)?;
+        let state_layout = self
+            .vm_session
+            .get_type_layout(&state_type_tag)
+            .map_err(vm_error_to_async)?;
 
         // Check whether the actor state already exists.
         let state = self
             .vm_session
             .get_data_store()
-            .load_resource(actor_addr, &state_type)
+            .load_resource(actor_addr, &state_type, &state_layout)
             .map(|(gv, _)| gv)
             .map_err(partial_vm_error_to_async)?;
         if state.exists().map_err(partial_vm_error_to_async)? {
@@ -280,11 +284,15 @@ impl<'r, 'l, S: MoveResolver> AsyncSession<'r, 'l, S> {
             .vm_session
             .load_type(&state_type_tag)
             .map_err(vm_error_to_async)?;
+        let state_layout = self
+            .vm_session
+            .get_type_layout(&state_type_tag)
+            .map_err(vm_error_to_async)?;
 
         let actor_state_global = self
             .vm_session
             .get_data_store()
-            .load_resource(actor_addr, &state_type)
+            .load_resource(actor_addr, &state_type, &state_layout)
             .map(|(gv, _)| gv)
             .map_err(partial_vm_error_to_async)?;
         let actor_state = actor_state_global
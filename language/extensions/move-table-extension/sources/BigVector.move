@@ -0,0 +1,140 @@
+/// A vector whose elements are split into buckets of a fixed size, each stored as an entry of a
+/// `Table`, so that updating an element only reads and writes the bucket holding it rather than
+/// the whole vector.
+module extensions::big_vector {
+    use std::errors;
+    use std::vector;
+    use extensions::table::{Self, Table};
+
+    const EINDEX_OUT_OF_BOUNDS: u64 = 1;
+    const ENOT_EMPTY: u64 = 2;
+    const EZERO_BUCKET_SIZE: u64 = 3;
+
+    /// Type of big vectors
+    struct BigVector<T> has store {
+        /// The buckets, by index. All buckets are full, except the last one which is not empty.
+        buckets: Table<u64, vector<T>>,
+        /// The number of elements.
+        length: u64,
+        /// The number of elements in a full bucket.
+        bucket_size: u64,
+    }
+
+    /// Create an empty big vector, storing `bucket_size` elements per bucket.
+    /// Aborts if `bucket_size` is zero.
+    public fun empty<T: store>(bucket_size: u64): BigVector<T> {
+        assert!(bucket_size > 0, errors::invalid_argument(EZERO_BUCKET_SIZE));
+        BigVector {
+            buckets: table::new(),
+            length: 0,
+            bucket_size,
+        }
+    }
+
+    /// Create a big vector holding `e`, storing `bucket_size` elements per bucket.
+    /// Aborts if `bucket_size` is zero.
+    public fun singleton<T: store>(e: T, bucket_size: u64): BigVector<T> {
+        let v = empty(bucket_size);
+        push_back(&mut v, e);
+        v
+    }
+
+    /// Destroy a big vector. The vector must be empty to succeed.
+    public fun destroy_empty<T>(v: BigVector<T>) {
+        assert!(v.length == 0, errors::invalid_state(ENOT_EMPTY));
+        let BigVector { buckets, length: _, bucket_size: _ } = v;
+        table::destroy_empty(buckets)
+    }
+
+    /// Add `e` at the end of `v`.
+    public fun push_back<T: store>(v: &mut BigVector<T>, e: T) {
+        let bucket_index = v.length / v.bucket_size;
+        if (bucket_index == table::length(&v.buckets)) {
+            table::add(&mut v.buckets, bucket_index, vector::singleton(e));
+        } else {
+            vector::push_back(table::borrow_mut(&mut v.buckets, bucket_index), e);
+        };
+        v.length = v.length + 1;
+    }
+
+    /// Remove and return the last element of `v`.
+    /// Aborts if `v` is empty.
+    public fun pop_back<T>(v: &mut BigVector<T>): T {
+        assert!(v.length > 0, errors::invalid_argument(EINDEX_OUT_OF_BOUNDS));
+        let bucket_index = (v.length - 1) / v.bucket_size;
+        let e = vector::pop_back(table::borrow_mut(&mut v.buckets, bucket_index));
+        if (vector::is_empty(table::borrow(&v.buckets, bucket_index))) {
+            vector::destroy_empty(table::remove(&mut v.buckets, bucket_index));
+        };
+        v.length = v.length - 1;
+        e
+    }
+
+    /// Acquire an immutable reference to the `i`-th element of `v`.
+    /// Aborts if `i` is out of bounds.
+    public fun borrow<T>(v: &BigVector<T>, i: u64): &T {
+        assert!(i < v.length, errors::invalid_argument(EINDEX_OUT_OF_BOUNDS));
+        vector::borrow(table::borrow(&v.buckets, i / v.bucket_size), i % v.bucket_size)
+    }
+
+    /// Acquire a mutable reference to the `i`-th element of `v`.
+    /// Aborts if `i` is out of bounds.
+    public fun borrow_mut<T>(v: &mut BigVector<T>, i: u64): &mut T {
+        assert!(i < v.length, errors::invalid_argument(EINDEX_OUT_OF_BOUNDS));
+        let bucket_size = v.bucket_size;
+        vector::borrow_mut(table::borrow_mut(&mut v.buckets, i / bucket_size), i % bucket_size)
+    }
+
+    /// Swap the `i`-th and `j`-th elements of `v`.
+    /// Aborts if `i` or `j` is out of bounds.
+    public fun swap<T>(v: &mut BigVector<T>, i: u64, j: u64) {
+        assert!(i < v.length && j < v.length, errors::invalid_argument(EINDEX_OUT_OF_BOUNDS));
+        let (i_bucket_index, j_bucket_index) = (i / v.bucket_size, j / v.bucket_size);
+        let (i_offset, j_offset) = (i % v.bucket_size, j % v.bucket_size);
+        if (i_bucket_index == j_bucket_index) {
+            vector::swap(table::borrow_mut(&mut v.buckets, i_bucket_index), i_offset, j_offset);
+            return
+        };
+        // Both buckets cannot be borrowed at once, so take the bucket of `j` out of the table,
+        // and exchange the elements through the ends of the buckets.
+        let j_bucket = table::remove(&mut v.buckets, j_bucket_index);
+        let j_last = vector::length(&j_bucket) - 1;
+        vector::swap(&mut j_bucket, j_offset, j_last);
+        let i_bucket = table::borrow_mut(&mut v.buckets, i_bucket_index);
+        vector::push_back(i_bucket, vector::pop_back(&mut j_bucket));
+        let i_last = vector::length(i_bucket) - 1;
+        vector::swap(i_bucket, i_offset, i_last);
+        vector::push_back(&mut j_bucket, vector::pop_back(i_bucket));
+        vector::swap(&mut j_bucket, j_offset, j_last);
+        table::add(&mut v.buckets, j_bucket_index, j_bucket);
+    }
+
+    /// Remove and return the `i`-th element of `v`, replacing it with the last element.
+    /// This is O(1), but does not preserve the order of the elements.
+    /// Aborts if `i` is out of bounds.
+    public fun swap_remove<T>(v: &mut BigVector<T>, i: u64): T {
+        assert!(i < v.length, errors::invalid_argument(EINDEX_OUT_OF_BOUNDS));
+        let last = pop_back(v);
+        if (i == v.length) {
+            return last
+        };
+        let bucket = table::borrow_mut(&mut v.buckets, i / v.bucket_size);
+        vector::push_back(bucket, last);
+        vector::swap_remove(bucket, i % v.bucket_size)
+    }
+
+    /// Returns the length of `v`, i.e. the number of elements.
+    public fun length<T>(v: &BigVector<T>): u64 {
+        v.length
+    }
+
+    /// Returns true if `v` is empty.
+    public fun is_empty<T>(v: &BigVector<T>): bool {
+        v.length == 0
+    }
+
+    /// Returns the number of elements stored per bucket of `v`.
+    public fun bucket_size<T>(v: &BigVector<T>): u64 {
+        v.bucket_size
+    }
+}
@@ -0,0 +1,156 @@
+#[test_only]
+module extensions::big_vector_tests {
+    use std::vector;
+    use extensions::big_vector::{Self as V, BigVector};
+
+    struct S<T> has key {
+        v: BigVector<T>
+    }
+
+    fun range(n: u64, bucket_size: u64): BigVector<u64> {
+        let v = V::empty(bucket_size);
+        let i = 0;
+        while (i < n) {
+            V::push_back(&mut v, i);
+            i = i + 1;
+        };
+        v
+    }
+
+    fun destroy(v: BigVector<u64>) {
+        while (!V::is_empty(&v)) {
+            V::pop_back(&mut v);
+        };
+        V::destroy_empty(v)
+    }
+
+    #[test]
+    fun push_back_and_borrow() {
+        let v = range(10, 3);
+        assert!(V::length(&v) == 10, 0);
+        assert!(V::bucket_size(&v) == 3, 1);
+        let i = 0;
+        while (i < 10) {
+            assert!(*V::borrow(&v, i) == i, 100 + i);
+            i = i + 1;
+        };
+        destroy(v)
+    }
+
+    #[test]
+    fun borrow_mut_updates() {
+        let v = range(7, 2);
+        *V::borrow_mut(&mut v, 0) = 100;
+        *V::borrow_mut(&mut v, 5) = 105;
+        assert!(*V::borrow(&v, 0) == 100, 0);
+        assert!(*V::borrow(&v, 1) == 1, 1);
+        assert!(*V::borrow(&v, 5) == 105, 2);
+        assert!(*V::borrow(&v, 6) == 6, 3);
+        destroy(v)
+    }
+
+    #[test]
+    fun pop_back_across_buckets() {
+        let v = range(5, 2);
+        assert!(V::pop_back(&mut v) == 4, 0);
+        assert!(V::pop_back(&mut v) == 3, 1);
+        assert!(V::pop_back(&mut v) == 2, 2);
+        assert!(V::length(&v) == 2, 3);
+        // Pushing after popping reuses the freed buckets.
+        V::push_back(&mut v, 12);
+        V::push_back(&mut v, 13);
+        assert!(*V::borrow(&v, 2) == 12, 4);
+        assert!(*V::borrow(&v, 3) == 13, 5);
+        assert!(V::pop_back(&mut v) == 13, 6);
+        assert!(V::pop_back(&mut v) == 12, 7);
+        assert!(V::pop_back(&mut v) == 1, 8);
+        assert!(V::pop_back(&mut v) == 0, 9);
+        V::destroy_empty(v)
+    }
+
+    #[test]
+    fun swap_remove_elements() {
+        let v = range(7, 3);
+        // The last element replaces the removed one.
+        assert!(V::swap_remove(&mut v, 1) == 1, 0);
+        assert!(*V::borrow(&v, 1) == 6, 1);
+        assert!(V::length(&v) == 6, 2);
+        // Removing the last element only pops it.
+        assert!(V::swap_remove(&mut v, 5) == 5, 3);
+        assert!(V::length(&v) == 5, 4);
+        // Within the last bucket.
+        assert!(V::swap_remove(&mut v, 3) == 3, 5);
+        assert!(*V::borrow(&v, 3) == 4, 6);
+        assert!(V::length(&v) == 4, 7);
+        assert!(*V::borrow(&v, 0) == 0, 8);
+        assert!(*V::borrow(&v, 2) == 2, 9);
+        destroy(v)
+    }
+
+    #[test]
+    fun swap_elements() {
+        let v = range(8, 3);
+        // Within a bucket.
+        V::swap(&mut v, 0, 2);
+        assert!(*V::borrow(&v, 0) == 2 && *V::borrow(&v, 2) == 0, 0);
+        // Across buckets, both ways.
+        V::swap(&mut v, 1, 7);
+        assert!(*V::borrow(&v, 1) == 7 && *V::borrow(&v, 7) == 1, 1);
+        V::swap(&mut v, 6, 4);
+        assert!(*V::borrow(&v, 6) == 4 && *V::borrow(&v, 4) == 6, 2);
+        V::swap(&mut v, 3, 3);
+        let expected = vector[2u8, 7u8, 0u8, 3u8, 6u8, 5u8, 4u8, 1u8];
+        let i = 0;
+        while (i < 8) {
+            assert!(*V::borrow(&v, i) == (*vector::borrow(&expected, i) as u64), 100 + i);
+            i = i + 1;
+        };
+        destroy(v)
+    }
+
+    #[test(s = @0x42)]
+    fun store_in_resource(s: signer) acquires S {
+        move_to(&s, S { v: V::singleton(@0x1, 2) });
+        let v = &mut borrow_global_mut<S<address>>(@0x42).v;
+        V::push_back(v, @0x2);
+        V::push_back(v, @0x3);
+        assert!(V::length(v) == 3, 0);
+        assert!(*V::borrow(&borrow_global<S<address>>(@0x42).v, 2) == @0x3, 1);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 263)]
+    fun borrow_out_of_bounds() {
+        let v = range(3, 2);
+        V::borrow(&v, 3);
+        destroy(v)
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 263)]
+    fun pop_back_empty() {
+        let v = V::empty<u64>(2);
+        V::pop_back(&mut v);
+        V::destroy_empty(v)
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 263)]
+    fun swap_out_of_bounds() {
+        let v = range(3, 2);
+        V::swap(&mut v, 0, 3);
+        destroy(v)
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 513)]
+    fun destroy_non_empty() {
+        V::destroy_empty(range(1, 2))
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 775)]
+    fun zero_bucket_size() {
+        V::destroy_empty(V::empty<u64>(0))
+    }
+}
@@ -3,11 +3,12 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    file_format::{AbilitySet, StructTypeParameter, Visibility},
+    file_format::{Ability, AbilitySet, StructTypeParameter, Visibility},
     file_format_common::VERSION_5,
     normalized::Module,
 };
-use std::collections::BTreeSet;
+use move_core_types::{identifier::Identifier, language_storage::ModuleId};
+use std::{collections::BTreeSet, fmt};
 
 /// The result of a linking and layout compatibility check. Here is what the different combinations
 /// mean:
@@ -77,12 +78,31 @@ impl Compatibility {
         old_module: &Module,
         new_module: &Module,
     ) -> Compatibility {
-        let mut struct_and_function_linking = true;
-        let mut struct_layout = true;
+        let incompatibilities =
+            Self::incompatibilities(check_friend_linking, old_module, new_module);
+        Compatibility {
+            struct_and_function_linking: !incompatibilities
+                .iter()
+                .any(Incompatibility::breaks_linking),
+            struct_layout: !incompatibilities.iter().any(Incompatibility::breaks_layout),
+        }
+    }
+
+    /// Return every reason for `new_module` not to be backward compatible with the old module
+    /// `old_module`. The update is fully compatible if the result is empty.
+    pub fn incompatibilities(
+        check_friend_linking: bool,
+        old_module: &Module,
+        new_module: &Module,
+    ) -> Vec<Incompatibility> {
+        let mut incompatibilities = vec![];
 
         // module's name and address are unchanged
         if old_module.address != new_module.address || old_module.name != new_module.name {
-            struct_and_function_linking = false;
+            incompatibilities.push(Incompatibility::ModuleIdChanged {
+                old: old_module.module_id(),
+                new: new_module.module_id(),
+            });
         }
 
         // old module's structs are a subset of the new module's structs
@@ -93,19 +113,27 @@ impl Compatibility {
                     // Struct not present in new . Existing modules that depend on this struct will fail to link with the new version of the module.
                     // Also, struct layout cannot be guaranteed transitively, because after
                     // removing the struct, it could be re-added later with a different layout.
-                    struct_and_function_linking = false;
-                    struct_layout = false;
-                    break;
+                    incompatibilities.push(Incompatibility::StructRemoved { name: name.clone() });
+                    continue;
                 }
             };
 
-            if !struct_abilities_compatibile(old_struct.abilities, new_struct.abilities)
-                || !struct_type_parameters_compatibile(
-                    &old_struct.type_parameters,
-                    &new_struct.type_parameters,
-                )
-            {
-                struct_and_function_linking = false;
+            if !struct_abilities_compatibile(old_struct.abilities, new_struct.abilities) {
+                incompatibilities.push(Incompatibility::StructAbilitiesRemoved {
+                    name: name.clone(),
+                    abilities: old_struct
+                        .abilities
+                        .into_iter()
+                        .filter(|ability| !new_struct.abilities.has_ability(*ability))
+                        .collect(),
+                });
+            }
+            if !struct_type_parameters_compatibile(
+                &old_struct.type_parameters,
+                &new_struct.type_parameters,
+            ) {
+                incompatibilities
+                    .push(Incompatibility::StructTypeParametersChanged { name: name.clone() });
             }
             if new_struct.fields != old_struct.fields {
                 // Fields changed. Code in this module will fail at runtime if it tries to
@@ -114,7 +142,7 @@ impl Compatibility {
                 // choose that changing the name (but not position or type) of a field is
                 // compatible. The VM does not care about the name of a field
                 // (it's purely informational), but clients presumably do.
-                struct_layout = false
+                incompatibilities.push(Incompatibility::StructLayoutChanged { name: name.clone() });
             }
         }
 
@@ -139,7 +167,7 @@ impl Compatibility {
             let new_func = match new_module.exposed_functions.get(name) {
                 Some(new_func) => new_func,
                 None => {
-                    struct_and_function_linking = false;
+                    incompatibilities.push(Incompatibility::FunctionRemoved { name: name.clone() });
                     continue;
                 }
             };
@@ -165,16 +193,29 @@ impl Compatibility {
                 // If it was not an entry function, it is allowed to become one.
                 !old_func.is_entry || new_func.is_entry
             };
-            if !is_vis_compatible
-                || !is_entry_compatible
-                || old_func.parameters != new_func.parameters
-                || old_func.return_ != new_func.return_
-                || !fun_type_parameters_compatibile(
-                    &old_func.type_parameters,
-                    &new_func.type_parameters,
-                )
-            {
-                struct_and_function_linking = false;
+            if !is_vis_compatible {
+                incompatibilities.push(Incompatibility::FunctionVisibilityChanged {
+                    name: name.clone(),
+                    old: old_func.visibility,
+                    new: new_func.visibility,
+                });
+            }
+            if !is_entry_compatible {
+                incompatibilities.push(Incompatibility::FunctionEntryChanged {
+                    name: name.clone(),
+                    was_entry: old_func.is_entry,
+                });
+            }
+            if old_func.parameters != new_func.parameters || old_func.return_ != new_func.return_ {
+                incompatibilities
+                    .push(Incompatibility::FunctionSignatureChanged { name: name.clone() });
+            }
+            if !fun_type_parameters_compatibile(
+                &old_func.type_parameters,
+                &new_func.type_parameters,
+            ) {
+                incompatibilities
+                    .push(Incompatibility::FunctionTypeParametersChanged { name: name.clone() });
             }
         }
 
@@ -186,18 +227,141 @@ impl Compatibility {
             //
             let old_friend_module_ids: BTreeSet<_> = old_module.friends.iter().cloned().collect();
             let new_friend_module_ids: BTreeSet<_> = new_module.friends.iter().cloned().collect();
-            if !old_friend_module_ids.is_subset(&new_friend_module_ids) {
-                struct_and_function_linking = false;
+            for friend in old_friend_module_ids.difference(&new_friend_module_ids) {
+                incompatibilities.push(Incompatibility::FriendRemoved {
+                    module: friend.clone(),
+                });
             }
         }
 
-        Compatibility {
-            struct_and_function_linking,
-            struct_layout,
+        incompatibilities
+    }
+}
+
+/// A reason for a module update not to be backward compatible, naming the offending item.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Incompatibility {
+    /// The address or the name of the module changed.
+    ModuleIdChanged { old: ModuleId, new: ModuleId },
+    /// A struct was removed.
+    StructRemoved { name: Identifier },
+    /// Some abilities of a struct were removed.
+    StructAbilitiesRemoved {
+        name: Identifier,
+        abilities: Vec<Ability>,
+    },
+    /// The number, the phantom declarations or the constraints of the type parameters of a struct
+    /// changed in an incompatible way.
+    StructTypeParametersChanged { name: Identifier },
+    /// The fields of a struct changed.
+    StructLayoutChanged { name: Identifier },
+    /// A public, friend or entry function was removed.
+    FunctionRemoved { name: Identifier },
+    /// The visibility of a function was restricted.
+    FunctionVisibilityChanged {
+        name: Identifier,
+        old: Visibility,
+        new: Visibility,
+    },
+    /// A function stopped being an entry function, or became one in a module of a file format
+    /// version where this is not allowed.
+    FunctionEntryChanged { name: Identifier, was_entry: bool },
+    /// The parameter or return types of a function changed.
+    FunctionSignatureChanged { name: Identifier },
+    /// The number or the constraints of the type parameters of a function changed in an
+    /// incompatible way.
+    FunctionTypeParametersChanged { name: Identifier },
+    /// A friend declaration was removed.
+    FriendRemoved { module: ModuleId },
+}
+
+impl Incompatibility {
+    /// Return true if dependent modules may not link against the new module.
+    pub fn breaks_linking(&self) -> bool {
+        !matches!(self, Self::StructLayoutChanged { .. })
+    }
+
+    /// Return true if structs previously published by the module may not be read anymore.
+    pub fn breaks_layout(&self) -> bool {
+        matches!(
+            self,
+            Self::StructRemoved { .. } | Self::StructLayoutChanged { .. }
+        )
+    }
+
+    /// Return true if this incompatibility is rejected by the checks enabled in `config`.
+    pub fn is_checked_by(&self, config: &CompatibilityConfig) -> bool {
+        (config.check_struct_and_function_linking && self.breaks_linking())
+            || (config.check_struct_layout && self.breaks_layout())
+    }
+}
+
+impl fmt::Display for Incompatibility {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ModuleIdChanged { old, new } => {
+                write!(f, "module `{}` was renamed to `{}`", old, new)
+            }
+            Self::StructRemoved { name } => write!(f, "struct `{}` was removed", name),
+            Self::StructAbilitiesRemoved { name, abilities } => write!(
+                f,
+                "abilities {} were removed from struct `{}`",
+                abilities
+                    .iter()
+                    .map(|ability| ability_name(*ability))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                name
+            ),
+            Self::StructTypeParametersChanged { name } => {
+                write!(f, "type parameters of struct `{}` changed", name)
+            }
+            Self::StructLayoutChanged { name } => {
+                write!(f, "fields of struct `{}` changed", name)
+            }
+            Self::FunctionRemoved { name } => write!(f, "function `{}` was removed", name),
+            Self::FunctionVisibilityChanged { name, old, new } => write!(
+                f,
+                "visibility of function `{}` changed from {} to {}",
+                name,
+                visibility_name(*old),
+                visibility_name(*new)
+            ),
+            Self::FunctionEntryChanged { name, was_entry } => {
+                if *was_entry {
+                    write!(f, "function `{}` is no longer an entry function", name)
+                } else {
+                    write!(f, "function `{}` became an entry function", name)
+                }
+            }
+            Self::FunctionSignatureChanged { name } => {
+                write!(f, "signature of function `{}` changed", name)
+            }
+            Self::FunctionTypeParametersChanged { name } => {
+                write!(f, "type parameters of function `{}` changed", name)
+            }
+            Self::FriendRemoved { module } => write!(f, "friend `{}` was removed", module),
         }
     }
 }
 
+fn ability_name(ability: Ability) -> &'static str {
+    match ability {
+        Ability::Copy => "copy",
+        Ability::Drop => "drop",
+        Ability::Store => "store",
+        Ability::Key => "key",
+    }
+}
+
+fn visibility_name(visibility: Visibility) -> &'static str {
+    match visibility {
+        Visibility::Private => "private",
+        Visibility::Public => "public",
+        Visibility::Friend => "friend",
+    }
+}
+
 // When upgrading, the new abilities must be a superset of the old abilities.
 // Adding an ability is fine, but removing an ability could cause existing usages to fail.
 fn struct_abilities_compatibile(old_abilities: AbilitySet, new_abilities: AbilitySet) -> bool {
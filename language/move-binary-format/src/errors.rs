@@ -46,6 +46,7 @@ pub struct VMError {
     major_status: StatusCode,
     sub_status: Option<u64>,
     message: Option<String>,
+    abort_message: Option<String>,
     exec_state: Option<ExecutionState>,
     location: Location,
     indices: Vec<(IndexKind, TableIndex)>,
@@ -134,6 +135,11 @@ impl VMError {
         self.message.as_ref()
     }
 
+    /// The message attached to a Move abort, e.g. by `std::error::abort_with_message`.
+    pub fn abort_message(&self) -> Option<&String> {
+        self.abort_message.as_ref()
+    }
+
     pub fn exec_state(&self) -> Option<&ExecutionState> {
         self.exec_state.as_ref()
     }
@@ -177,6 +183,7 @@ impl VMError {
             location,
             indices,
             offsets,
+            ..
         } = self;
         (
             major_status,
@@ -194,6 +201,7 @@ impl VMError {
             major_status,
             sub_status,
             message,
+            abort_message,
             exec_state,
             indices,
             offsets,
@@ -203,6 +211,7 @@ impl VMError {
             major_status,
             sub_status,
             message,
+            abort_message,
             exec_state,
             indices,
             offsets,
@@ -217,6 +226,7 @@ pub struct PartialVMError {
     major_status: StatusCode,
     sub_status: Option<u64>,
     message: Option<String>,
+    abort_message: Option<String>,
     exec_state: Option<ExecutionState>,
     indices: Vec<(IndexKind, TableIndex)>,
     offsets: Vec<(FunctionDefinitionIndex, CodeOffset)>,
@@ -240,6 +250,7 @@ impl PartialVMError {
             exec_state,
             indices,
             offsets,
+            ..
         } = self;
         (
             major_status,
@@ -256,6 +267,7 @@ impl PartialVMError {
             major_status,
             sub_status,
             message,
+            abort_message,
             exec_state,
             indices,
             offsets,
@@ -264,6 +276,7 @@ impl PartialVMError {
             major_status,
             sub_status,
             message,
+            abort_message,
             exec_state,
             location,
             indices,
@@ -276,6 +289,7 @@ impl PartialVMError {
             major_status,
             sub_status: None,
             message: None,
+            abort_message: None,
             exec_state: None,
             indices: vec![],
             offsets: vec![],
@@ -302,6 +316,18 @@ impl PartialVMError {
         }
     }
 
+    pub fn with_abort_message(self, abort_message: String) -> Self {
+        debug_assert!(self.abort_message.is_none());
+        Self {
+            abort_message: Some(abort_message),
+            ..self
+        }
+    }
+
+    pub fn abort_message(&self) -> Option<&String> {
+        self.abort_message.as_ref()
+    }
+
     pub fn with_exec_state(self, exec_state: ExecutionState) -> Self {
         debug_assert!(self.exec_state.is_none());
         Self {
@@ -383,6 +409,10 @@ impl fmt::Display for PartialVMError {
             status = format!("{} and message {}", status, msg);
         }
 
+        if let Some(msg) = &self.abort_message {
+            status = format!("{} and abort message {:?}", status, msg);
+        }
+
         for (kind, index) in &self.indices {
             status = format!("{} at index {} for {}", status, index, kind);
         }
@@ -411,6 +441,10 @@ impl fmt::Display for VMError {
             status = format!("{} and message {}", status, msg);
         }
 
+        if let Some(msg) = &self.abort_message {
+            status = format!("{} and abort message {:?}", status, msg);
+        }
+
         for (kind, index) in &self.indices {
             status = format!("{} at index {} for {}", status, index, kind);
         }
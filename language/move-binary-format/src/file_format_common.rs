@@ -136,7 +136,7 @@ pub enum SerializedNativeStructFlag {
 #[rustfmt::skip]
 #[allow(non_camel_case_types)]
 #[repr(u8)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum Opcodes {
     POP                         = 0x01,
     RET                         = 0x02,
@@ -525,8 +525,13 @@ pub(crate) use versioned_data::{VersionedBinary, VersionedCursor};
 /// The encoding of the instruction is the serialized form of it, but disregarding the
 /// serialization of the instruction's argument(s).
 pub fn instruction_key(instruction: &Bytecode) -> u8 {
+    instruction_opcode(instruction) as u8
+}
+
+/// The opcode of the instruction.
+pub fn instruction_opcode(instruction: &Bytecode) -> Opcodes {
     use Bytecode::*;
-    let opcode = match instruction {
+    match instruction {
         Pop => Opcodes::POP,
         Ret => Opcodes::RET,
         BrTrue(_) => Opcodes::BR_TRUE,
@@ -598,6 +603,5 @@ pub fn instruction_key(instruction: &Bytecode) -> u8 {
         VecPopBack(_) => Opcodes::VEC_POP_BACK,
         VecUnpack(..) => Opcodes::VEC_UNPACK,
         VecSwap(_) => Opcodes::VEC_SWAP,
-    };
-    opcode as u8
+    }
 }
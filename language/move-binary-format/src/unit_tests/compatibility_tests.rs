@@ -3,8 +3,14 @@
 
 use std::convert::TryFrom;
 
-use crate::{compatibility::Compatibility, file_format::*, normalized};
-use move_core_types::{account_address::AccountAddress, identifier::Identifier};
+use crate::{
+    compatibility::{Compatibility, CompatibilityConfig, Incompatibility},
+    file_format::*,
+    normalized,
+};
+use move_core_types::{
+    account_address::AccountAddress, identifier::Identifier, language_storage::ModuleId,
+};
 
 fn mk_module(vis: u8) -> normalized::Module {
     let (visibility, is_entry) = if vis == Visibility::DEPRECATED_SCRIPT {
@@ -132,3 +138,142 @@ fn deprecated_add_script_visibility() {
         NON_COMPATIBLE
     );
 }
+
+fn ident(name: &str) -> Identifier {
+    Identifier::new(name).unwrap()
+}
+
+// module M { struct S has copy, drop { f: u64 } public fun f(): u64 } with friend N
+fn mk_normalized_module() -> normalized::Module {
+    normalized::Module {
+        file_format_version: crate::file_format_common::VERSION_5,
+        address: AccountAddress::ZERO,
+        name: ident("M"),
+        friends: vec![ModuleId::new(AccountAddress::ZERO, ident("N"))],
+        structs: vec![(
+            ident("S"),
+            normalized::Struct {
+                abilities: AbilitySet::EMPTY | Ability::Copy | Ability::Drop,
+                type_parameters: vec![],
+                fields: vec![normalized::Field {
+                    name: ident("f"),
+                    type_: normalized::Type::U64,
+                }],
+            },
+        )]
+        .into_iter()
+        .collect(),
+        exposed_functions: vec![(
+            ident("f"),
+            normalized::Function {
+                visibility: Visibility::Public,
+                is_entry: false,
+                type_parameters: vec![],
+                parameters: vec![],
+                return_: vec![normalized::Type::U64],
+            },
+        )]
+        .into_iter()
+        .collect(),
+    }
+}
+
+#[test]
+fn no_incompatibilities() {
+    let module = mk_normalized_module();
+    assert!(Compatibility::incompatibilities(true, &module, &module).is_empty());
+}
+
+#[test]
+fn struct_incompatibilities() {
+    let old = mk_normalized_module();
+
+    let mut new = old.clone();
+    new.structs.clear();
+    let incompatibilities = Compatibility::incompatibilities(true, &old, &new);
+    assert_eq!(
+        incompatibilities,
+        vec![Incompatibility::StructRemoved { name: ident("S") }]
+    );
+    assert!(incompatibilities[0].breaks_linking() && incompatibilities[0].breaks_layout());
+
+    let mut new = old.clone();
+    let s = new.structs.get_mut(&ident("S")).unwrap();
+    s.abilities = AbilitySet::EMPTY | Ability::Drop | Ability::Store;
+    s.fields[0].type_ = normalized::Type::Bool;
+    let incompatibilities = Compatibility::incompatibilities(true, &old, &new);
+    assert_eq!(
+        incompatibilities,
+        vec![
+            Incompatibility::StructAbilitiesRemoved {
+                name: ident("S"),
+                abilities: vec![Ability::Copy],
+            },
+            Incompatibility::StructLayoutChanged { name: ident("S") },
+        ]
+    );
+    assert_eq!(
+        incompatibilities[0].to_string(),
+        "abilities copy were removed from struct `S`"
+    );
+    assert_eq!(
+        Compatibility::check(true, &old, &new),
+        Compatibility {
+            struct_and_function_linking: false,
+            struct_layout: false,
+        }
+    );
+    // only the layout change is rejected when linking is not checked
+    let config = CompatibilityConfig {
+        check_struct_and_function_linking: false,
+        ..CompatibilityConfig::full_check()
+    };
+    assert!(!incompatibilities[0].is_checked_by(&config));
+    assert!(incompatibilities[1].is_checked_by(&config));
+}
+
+#[test]
+fn function_incompatibilities() {
+    let old = mk_normalized_module();
+
+    let mut new = old.clone();
+    let f = new.exposed_functions.get_mut(&ident("f")).unwrap();
+    f.visibility = Visibility::Friend;
+    f.parameters = vec![normalized::Type::Address];
+    f.type_parameters = vec![AbilitySet::EMPTY];
+    assert_eq!(
+        Compatibility::incompatibilities(true, &old, &new),
+        vec![
+            Incompatibility::FunctionVisibilityChanged {
+                name: ident("f"),
+                old: Visibility::Public,
+                new: Visibility::Friend,
+            },
+            Incompatibility::FunctionSignatureChanged { name: ident("f") },
+            Incompatibility::FunctionTypeParametersChanged { name: ident("f") },
+        ]
+    );
+
+    let mut new = old.clone();
+    new.exposed_functions.clear();
+    let incompatibilities = Compatibility::incompatibilities(true, &old, &new);
+    assert_eq!(
+        incompatibilities,
+        vec![Incompatibility::FunctionRemoved { name: ident("f") }]
+    );
+    assert_eq!(incompatibilities[0].to_string(), "function `f` was removed");
+}
+
+#[test]
+fn friend_incompatibilities() {
+    let old = mk_normalized_module();
+    let mut new = old.clone();
+    new.friends.clear();
+    assert_eq!(
+        Compatibility::incompatibilities(true, &old, &new),
+        vec![Incompatibility::FriendRemoved {
+            module: ModuleId::new(AccountAddress::ZERO, ident("N")),
+        }]
+    );
+    assert!(Compatibility::incompatibilities(false, &old, &new).is_empty());
+}
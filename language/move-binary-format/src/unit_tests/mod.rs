@@ -7,3 +7,4 @@ mod compatibility_tests;
 mod deserializer_tests;
 mod number_tests;
 mod signature_token_tests;
+mod upgrade_policy_tests;
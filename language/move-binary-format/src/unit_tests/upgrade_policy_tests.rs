@@ -0,0 +1,74 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    file_format::empty_module,
+    upgrade_policy::{UpgradePolicy, UPGRADE_POLICY_METADATA_KEY},
+};
+use move_core_types::{metadata::Metadata, vm_status::StatusCode};
+
+const ALL_POLICIES: [UpgradePolicy; 4] = [
+    UpgradePolicy::Arbitrary,
+    UpgradePolicy::Compatible,
+    UpgradePolicy::Additive,
+    UpgradePolicy::Immutable,
+];
+
+#[test]
+fn default_policy() {
+    assert_eq!(
+        UpgradePolicy::from_module(&empty_module()).unwrap(),
+        UpgradePolicy::Compatible
+    );
+}
+
+#[test]
+fn metadata_roundtrip() {
+    for policy in ALL_POLICIES {
+        let mut module = empty_module();
+        UpgradePolicy::Immutable.set_in_module(&mut module);
+        policy.set_in_module(&mut module);
+        assert_eq!(module.metadata.len(), 1);
+        assert_eq!(UpgradePolicy::from_module(&module).unwrap(), policy);
+    }
+}
+
+#[test]
+fn malformed_metadata() {
+    let mut module = empty_module();
+    module.metadata.push(Metadata {
+        key: UPGRADE_POLICY_METADATA_KEY.to_vec(),
+        value: vec![],
+    });
+    assert_eq!(
+        UpgradePolicy::from_module(&module)
+            .unwrap_err()
+            .major_status(),
+        StatusCode::MALFORMED_UPGRADE_POLICY
+    );
+
+    let mut module = empty_module();
+    module
+        .metadata
+        .push(UpgradePolicy::Compatible.to_metadata());
+    module.metadata.push(UpgradePolicy::Arbitrary.to_metadata());
+    assert_eq!(
+        UpgradePolicy::from_module(&module)
+            .unwrap_err()
+            .major_status(),
+        StatusCode::MALFORMED_UPGRADE_POLICY
+    );
+}
+
+#[test]
+fn parse_and_display() {
+    for policy in ALL_POLICIES {
+        assert_eq!(policy.to_string().parse::<UpgradePolicy>().unwrap(), policy);
+    }
+    assert!("upgradable".parse::<UpgradePolicy>().is_err());
+}
+
+#[test]
+fn policies_are_ordered_by_restrictiveness() {
+    assert!(ALL_POLICIES.windows(2).all(|pair| pair[0] < pair[1]));
+}
@@ -0,0 +1,123 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Upgrade policies restrict how a published module can be updated. The policy of a module is
+//! declared in its metadata section, and is enforced when a new version of the module is published.
+
+use crate::{
+    errors::{PartialVMError, PartialVMResult},
+    file_format::CompiledModule,
+    normalized::Module,
+};
+use move_core_types::{metadata::Metadata, vm_status::StatusCode};
+use std::{collections::BTreeSet, fmt, str::FromStr};
+
+/// The key of the metadata entry holding the upgrade policy of a module.
+pub const UPGRADE_POLICY_METADATA_KEY: &[u8] = b"upgrade_policy";
+
+/// How a published module can be updated. Policies are ordered from the least to the most
+/// restrictive, and an update can never relax the policy of a module.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum UpgradePolicy {
+    /// Any update is allowed, including backward incompatible ones.
+    Arbitrary = 0,
+    /// Only backward compatible updates are allowed. This is the policy of modules which do not
+    /// declare one.
+    #[default]
+    Compatible = 1,
+    /// Only new declarations can be added: existing structs, public, friend and entry functions
+    /// and friends must be left unchanged.
+    Additive = 2,
+    /// The module cannot be updated.
+    Immutable = 3,
+}
+
+impl UpgradePolicy {
+    /// The metadata entry declaring this policy.
+    pub fn to_metadata(self) -> Metadata {
+        Metadata {
+            key: UPGRADE_POLICY_METADATA_KEY.to_vec(),
+            value: vec![self as u8],
+        }
+    }
+
+    /// The policy declared in the metadata of `module`, or the default policy if there is none.
+    pub fn from_module(module: &CompiledModule) -> PartialVMResult<Self> {
+        let mut entries = module
+            .metadata
+            .iter()
+            .filter(|md| md.key == UPGRADE_POLICY_METADATA_KEY);
+        let metadata = match entries.next() {
+            None => return Ok(Self::default()),
+            Some(metadata) => metadata,
+        };
+        if entries.next().is_some() {
+            return Err(PartialVMError::new(StatusCode::MALFORMED_UPGRADE_POLICY)
+                .with_message("Multiple upgrade policies declared".to_string()));
+        }
+        match metadata.value.as_slice() {
+            [0] => Ok(Self::Arbitrary),
+            [1] => Ok(Self::Compatible),
+            [2] => Ok(Self::Additive),
+            [3] => Ok(Self::Immutable),
+            value => Err(PartialVMError::new(StatusCode::MALFORMED_UPGRADE_POLICY)
+                .with_message(format!("Invalid upgrade policy {:?}", value))),
+        }
+    }
+
+    /// Declare this policy in the metadata of `module`, replacing any previous declaration.
+    pub fn set_in_module(self, module: &mut CompiledModule) {
+        module
+            .metadata
+            .retain(|md| md.key != UPGRADE_POLICY_METADATA_KEY);
+        module.metadata.push(self.to_metadata());
+    }
+
+    /// Return true if `new_module` only adds declarations to `old_module`, which is the
+    /// requirement of the `Additive` policy.
+    pub fn is_additive_update(old_module: &Module, new_module: &Module) -> bool {
+        let old_friends: BTreeSet<_> = old_module.friends.iter().collect();
+        let new_friends: BTreeSet<_> = new_module.friends.iter().collect();
+        old_module.address == new_module.address
+            && old_module.name == new_module.name
+            && old_module
+                .structs
+                .iter()
+                .all(|(name, old_struct)| new_module.structs.get(name) == Some(old_struct))
+            && old_module
+                .exposed_functions
+                .iter()
+                .all(|(name, old_func)| new_module.exposed_functions.get(name) == Some(old_func))
+            && old_friends.is_subset(&new_friends)
+    }
+}
+
+impl fmt::Display for UpgradePolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Arbitrary => "arbitrary",
+            Self::Compatible => "compatible",
+            Self::Additive => "additive",
+            Self::Immutable => "immutable",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl FromStr for UpgradePolicy {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "arbitrary" => Ok(Self::Arbitrary),
+            "compatible" => Ok(Self::Compatible),
+            "additive" => Ok(Self::Additive),
+            "immutable" => Ok(Self::Immutable),
+            _ => anyhow::bail!(
+                "Invalid upgrade policy '{}', expected one of: arbitrary, compatible, additive, \
+                 immutable",
+                s
+            ),
+        }
+    }
+}
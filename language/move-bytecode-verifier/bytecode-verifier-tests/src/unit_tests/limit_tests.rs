@@ -0,0 +1,142 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::support::dummy_procedure_module;
+use move_binary_format::file_format::{
+    basic_test_module, empty_script, AbilitySet, Bytecode, Signature, SignatureToken,
+    StructFieldInformation, TypeSignature,
+};
+use move_bytecode_verifier::{
+    verify_module_with_config, verify_script_with_config, LimitsVerifier, VerifierConfig,
+};
+use move_core_types::vm_status::StatusCode;
+
+fn nested_vector(depth: usize) -> SignatureToken {
+    let mut ty = SignatureToken::U8;
+    for _ in 0..depth {
+        ty = SignatureToken::Vector(Box::new(ty));
+    }
+    ty
+}
+
+#[test]
+fn test_function_size() {
+    let module = dummy_procedure_module(vec![Bytecode::Nop, Bytecode::Nop, Bytecode::Ret]);
+    let config = VerifierConfig {
+        max_function_size: Some(3),
+        ..Default::default()
+    };
+    assert!(verify_module_with_config(&config, &module).is_ok());
+
+    let config = VerifierConfig {
+        max_function_size: Some(2),
+        ..Default::default()
+    };
+    assert_eq!(
+        verify_module_with_config(&config, &module)
+            .unwrap_err()
+            .major_status(),
+        StatusCode::TOO_MANY_INSTRUCTIONS
+    );
+}
+
+#[test]
+fn test_basic_blocks() {
+    // Three blocks: [LdTrue, BrTrue], [Branch], [Ret]
+    let module = dummy_procedure_module(vec![
+        Bytecode::LdTrue,
+        Bytecode::BrTrue(3),
+        Bytecode::Branch(3),
+        Bytecode::Ret,
+    ]);
+    let config = VerifierConfig {
+        max_basic_blocks: Some(3),
+        ..Default::default()
+    };
+    assert!(verify_module_with_config(&config, &module).is_ok());
+
+    let config = VerifierConfig {
+        max_basic_blocks: Some(2),
+        ..Default::default()
+    };
+    assert_eq!(
+        verify_module_with_config(&config, &module)
+            .unwrap_err()
+            .major_status(),
+        StatusCode::TOO_MANY_BASIC_BLOCKS
+    );
+}
+
+#[test]
+fn test_type_nodes_in_signature() {
+    let mut module = basic_test_module();
+    module.signatures.push(Signature(vec![nested_vector(4)]));
+
+    let config = VerifierConfig {
+        max_type_nodes: Some(5),
+        ..Default::default()
+    };
+    assert!(LimitsVerifier::verify_module(&config, &module).is_ok());
+
+    let config = VerifierConfig {
+        max_type_nodes: Some(4),
+        ..Default::default()
+    };
+    assert_eq!(
+        LimitsVerifier::verify_module(&config, &module)
+            .unwrap_err()
+            .major_status(),
+        StatusCode::TOO_MANY_TYPE_NODES
+    );
+}
+
+#[test]
+fn test_type_nodes_in_struct_field() {
+    let mut module = basic_test_module();
+    if let StructFieldInformation::Declared(fields) = &mut module.struct_defs[0].field_information {
+        fields[0].signature = TypeSignature(nested_vector(2));
+    }
+
+    let config = VerifierConfig {
+        max_type_nodes: Some(2),
+        ..Default::default()
+    };
+    assert_eq!(
+        LimitsVerifier::verify_module(&config, &module)
+            .unwrap_err()
+            .major_status(),
+        StatusCode::TOO_MANY_TYPE_NODES
+    );
+}
+
+#[test]
+fn test_generic_instantiation_length() {
+    let mut module = basic_test_module();
+    module.function_handles[0].type_parameters = vec![AbilitySet::EMPTY; 3];
+
+    let config = VerifierConfig {
+        max_generic_instantiation_length: Some(3),
+        ..Default::default()
+    };
+    assert!(LimitsVerifier::verify_module(&config, &module).is_ok());
+
+    let config = VerifierConfig {
+        max_generic_instantiation_length: Some(2),
+        ..Default::default()
+    };
+    assert_eq!(
+        LimitsVerifier::verify_module(&config, &module)
+            .unwrap_err()
+            .major_status(),
+        StatusCode::TOO_MANY_TYPE_PARAMETERS
+    );
+
+    let mut script = empty_script();
+    script.type_parameters = vec![AbilitySet::EMPTY; 3];
+    assert_eq!(
+        verify_script_with_config(&config, &script)
+            .unwrap_err()
+            .major_status(),
+        StatusCode::TOO_MANY_TYPE_PARAMETERS
+    );
+}
@@ -7,8 +7,10 @@
 //! - All forward jumps do not enter into the middle of a loop
 //! - All "breaks" (forward, loop-exiting jumps) go to the "end" of the loop
 //! - All "continues" (back jumps in a loop) are only to the current loop
+//! - The function body does not exceed the configured number of instructions and basic blocks
 use crate::verifier::VerifierConfig;
 use move_binary_format::{
+    control_flow_graph::{ControlFlowGraph, VMControlFlowGraph},
     errors::{PartialVMError, PartialVMResult},
     file_format::{Bytecode, CodeOffset, CodeUnit, FunctionDefinitionIndex},
 };
@@ -32,6 +34,9 @@ pub fn verify(
         Some(_) => (),
     }
 
+    // check size limits
+    check_function_size(verifier_config, current_function, code)?;
+
     // check jumps
     let context = &ControlFlowVerifier {
         current_function,
@@ -41,6 +46,26 @@ pub fn verify(
     check_jumps(verifier_config, context, labels)
 }
 
+fn check_function_size(
+    verifier_config: &VerifierConfig,
+    current_function: FunctionDefinitionIndex,
+    code: &CodeUnit,
+) -> PartialVMResult<()> {
+    if let Some(limit) = verifier_config.max_function_size {
+        if code.code.len() > limit {
+            return Err(PartialVMError::new(StatusCode::TOO_MANY_INSTRUCTIONS)
+                .at_code_offset(current_function, limit as CodeOffset));
+        }
+    }
+    if let Some(limit) = verifier_config.max_basic_blocks {
+        if VMControlFlowGraph::new(&code.code).num_blocks() as usize > limit {
+            return Err(PartialVMError::new(StatusCode::TOO_MANY_BASIC_BLOCKS)
+                .at_code_offset(current_function, 0));
+        }
+    }
+    Ok(())
+}
+
 #[derive(Clone, Copy)]
 enum Label {
     Loop { last_continue: u16 },
@@ -0,0 +1,117 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! This module implements a checker for verifying that a module or script stays within the
+//! size limits configured in `VerifierConfig`:
+//! - the number of type parameters of struct and function handles (and hence the number of
+//!   type arguments of any generic instantiation) is bounded
+//! - the number of nodes of every type used in signatures and struct fields is bounded
+use crate::verifier::VerifierConfig;
+use move_binary_format::{
+    binary_views::BinaryIndexedView,
+    errors::{Location, PartialVMError, PartialVMResult, VMResult},
+    file_format::{
+        CompiledModule, CompiledScript, SignatureToken, StructFieldInformation, TableIndex,
+    },
+    IndexKind,
+};
+use move_core_types::vm_status::StatusCode;
+
+pub struct LimitsVerifier<'a> {
+    resolver: BinaryIndexedView<'a>,
+}
+
+impl<'a> LimitsVerifier<'a> {
+    pub fn verify_module(config: &VerifierConfig, module: &'a CompiledModule) -> VMResult<()> {
+        Self::verify_module_impl(config, module)
+            .map_err(|e| e.finish(Location::Module(module.self_id())))
+    }
+
+    fn verify_module_impl(
+        config: &VerifierConfig,
+        module: &'a CompiledModule,
+    ) -> PartialVMResult<()> {
+        let limit_check = Self {
+            resolver: BinaryIndexedView::Module(module),
+        };
+        limit_check.verify_struct_handles(config)?;
+        limit_check.verify_function_handles(config)?;
+        limit_check.verify_type_nodes(config)
+    }
+
+    pub fn verify_script(config: &VerifierConfig, script: &'a CompiledScript) -> VMResult<()> {
+        Self::verify_script_impl(config, script).map_err(|e| e.finish(Location::Script))
+    }
+
+    fn verify_script_impl(
+        config: &VerifierConfig,
+        script: &'a CompiledScript,
+    ) -> PartialVMResult<()> {
+        if let Some(limit) = config.max_generic_instantiation_length {
+            if script.type_parameters.len() > limit {
+                return Err(PartialVMError::new(StatusCode::TOO_MANY_TYPE_PARAMETERS));
+            }
+        }
+        let limit_check = Self {
+            resolver: BinaryIndexedView::Script(script),
+        };
+        limit_check.verify_function_handles(config)?;
+        limit_check.verify_type_nodes(config)
+    }
+
+    fn verify_struct_handles(&self, config: &VerifierConfig) -> PartialVMResult<()> {
+        if let Some(limit) = config.max_generic_instantiation_length {
+            for (idx, struct_handle) in self.resolver.struct_handles().iter().enumerate() {
+                if struct_handle.type_parameters.len() > limit {
+                    return Err(PartialVMError::new(StatusCode::TOO_MANY_TYPE_PARAMETERS)
+                        .at_index(IndexKind::StructHandle, idx as TableIndex));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn verify_function_handles(&self, config: &VerifierConfig) -> PartialVMResult<()> {
+        if let Some(limit) = config.max_generic_instantiation_length {
+            for (idx, function_handle) in self.resolver.function_handles().iter().enumerate() {
+                if function_handle.type_parameters.len() > limit {
+                    return Err(PartialVMError::new(StatusCode::TOO_MANY_TYPE_PARAMETERS)
+                        .at_index(IndexKind::FunctionHandle, idx as TableIndex));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn verify_type_nodes(&self, config: &VerifierConfig) -> PartialVMResult<()> {
+        let limit = match config.max_type_nodes {
+            Some(limit) => limit,
+            None => return Ok(()),
+        };
+        for (idx, signature) in self.resolver.signatures().iter().enumerate() {
+            for ty in &signature.0 {
+                Self::verify_type_node_count(limit, ty)
+                    .map_err(|err| err.at_index(IndexKind::Signature, idx as TableIndex))?;
+            }
+        }
+        if let Some(struct_defs) = self.resolver.struct_defs() {
+            for (idx, struct_def) in struct_defs.iter().enumerate() {
+                if let StructFieldInformation::Declared(fields) = &struct_def.field_information {
+                    for field in fields {
+                        Self::verify_type_node_count(limit, &field.signature.0).map_err(|err| {
+                            err.at_index(IndexKind::StructDefinition, idx as TableIndex)
+                        })?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn verify_type_node_count(limit: usize, ty: &SignatureToken) -> PartialVMResult<()> {
+        if ty.preorder_traversal().count() > limit {
+            return Err(PartialVMError::new(StatusCode::TOO_MANY_TYPE_NODES));
+        }
+        Ok(())
+    }
+}
@@ -7,8 +7,9 @@ use crate::{
     ability_field_requirements, check_duplication::DuplicationChecker,
     code_unit_verifier::CodeUnitVerifier, constants, friends,
     instantiation_loops::InstantiationLoopChecker, instruction_consistency::InstructionConsistency,
-    script_signature, script_signature::no_additional_script_signature_checks,
-    signature::SignatureChecker, struct_defs::RecursiveStructDefChecker,
+    limits::LimitsVerifier, script_signature,
+    script_signature::no_additional_script_signature_checks, signature::SignatureChecker,
+    struct_defs::RecursiveStructDefChecker,
 };
 use move_binary_format::{
     check_bounds::BoundsChecker,
@@ -16,9 +17,21 @@ use move_binary_format::{
     file_format::{CompiledModule, CompiledScript},
 };
 
+/// Limits enforced by the bytecode verifier. A `None` value means the corresponding
+/// property is not bounded.
 #[derive(Debug, Clone, Default)]
 pub struct VerifierConfig {
+    /// Maximum nesting depth of loops in a function body.
     pub max_loop_depth: Option<usize>,
+    /// Maximum number of instructions in a function body.
+    pub max_function_size: Option<usize>,
+    /// Maximum number of basic blocks in a function body.
+    pub max_basic_blocks: Option<usize>,
+    /// Maximum number of nodes in a single type, e.g. `vector<Option<u64>>` has 3 nodes.
+    pub max_type_nodes: Option<usize>,
+    /// Maximum number of type parameters of a generic struct or function, and hence of type
+    /// arguments in any instantiation.
+    pub max_generic_instantiation_length: Option<usize>,
 }
 
 /// Helper for a "canonical" verification of a module.
@@ -42,6 +55,7 @@ pub fn verify_module_with_config(config: &VerifierConfig, module: &CompiledModul
         e.finish(Location::Undefined)
     })?;
     DuplicationChecker::verify_module(module)?;
+    LimitsVerifier::verify_module(config, module)?;
     SignatureChecker::verify_module(module)?;
     InstructionConsistency::verify_module(module)?;
     constants::verify_module(module)?;
@@ -70,6 +84,7 @@ pub fn verify_script(script: &CompiledScript) -> VMResult<()> {
 pub fn verify_script_with_config(config: &VerifierConfig, script: &CompiledScript) -> VMResult<()> {
     BoundsChecker::verify_script(script).map_err(|e| e.finish(Location::Script))?;
     DuplicationChecker::verify_script(script)?;
+    LimitsVerifier::verify_script(config, script)?;
     SignatureChecker::verify_script(script)?;
     InstructionConsistency::verify_script(script)?;
     constants::verify_script(script)?;
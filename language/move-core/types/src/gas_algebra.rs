@@ -37,6 +37,9 @@ pub enum AbstractMemoryUnit {}
 /// Unit for counting arguments.
 pub enum Arg {}
 
+/// Unit for counting bytecode instructions.
+pub enum Instruction {}
+
 /// A derived unit resulted from the division of two given units.
 /// This is used to permit type-safe multiplications.
 ///
@@ -63,6 +66,8 @@ pub type NumBytes = GasQuantity<Byte>;
 
 pub type NumArgs = GasQuantity<Arg>;
 
+pub type NumInstructions = GasQuantity<Instruction>;
+
 /// An abstract measurement of the memory footprint of some Move concept (e.g. value, type etc.)
 /// in the Move VM.
 ///
@@ -77,6 +82,8 @@ pub type InternalGasPerAbstractMemoryUnit =
 
 pub type InternalGasPerArg = GasQuantity<UnitDiv<InternalGasUnit, Arg>>;
 
+pub type InternalGasPerInstruction = GasQuantity<UnitDiv<InternalGasUnit, Instruction>>;
+
 /***************************************************************************************************
  * Constructors
  *
@@ -27,12 +27,15 @@ enum Token {
     U64(String),
     U128(String),
     Bytes(String),
+    String(String),
     True,
     False,
     ColonColon,
     Lt,
     Gt,
     Comma,
+    LBracket,
+    RBracket,
     EOF,
 }
 
@@ -99,6 +102,8 @@ fn next_token(s: &str) -> Result<Option<(Token, usize)>> {
             '<' => (Token::Lt, 1),
             '>' => (Token::Gt, 1),
             ',' => (Token::Comma, 1),
+            '[' => (Token::LBracket, 1),
+            ']' => (Token::RBracket, 1),
             ':' => match it.next() {
                 Some(':') => (Token::ColonColon, 2),
                 _ => bail!("unrecognized token"),
@@ -151,6 +156,18 @@ fn next_token(s: &str) -> Result<Option<(Token, usize)>> {
                 let len = r.len() + 3;
                 (Token::Bytes(r), len)
             }
+            '"' => {
+                let mut r = String::new();
+                loop {
+                    match it.next() {
+                        Some('"') => break,
+                        Some(c) => r.push(c),
+                        None => bail!("unrecognized token"),
+                    }
+                }
+                let len = r.len() + 2;
+                (Token::String(r), len)
+            }
             c if c.is_ascii_whitespace() => {
                 let mut r = String::new();
                 r.push(c);
@@ -315,6 +332,17 @@ impl<I: Iterator<Item = Token>> Parser<I> {
                 TransactionArgument::Address(AccountAddress::from_hex_literal(&addr)?)
             }
             Token::Bytes(s) => TransactionArgument::U8Vector(hex::decode(s)?),
+            Token::String(s) => TransactionArgument::String(s),
+            Token::VectorType => {
+                self.consume(Token::LBracket)?;
+                let args = self.parse_comma_list(
+                    |parser| parser.parse_transaction_argument(),
+                    Token::RBracket,
+                    true,
+                )?;
+                self.consume(Token::RBracket)?;
+                TransactionArgument::Vector(args)
+            }
             tok => bail!("unexpected token {:?}, expected transaction argument", tok),
         })
     }
@@ -427,6 +455,22 @@ mod tests {
             ("x\"\"", T::U8Vector(vec![])),
             ("x\"00\"", T::U8Vector(vec![0x00])),
             ("x\"deadbeef\"", T::U8Vector(vec![0xde, 0xad, 0xbe, 0xef])),
+            ("\"\"", T::String("".to_string())),
+            ("\"hello, world\"", T::String("hello, world".to_string())),
+            ("\"h\u{e9}llo\"", T::String("h\u{e9}llo".to_string())),
+            ("vector[]", T::Vector(vec![])),
+            ("vector[1, 2,]", T::Vector(vec![T::U64(1), T::U64(2)])),
+            (
+                "vector[vector[b\"a\"], vector[]]",
+                T::Vector(vec![
+                    T::Vector(vec![T::U8Vector(vec![0x61])]),
+                    T::Vector(vec![]),
+                ]),
+            ),
+            (
+                "vector[\"a\", \"b\"]",
+                T::Vector(vec![T::String("a".to_string()), T::String("b".to_string())]),
+            ),
         ] {
             assert_eq!(&parse_transaction_argument(s).unwrap(), expected)
         }
@@ -466,6 +510,11 @@ mod tests {
             "x\" \"",
             "x\"0g\"",
             "x\"0\"",
+            "\"abc",
+            "vector",
+            "vector[1",
+            "vector[1 2]",
+            "vector<u8>[1]",
             "garbage",
             "true3",
             "3false",
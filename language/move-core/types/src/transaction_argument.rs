@@ -15,6 +15,11 @@ pub enum TransactionArgument {
     Address(AccountAddress),
     U8Vector(#[serde(with = "serde_bytes")] Vec<u8>),
     Bool(bool),
+    /// A UTF-8 string, passed as a `std::string::String`.
+    String(String),
+    /// A vector of arguments of the same type. An `std::option::Option` is passed as a vector of
+    /// at most one element.
+    Vector(Vec<TransactionArgument>),
 }
 
 impl fmt::Debug for TransactionArgument {
@@ -28,6 +33,8 @@ impl fmt::Debug for TransactionArgument {
             TransactionArgument::U8Vector(vector) => {
                 write!(f, "{{U8Vector: 0x{}}}", hex::encode(vector))
             }
+            TransactionArgument::String(string) => write!(f, "{{STRING: {:?}}}", string),
+            TransactionArgument::Vector(vector) => write!(f, "{{Vector: {:?}}}", vector),
         }
     }
 }
@@ -41,6 +48,11 @@ impl From<TransactionArgument> for MoveValue {
             TransactionArgument::Address(a) => MoveValue::Address(a),
             TransactionArgument::Bool(b) => MoveValue::Bool(b),
             TransactionArgument::U8Vector(v) => MoveValue::vector_u8(v),
+            // a `String` is serialized as its bytes
+            TransactionArgument::String(s) => MoveValue::vector_u8(s.into_bytes()),
+            TransactionArgument::Vector(v) => {
+                MoveValue::Vector(v.into_iter().map(MoveValue::from).collect())
+            }
         }
     }
 }
@@ -54,16 +66,20 @@ impl TryFrom<MoveValue> for TransactionArgument {
             MoveValue::U128(i) => TransactionArgument::U128(i),
             MoveValue::Address(a) => TransactionArgument::Address(a),
             MoveValue::Bool(b) => TransactionArgument::Bool(b),
-            MoveValue::Vector(v) => TransactionArgument::U8Vector(
+            MoveValue::Vector(v) if v.iter().all(|mv| matches!(mv, MoveValue::U8(_))) => {
+                TransactionArgument::U8Vector(
+                    v.into_iter()
+                        .filter_map(|mv| match mv {
+                            MoveValue::U8(byte) => Some(byte),
+                            _ => None,
+                        })
+                        .collect(),
+                )
+            }
+            MoveValue::Vector(v) => TransactionArgument::Vector(
                 v.into_iter()
-                    .map(|mv| {
-                        if let MoveValue::U8(byte) = mv {
-                            Ok(byte)
-                        } else {
-                            Err(anyhow!("unexpected value in bytes: {:?}", mv))
-                        }
-                    })
-                    .collect::<Result<Vec<u8>>>()?,
+                    .map(TransactionArgument::try_from)
+                    .collect::<Result<_>>()?,
             ),
             MoveValue::Signer(_) | MoveValue::Struct(_) => {
                 return Err(anyhow!("invalid transaction argument: {:?}", val))
@@ -123,10 +139,24 @@ mod tests {
             TransactionArgument::Bool(true),
             TransactionArgument::Address(AccountAddress::from_hex_literal("0x1").unwrap()),
             TransactionArgument::U8Vector(vec![1, 2, 3, 4]),
+            TransactionArgument::Vector(vec![
+                TransactionArgument::U64(1),
+                TransactionArgument::U64(2),
+            ]),
+            TransactionArgument::Vector(vec![
+                TransactionArgument::U8Vector(vec![1, 2]),
+                TransactionArgument::U8Vector(vec![]),
+            ]),
         ];
         for val in vals {
             let ret: TransactionArgument = MoveValue::from(val.clone()).try_into().unwrap();
             assert_eq!(ret, val);
         }
     }
+
+    #[test]
+    fn test_string_to_move_value() {
+        let val = MoveValue::from(TransactionArgument::String("hello".to_string()));
+        assert_eq!(val, MoveValue::vector_u8(b"hello".to_vec()));
+    }
 }
@@ -601,6 +601,23 @@ pub enum StatusCode {
     VEC_BORROW_ELEMENT_EXISTS_MUTABLE_BORROW_ERROR = 1110,
     // Loops are too deeply nested.
     LOOP_MAX_DEPTH_REACHED = 1111,
+    // A generic instantiation has more type arguments than allowed.
+    TOO_MANY_TYPE_PARAMETERS = 1112,
+    // A function body has more basic blocks than allowed.
+    TOO_MANY_BASIC_BLOCKS = 1113,
+    // A type is made of more nodes than allowed.
+    TOO_MANY_TYPE_NODES = 1114,
+    // A function body has more instructions than allowed.
+    TOO_MANY_INSTRUCTIONS = 1115,
+    // The sender is trying to update a module whose upgrade policy is immutable
+    IMMUTABLE_MODULE_UPDATE = 1116,
+    // The sender is trying to update a module whose upgrade policy is additive with an update
+    // that changes existing declarations
+    NON_ADDITIVE_MODULE_UPDATE = 1117,
+    // The updated module declares a less restrictive upgrade policy than the published one
+    UPGRADE_POLICY_WEAKENED = 1118,
+    // The upgrade policy in the metadata of a module cannot be decoded
+    MALFORMED_UPGRADE_POLICY = 1119,
 
     // These are errors that the VM might raise if a violation of internal
     // invariants takes place.
@@ -624,6 +624,7 @@ fn call_native_function(
                 state.record_access(args[0], Access::Read, func_env)
             }
         }
+        ("bcs", "from_bytes") => (),
         ("signer", "borrow_address") => {
             if state.locals.local_exists(args[0], func_env) {
                 // treat as identity function
@@ -664,11 +665,25 @@ fn call_native_function(
                 state.access_offset(args[0], Offset::VectorIndex, Access::Write, func_env);
             }
         }
-        ("vector", "contains") => {
+        ("vector", "contains") | ("vector", "binary_search") => {
             if state.locals.local_exists(args[0], func_env) {
                 state.record_access(args[0], Access::Read, func_env); // reads the length + contents
             }
         }
+        ("vector", "sort") | ("vector", "sort_by_key") | ("vector", "move_range") => {
+            let vector_args = match fun_name {
+                "sort" => &args[..1],
+                "sort_by_key" => &args[..2],
+                _ => &[args[0], args[3]][..],
+            };
+            for arg in vector_args {
+                if state.locals.local_exists(*arg, func_env) {
+                    // reads and writes the contents of the vector
+                    state.access_offset(*arg, Offset::VectorIndex, Access::Read, func_env);
+                    state.access_offset(*arg, Offset::VectorIndex, Access::Write, func_env);
+                }
+            }
+        }
         ("Account", "create_signer") => {
             if state.locals.local_exists(args[0], func_env) {
                 state.record_access(args[0], Access::Read, func_env); // reads the input address
@@ -689,8 +704,41 @@ fn call_native_function(
         | ("string", "internal_sub_string")
         | ("string", "internal_index_of") => (),
         ("event", "write_to_event_store") => (),
-        ("hash", "sha3_256") | ("hash", "sha2_256") => (),
+        ("random", "next_u64") | ("random", "bytes") => (),
+        ("hash", "sha3_256")
+        | ("hash", "sha2_256")
+        | ("hash", "keccak256")
+        | ("hash", "blake2b_256")
+        | ("hash", "ripemd160")
+        | ("hash", "sha2_512") => (),
+        ("hex", "encode")
+        | ("hex", "internal_decode")
+        | ("base64", "encode")
+        | ("base64", "internal_decode") => (),
+        ("type_info", "size_of_val") => {
+            if state.locals.local_exists(args[0], func_env) {
+                state.record_access(args[0], Access::Read, func_env)
+            }
+        }
+        ("type_info", "type_of") | ("type_info", "abilities") => (),
+        ("string_utils", "to_string") => {
+            if state.locals.local_exists(args[0], func_env) {
+                state.record_access(args[0], Access::Read, func_env)
+            }
+        }
+        ("string_utils", "format_list") => {
+            if state.locals.local_exists(args[1], func_env) {
+                state.record_access(args[1], Access::Read, func_env)
+            }
+        }
+        ("math128", "mul_div_internal") => (),
         ("Signature", "ed25519_validate_pubkey") | ("Signature", "ed25519_verify") => (),
+        ("ed25519", "public_key_validate_internal")
+        | ("ed25519", "signature_verify_strict_internal")
+        | ("multi_ed25519", "public_key_validate_internal")
+        | ("multi_ed25519", "signature_verify_strict_internal")
+        | ("secp256k1", "ecdsa_recover_internal")
+        | ("secp256k1", "ecdsa_verify_internal") => (),
         (m, f) => {
             panic!("Unsupported native function {:?}::{:?}", m, f)
         }
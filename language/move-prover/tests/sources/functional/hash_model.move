@@ -90,4 +90,41 @@ module 0x42::TestHash {
         // ensures result_1 == result_2; // TODO: uncomment this after fixing the non-determinism issue of Boogie
         ensures len(result_1) > 0 ==> result_1[0] < max_u8();
     }
+
+
+    // ---------------
+    // RIPEMD-160 Tests
+    // ---------------
+
+    fun hash_test5(v1: vector<u8>, v2: vector<u8>): (vector<u8>, vector<u8>)
+    {
+        let h1 = hash::ripemd160(v1);
+        let h2 = hash::ripemd160(v2);
+        (h1, h2)
+    }
+    spec hash_test5 {
+        aborts_if false;
+        ensures result_1 == result_2 ==> v1 == v2;
+        ensures v1 == v2 ==> result_1 == result_2;
+        ensures len(result_1) == 20;
+        ensures result_1 == hash::ripemd160(v1);
+    }
+
+    // -------------
+    // SHA-512 Tests
+    // -------------
+
+    fun hash_test6(v1: vector<u8>, v2: vector<u8>): (vector<u8>, vector<u8>)
+    {
+        let h1 = hash::sha2_512(v1);
+        let h2 = hash::sha2_512(v2);
+        (h1, h2)
+    }
+    spec hash_test6 {
+        aborts_if false;
+        ensures result_1 == result_2 ==> v1 == v2;
+        ensures v1 == v2 ==> result_1 == result_2;
+        ensures len(result_1) == 64;
+        ensures result_1 == hash::sha2_512(v1);
+    }
 }
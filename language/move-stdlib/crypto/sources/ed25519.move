@@ -0,0 +1,106 @@
+/// Ed25519 signature verification, following [RFC8032](https://tools.ietf.org/html/rfc8032) with
+/// the stricter checks of `verify_strict`: public keys of small order and non-canonical signatures
+/// are rejected.
+module std::ed25519 {
+    use std::option::{Self, Option};
+    use std::vector;
+
+    /// The size of a serialized public key, in bytes.
+    const PUBLIC_KEY_NUM_BYTES: u64 = 32;
+    /// The size of a serialized signature, in bytes.
+    const SIGNATURE_NUM_BYTES: u64 = 64;
+
+    /// Wrong number of bytes were given as input when deserializing an Ed25519 signature.
+    const E_WRONG_SIGNATURE_SIZE: u64 = 1;
+
+    /// A public key which is known to be a point on the curve outside of the small subgroup.
+    struct ValidatedPublicKey has copy, drop, store {
+        bytes: vector<u8>
+    }
+
+    /// A purported Ed25519 signature, only checked for its size.
+    struct Signature has copy, drop, store {
+        bytes: vector<u8>
+    }
+
+    /// Parse `bytes` as a validated public key, returning `none` if they do not encode one.
+    public fun new_validated_public_key_from_bytes(bytes: vector<u8>): Option<ValidatedPublicKey> {
+        if (public_key_validate_internal(bytes)) {
+            option::some(ValidatedPublicKey { bytes })
+        } else {
+            option::none()
+        }
+    }
+    spec new_validated_public_key_from_bytes {
+        aborts_if false;
+        ensures spec_public_key_validate_internal(bytes) ==> option::is_some(result);
+        ensures !spec_public_key_validate_internal(bytes) ==> option::is_none(result);
+    }
+
+    /// Parse `bytes` as a signature.
+    /// Aborts with `E_WRONG_SIGNATURE_SIZE` if `bytes` are not 64 bytes long.
+    public fun new_signature_from_bytes(bytes: vector<u8>): Signature {
+        assert!(vector::length(&bytes) == SIGNATURE_NUM_BYTES, E_WRONG_SIGNATURE_SIZE);
+        Signature { bytes }
+    }
+    spec new_signature_from_bytes {
+        aborts_if len(bytes) != SIGNATURE_NUM_BYTES with E_WRONG_SIGNATURE_SIZE;
+        ensures result.bytes == bytes;
+    }
+
+    /// Get the serialization of a validated public key.
+    public fun validated_public_key_to_bytes(pk: &ValidatedPublicKey): vector<u8> {
+        pk.bytes
+    }
+
+    /// Get the serialization of a signature.
+    public fun signature_to_bytes(sig: &Signature): vector<u8> {
+        sig.bytes
+    }
+
+    /// Return `true` if `signature` is a valid signature of `message` by `public_key`.
+    public fun signature_verify_strict(
+        signature: &Signature,
+        public_key: &ValidatedPublicKey,
+        message: vector<u8>,
+    ): bool {
+        signature_verify_strict_internal(signature.bytes, public_key.bytes, message)
+    }
+    spec signature_verify_strict {
+        aborts_if false;
+        ensures result == spec_signature_verify_strict_internal(signature.bytes, public_key.bytes, message);
+    }
+
+    // Native functions
+
+    /// Return `true` if `bytes` is a point on the curve outside of the small subgroup.
+    native fun public_key_validate_internal(bytes: vector<u8>): bool;
+    spec public_key_validate_internal {
+        pragma opaque;
+        aborts_if false;
+        ensures result == spec_public_key_validate_internal(bytes);
+    }
+
+    /// Return `true` if `signature` is a canonical signature of `message` by `public_key`,
+    /// which is expected to have been validated.
+    native fun signature_verify_strict_internal(
+        signature: vector<u8>,
+        public_key: vector<u8>,
+        message: vector<u8>,
+    ): bool;
+    spec signature_verify_strict_internal {
+        pragma opaque;
+        aborts_if false;
+        ensures result == spec_signature_verify_strict_internal(signature, public_key, message);
+    }
+
+    // Specifications of the native functions
+
+    spec fun spec_public_key_validate_internal(bytes: vector<u8>): bool;
+
+    spec fun spec_signature_verify_strict_internal(
+        signature: vector<u8>,
+        public_key: vector<u8>,
+        message: vector<u8>,
+    ): bool;
+}
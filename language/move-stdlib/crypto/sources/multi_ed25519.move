@@ -0,0 +1,132 @@
+/// Multi-Ed25519 threshold signature verification. A public key is the concatenation of up to
+/// `MAX_NUMBER_OF_PUBLIC_KEYS` Ed25519 public keys followed by a one byte threshold `t`. A signature
+/// is the concatenation of at least `t` Ed25519 signatures, ordered by the index of their key,
+/// followed by a four byte bitmap of these indices, the most significant bit of the first byte
+/// standing for index 0.
+module std::multi_ed25519 {
+    use std::option::{Self, Option};
+    use std::vector;
+
+    /// The size of a serialized Ed25519 public key, in bytes.
+    const INDIVIDUAL_PUBLIC_KEY_NUM_BYTES: u64 = 32;
+    /// The size of a serialized Ed25519 signature, in bytes.
+    const INDIVIDUAL_SIGNATURE_NUM_BYTES: u64 = 64;
+    /// The size of the bitmap ending a signature, in bytes.
+    const BITMAP_NUM_OF_BYTES: u64 = 4;
+    /// The maximum number of Ed25519 public keys in a multi-Ed25519 public key.
+    const MAX_NUMBER_OF_PUBLIC_KEYS: u64 = 32;
+
+    /// Wrong number of bytes were given as input when deserializing a multi-Ed25519 signature.
+    const E_WRONG_SIGNATURE_SIZE: u64 = 1;
+
+    /// A public key whose keys are all valid Ed25519 public keys and whose threshold is between 1
+    /// and the number of keys.
+    struct ValidatedPublicKey has copy, drop, store {
+        bytes: vector<u8>
+    }
+
+    /// A purported multi-Ed25519 signature, only checked for its size.
+    struct Signature has copy, drop, store {
+        bytes: vector<u8>
+    }
+
+    /// Parse `bytes` as a validated public key, returning `none` if they do not encode one.
+    public fun new_validated_public_key_from_bytes(bytes: vector<u8>): Option<ValidatedPublicKey> {
+        if (public_key_validate_internal(bytes)) {
+            option::some(ValidatedPublicKey { bytes })
+        } else {
+            option::none()
+        }
+    }
+    spec new_validated_public_key_from_bytes {
+        aborts_if false;
+        ensures spec_public_key_validate_internal(bytes) ==> option::is_some(result);
+        ensures !spec_public_key_validate_internal(bytes) ==> option::is_none(result);
+    }
+
+    /// Parse `bytes` as a signature.
+    /// Aborts with `E_WRONG_SIGNATURE_SIZE` if `bytes` are not made of a bitmap preceded by
+    /// Ed25519 signatures.
+    public fun new_signature_from_bytes(bytes: vector<u8>): Signature {
+        let len = vector::length(&bytes);
+        assert!(
+            len >= BITMAP_NUM_OF_BYTES
+                && (len - BITMAP_NUM_OF_BYTES) % INDIVIDUAL_SIGNATURE_NUM_BYTES == 0,
+            E_WRONG_SIGNATURE_SIZE
+        );
+        Signature { bytes }
+    }
+    spec new_signature_from_bytes {
+        aborts_if len(bytes) < BITMAP_NUM_OF_BYTES with E_WRONG_SIGNATURE_SIZE;
+        aborts_if (len(bytes) - BITMAP_NUM_OF_BYTES) % INDIVIDUAL_SIGNATURE_NUM_BYTES != 0
+            with E_WRONG_SIGNATURE_SIZE;
+        ensures result.bytes == bytes;
+    }
+
+    /// Get the serialization of a validated public key.
+    public fun validated_public_key_to_bytes(pk: &ValidatedPublicKey): vector<u8> {
+        pk.bytes
+    }
+
+    /// Get the serialization of a signature.
+    public fun signature_to_bytes(sig: &Signature): vector<u8> {
+        sig.bytes
+    }
+
+    /// Get the number of Ed25519 public keys in `pk`.
+    public fun validated_public_key_num_sub_pks(pk: &ValidatedPublicKey): u64 {
+        vector::length(&pk.bytes) / INDIVIDUAL_PUBLIC_KEY_NUM_BYTES
+    }
+
+    /// Get the number of signatures needed for a signature to be valid for `pk`.
+    public fun validated_public_key_threshold(pk: &ValidatedPublicKey): u8 {
+        *vector::borrow(&pk.bytes, vector::length(&pk.bytes) - 1)
+    }
+
+    /// Return `true` if `signature` holds at least as many valid signatures of `message` as the
+    /// threshold of `public_key`.
+    public fun signature_verify_strict(
+        signature: &Signature,
+        public_key: &ValidatedPublicKey,
+        message: vector<u8>,
+    ): bool {
+        signature_verify_strict_internal(signature.bytes, public_key.bytes, message)
+    }
+    spec signature_verify_strict {
+        aborts_if false;
+        ensures result == spec_signature_verify_strict_internal(signature.bytes, public_key.bytes, message);
+    }
+
+    // Native functions
+
+    /// Return `true` if `bytes` is a valid multi-Ed25519 public key.
+    native fun public_key_validate_internal(bytes: vector<u8>): bool;
+    spec public_key_validate_internal {
+        pragma opaque;
+        aborts_if false;
+        ensures result == spec_public_key_validate_internal(bytes);
+    }
+
+    /// Return `true` if `signature` holds enough canonical signatures of `message` by the keys of
+    /// `public_key`, which is expected to have been validated.
+    native fun signature_verify_strict_internal(
+        signature: vector<u8>,
+        public_key: vector<u8>,
+        message: vector<u8>,
+    ): bool;
+    spec signature_verify_strict_internal {
+        pragma opaque;
+        aborts_if false;
+        ensures result == spec_signature_verify_strict_internal(signature, public_key, message);
+    }
+
+    // Specifications of the native functions
+
+    spec fun spec_public_key_validate_internal(bytes: vector<u8>): bool;
+
+    spec fun spec_signature_verify_strict_internal(
+        signature: vector<u8>,
+        public_key: vector<u8>,
+        message: vector<u8>,
+    ): bool;
+}
@@ -0,0 +1,157 @@
+/// ECDSA signatures over secp256k1, as used by Bitcoin and Ethereum. Signatures are the 64 byte
+/// concatenation of `r` and `s`, and must have their `s` in the lower half of the curve order.
+module std::secp256k1 {
+    use std::option::{Self, Option};
+    use std::vector;
+
+    /// The size of a raw public key, in bytes.
+    const RAW_PUBLIC_KEY_NUM_BYTES: u64 = 64;
+    /// The size of a signature, in bytes.
+    const SIGNATURE_NUM_BYTES: u64 = 64;
+    /// The size of a message digest, in bytes.
+    const MESSAGE_NUM_BYTES: u64 = 32;
+
+    /// Wrong number of bytes were given as input when deserializing a raw public key.
+    const E_WRONG_PUBKEY_SIZE: u64 = 1;
+    /// Wrong number of bytes were given as input when deserializing a signature.
+    const E_WRONG_SIGNATURE_SIZE: u64 = 2;
+    /// The message is not a 32 byte digest.
+    const E_WRONG_MESSAGE_SIZE: u64 = 3;
+
+    /// A public key in its uncompressed form, without the leading `0x04` tag: the 32 byte
+    /// coordinates `x` and `y` of the point.
+    struct ECDSARawPublicKey has copy, drop, store {
+        bytes: vector<u8>
+    }
+
+    /// A purported ECDSA signature, only checked for its size.
+    struct ECDSASignature has copy, drop, store {
+        bytes: vector<u8>
+    }
+
+    /// Parse `bytes` as a raw public key.
+    /// Aborts with `E_WRONG_PUBKEY_SIZE` if `bytes` are not 64 bytes long.
+    public fun ecdsa_raw_public_key_from_64_bytes(bytes: vector<u8>): ECDSARawPublicKey {
+        assert!(vector::length(&bytes) == RAW_PUBLIC_KEY_NUM_BYTES, E_WRONG_PUBKEY_SIZE);
+        ECDSARawPublicKey { bytes }
+    }
+    spec ecdsa_raw_public_key_from_64_bytes {
+        aborts_if len(bytes) != RAW_PUBLIC_KEY_NUM_BYTES with E_WRONG_PUBKEY_SIZE;
+        ensures result.bytes == bytes;
+    }
+
+    /// Parse `bytes` as a signature.
+    /// Aborts with `E_WRONG_SIGNATURE_SIZE` if `bytes` are not 64 bytes long.
+    public fun ecdsa_signature_from_bytes(bytes: vector<u8>): ECDSASignature {
+        assert!(vector::length(&bytes) == SIGNATURE_NUM_BYTES, E_WRONG_SIGNATURE_SIZE);
+        ECDSASignature { bytes }
+    }
+    spec ecdsa_signature_from_bytes {
+        aborts_if len(bytes) != SIGNATURE_NUM_BYTES with E_WRONG_SIGNATURE_SIZE;
+        ensures result.bytes == bytes;
+    }
+
+    /// Get the serialization of a raw public key.
+    public fun ecdsa_raw_public_key_to_bytes(pk: &ECDSARawPublicKey): vector<u8> {
+        pk.bytes
+    }
+
+    /// Get the serialization of a signature.
+    public fun ecdsa_signature_to_bytes(sig: &ECDSASignature): vector<u8> {
+        sig.bytes
+    }
+
+    /// Recover the public key which signed the 32 byte digest `message`, given the
+    /// `recovery_id` of `signature`. Return `none` if no such key exists.
+    /// Aborts with `E_WRONG_MESSAGE_SIZE` if `message` is not 32 bytes long.
+    public fun ecdsa_recover(
+        message: vector<u8>,
+        recovery_id: u8,
+        signature: &ECDSASignature,
+    ): Option<ECDSARawPublicKey> {
+        assert!(vector::length(&message) == MESSAGE_NUM_BYTES, E_WRONG_MESSAGE_SIZE);
+        let (pk, success) = ecdsa_recover_internal(message, recovery_id, signature.bytes);
+        if (success) {
+            option::some(ecdsa_raw_public_key_from_64_bytes(pk))
+        } else {
+            option::none()
+        }
+    }
+    spec ecdsa_recover {
+        aborts_if len(message) != MESSAGE_NUM_BYTES with E_WRONG_MESSAGE_SIZE;
+        aborts_if spec_ecdsa_recover_internal_success(message, recovery_id, signature.bytes)
+            && len(spec_ecdsa_recover_internal_result(message, recovery_id, signature.bytes))
+                != RAW_PUBLIC_KEY_NUM_BYTES
+            with E_WRONG_PUBKEY_SIZE;
+        ensures spec_ecdsa_recover_internal_success(message, recovery_id, signature.bytes)
+            ==> result == option::spec_some(ECDSARawPublicKey {
+                bytes: spec_ecdsa_recover_internal_result(message, recovery_id, signature.bytes)
+            });
+        ensures !spec_ecdsa_recover_internal_success(message, recovery_id, signature.bytes)
+            ==> result == option::spec_none<ECDSARawPublicKey>();
+    }
+
+    /// Return `true` if `signature` is a valid signature of the 32 byte digest `message` by
+    /// `public_key`.
+    /// Aborts with `E_WRONG_MESSAGE_SIZE` if `message` is not 32 bytes long.
+    public fun ecdsa_verify(
+        message: vector<u8>,
+        signature: &ECDSASignature,
+        public_key: &ECDSARawPublicKey,
+    ): bool {
+        assert!(vector::length(&message) == MESSAGE_NUM_BYTES, E_WRONG_MESSAGE_SIZE);
+        ecdsa_verify_internal(message, signature.bytes, public_key.bytes)
+    }
+    spec ecdsa_verify {
+        aborts_if len(message) != MESSAGE_NUM_BYTES with E_WRONG_MESSAGE_SIZE;
+        ensures result == spec_ecdsa_verify_internal(message, signature.bytes, public_key.bytes);
+    }
+
+    // Native functions
+
+    /// Return the raw public key recovered from `signature` and whether the recovery succeeded.
+    /// The returned key is empty on failure.
+    native fun ecdsa_recover_internal(
+        message: vector<u8>,
+        recovery_id: u8,
+        signature: vector<u8>,
+    ): (vector<u8>, bool);
+    spec ecdsa_recover_internal {
+        pragma opaque;
+        aborts_if false;
+        ensures result_1 == spec_ecdsa_recover_internal_result(message, recovery_id, signature);
+        ensures result_2 == spec_ecdsa_recover_internal_success(message, recovery_id, signature);
+    }
+
+    /// Return `true` if `signature` is a valid signature of `message` by the raw `public_key`.
+    native fun ecdsa_verify_internal(
+        message: vector<u8>,
+        signature: vector<u8>,
+        public_key: vector<u8>,
+    ): bool;
+    spec ecdsa_verify_internal {
+        pragma opaque;
+        aborts_if false;
+        ensures result == spec_ecdsa_verify_internal(message, signature, public_key);
+    }
+
+    // Specifications of the native functions
+
+    spec fun spec_ecdsa_recover_internal_result(
+        message: vector<u8>,
+        recovery_id: u8,
+        signature: vector<u8>,
+    ): vector<u8>;
+
+    spec fun spec_ecdsa_recover_internal_success(
+        message: vector<u8>,
+        recovery_id: u8,
+        signature: vector<u8>,
+    ): bool;
+
+    spec fun spec_ecdsa_verify_internal(
+        message: vector<u8>,
+        signature: vector<u8>,
+        public_key: vector<u8>,
+    ): bool;
+}
@@ -0,0 +1,66 @@
+#[test_only]
+module std::ed25519_tests {
+    use std::ed25519;
+    use std::option;
+
+    // Test vectors 1 and 2 of RFC8032.
+    const PK_1: vector<u8> = x"d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a";
+    const SIG_1: vector<u8> = x"e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b";
+    const PK_2: vector<u8> = x"3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c";
+    const SIG_2: vector<u8> = x"92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00";
+
+    #[test]
+    fun verify_rfc8032_vectors() {
+        let pk_1 = option::extract(&mut ed25519::new_validated_public_key_from_bytes(PK_1));
+        let sig_1 = ed25519::new_signature_from_bytes(SIG_1);
+        assert!(ed25519::signature_verify_strict(&sig_1, &pk_1, x""), 0);
+
+        let pk_2 = option::extract(&mut ed25519::new_validated_public_key_from_bytes(PK_2));
+        let sig_2 = ed25519::new_signature_from_bytes(SIG_2);
+        assert!(ed25519::signature_verify_strict(&sig_2, &pk_2, x"72"), 1);
+
+        assert!(ed25519::validated_public_key_to_bytes(&pk_1) == PK_1, 2);
+        assert!(ed25519::signature_to_bytes(&sig_1) == SIG_1, 3);
+    }
+
+    #[test]
+    fun reject_wrong_message_key_or_signature() {
+        let pk_1 = option::extract(&mut ed25519::new_validated_public_key_from_bytes(PK_1));
+        let pk_2 = option::extract(&mut ed25519::new_validated_public_key_from_bytes(PK_2));
+        let sig_1 = ed25519::new_signature_from_bytes(SIG_1);
+        let sig_2 = ed25519::new_signature_from_bytes(SIG_2);
+        assert!(!ed25519::signature_verify_strict(&sig_1, &pk_1, x"72"), 0);
+        assert!(!ed25519::signature_verify_strict(&sig_1, &pk_2, x""), 1);
+        assert!(!ed25519::signature_verify_strict(&sig_2, &pk_1, x""), 2);
+    }
+
+    #[test]
+    fun reject_non_canonical_signature() {
+        let pk_1 = option::extract(&mut ed25519::new_validated_public_key_from_bytes(PK_1));
+        // The `S` half of the signature is the order of the curve.
+        let sig = ed25519::new_signature_from_bytes(
+            x"e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e06522490155edd3f55c1a631258d69cf7a2def9de1400000000000000000000000000000010"
+        );
+        assert!(!ed25519::signature_verify_strict(&sig, &pk_1, x""), 0);
+    }
+
+    #[test]
+    fun reject_invalid_public_keys() {
+        // Too short
+        assert!(option::is_none(&ed25519::new_validated_public_key_from_bytes(x"d75a98")), 0);
+        // The identity, which has a small order
+        assert!(option::is_none(&ed25519::new_validated_public_key_from_bytes(
+            x"0100000000000000000000000000000000000000000000000000000000000000"
+        )), 1);
+        // Not a point on the curve
+        assert!(option::is_none(&ed25519::new_validated_public_key_from_bytes(
+            x"0200000000000000000000000000000000000000000000000000000000000000"
+        )), 2);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 1)]
+    fun signature_of_wrong_size() {
+        ed25519::new_signature_from_bytes(x"e5564300");
+    }
+}
@@ -0,0 +1,64 @@
+#[test_only]
+module std::multi_ed25519_tests {
+    use std::multi_ed25519;
+    use std::option;
+
+    // Three keys with a threshold of 2.
+    const PK: vector<u8> = x"8a88e3dd7409f195fd52db2d3cba5d72ca6709bf1d94121bf3748801b40f6f5c8139770ea87d175f56a35466c34c7ecccb8d8a91b4ee37a25df60f5b8fc9b394ed4928c628d1c2c6eae90338905995612959273a5c63f93636c14614ac8737d102";
+    // Signatures of "multi" by the first and the third keys.
+    const SIG: vector<u8> = x"84b13c71fc8880dc7b6ea8382639e083fa484d866444bd981cb7b0d5ee5063520d74a2d7720ed740f742c9e57e37f578940356d641e2321e9e5b0a99c52c8b0796770a62de041909b5b66f92da6cf6a4c4c732dde27a90c3b7e1f11c1ceb2814a74e21ed2cd19d4c296e366d8dfa489ab302a40cad2e5df515bd4ae8d1ff8f02a0000000";
+    // The signature of the first key alone.
+    const SIG_1: vector<u8> = x"84b13c71fc8880dc7b6ea8382639e083fa484d866444bd981cb7b0d5ee5063520d74a2d7720ed740f742c9e57e37f578940356d641e2321e9e5b0a99c52c8b0780000000";
+
+    #[test]
+    fun verify_threshold_signature() {
+        let pk = option::extract(&mut multi_ed25519::new_validated_public_key_from_bytes(PK));
+        assert!(multi_ed25519::validated_public_key_num_sub_pks(&pk) == 3, 0);
+        assert!(multi_ed25519::validated_public_key_threshold(&pk) == 2, 1);
+
+        let sig = multi_ed25519::new_signature_from_bytes(SIG);
+        assert!(multi_ed25519::signature_verify_strict(&sig, &pk, b"multi"), 2);
+        assert!(!multi_ed25519::signature_verify_strict(&sig, &pk, b"multj"), 3);
+    }
+
+    #[test]
+    fun reject_signatures_below_threshold() {
+        let pk = option::extract(&mut multi_ed25519::new_validated_public_key_from_bytes(PK));
+        let sig = multi_ed25519::new_signature_from_bytes(SIG_1);
+        assert!(!multi_ed25519::signature_verify_strict(&sig, &pk, b"multi"), 0);
+    }
+
+    #[test]
+    fun reject_signatures_with_wrong_bitmap() {
+        let pk = option::extract(&mut multi_ed25519::new_validated_public_key_from_bytes(PK));
+        // The bitmap claims the signatures are by the first and the second keys.
+        let sig = multi_ed25519::new_signature_from_bytes(
+            x"84b13c71fc8880dc7b6ea8382639e083fa484d866444bd981cb7b0d5ee5063520d74a2d7720ed740f742c9e57e37f578940356d641e2321e9e5b0a99c52c8b0796770a62de041909b5b66f92da6cf6a4c4c732dde27a90c3b7e1f11c1ceb2814a74e21ed2cd19d4c296e366d8dfa489ab302a40cad2e5df515bd4ae8d1ff8f02c0000000"
+        );
+        assert!(!multi_ed25519::signature_verify_strict(&sig, &pk, b"multi"), 0);
+    }
+
+    #[test]
+    fun reject_invalid_public_keys() {
+        // A threshold of 0
+        assert!(option::is_none(&multi_ed25519::new_validated_public_key_from_bytes(
+            x"8a88e3dd7409f195fd52db2d3cba5d72ca6709bf1d94121bf3748801b40f6f5c00"
+        )), 0);
+        // A threshold above the number of keys
+        assert!(option::is_none(&multi_ed25519::new_validated_public_key_from_bytes(
+            x"8a88e3dd7409f195fd52db2d3cba5d72ca6709bf1d94121bf3748801b40f6f5c02"
+        )), 1);
+        // A truncated key
+        assert!(option::is_none(&multi_ed25519::new_validated_public_key_from_bytes(
+            x"8a88e3dd7409f195fd52db2d3cba5d72ca6709bf1d94121bf3748801b40f6f01"
+        )), 2);
+        // No key at all
+        assert!(option::is_none(&multi_ed25519::new_validated_public_key_from_bytes(x"")), 3);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 1)]
+    fun signature_of_wrong_size() {
+        multi_ed25519::new_signature_from_bytes(x"84b13c71a0000000");
+    }
+}
@@ -0,0 +1,63 @@
+#[test_only]
+module std::secp256k1_tests {
+    use std::option;
+    use std::secp256k1;
+
+    const MSG: vector<u8> = x"4242424242424242424242424242424242424242424242424242424242424242";
+    const PK: vector<u8> = x"989c0b76cb563971fdc9bef31ec06c3560f3249d6ee9e5d83c57625596e05f6f631f4d05b3ae518776ee08755a7703e64b2ebc32547504de0b55a142d4ecdf80";
+    const SIG: vector<u8> = x"56b328b30c8bf5839e24058747879408bdb36241dc9c2e7c619faa12b29209672e5835ada0e879eef6c789ec1db2fea34da5c06c308a5bf2b3fdc2300a8a15a9";
+    // `SIG` with `s` replaced by its complement to the curve order.
+    const HIGH_S_SIG: vector<u8> = x"56b328b30c8bf5839e24058747879408bdb36241dc9c2e7c619faa12b2920967d1a7ca525f17861109387613e24d015b6d091c7a7ebe44490bd49c5cc5ac2b98";
+
+    #[test]
+    fun recover_public_key() {
+        let sig = secp256k1::ecdsa_signature_from_bytes(SIG);
+        let pk = secp256k1::ecdsa_recover(MSG, 1, &sig);
+        assert!(option::is_some(&pk), 0);
+        assert!(secp256k1::ecdsa_raw_public_key_to_bytes(option::borrow(&pk)) == PK, 1);
+
+        // The other recovery id gives another key.
+        let pk = secp256k1::ecdsa_recover(MSG, 0, &sig);
+        assert!(option::is_some(&pk), 2);
+        assert!(secp256k1::ecdsa_raw_public_key_to_bytes(option::borrow(&pk)) != PK, 3);
+
+        // Recovery ids are below 4.
+        assert!(option::is_none(&secp256k1::ecdsa_recover(MSG, 4, &sig)), 4);
+    }
+
+    #[test]
+    fun verify_signature() {
+        let sig = secp256k1::ecdsa_signature_from_bytes(SIG);
+        let pk = secp256k1::ecdsa_raw_public_key_from_64_bytes(PK);
+        assert!(secp256k1::ecdsa_verify(MSG, &sig, &pk), 0);
+        assert!(
+            !secp256k1::ecdsa_verify(
+                x"4242424242424242424242424242424242424242424242424242424242424243",
+                &sig,
+                &pk
+            ),
+            1
+        );
+    }
+
+    #[test]
+    fun reject_malleable_signature() {
+        let sig = secp256k1::ecdsa_signature_from_bytes(HIGH_S_SIG);
+        let pk = secp256k1::ecdsa_raw_public_key_from_64_bytes(PK);
+        assert!(!secp256k1::ecdsa_verify(MSG, &sig, &pk), 0);
+        assert!(option::is_none(&secp256k1::ecdsa_recover(MSG, 0, &sig)), 1);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 3)]
+    fun recover_from_message_of_wrong_size() {
+        let sig = secp256k1::ecdsa_signature_from_bytes(SIG);
+        secp256k1::ecdsa_recover(x"42", 1, &sig);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 2)]
+    fun signature_of_wrong_size() {
+        secp256k1::ecdsa_signature_from_bytes(x"56b328");
+    }
+}
@@ -0,0 +1,244 @@
+/// A map backed by a vector of key-value pairs sorted by key, with lookups by binary search.
+/// Keys are ordered by `compare::cmp_bcs_bytes` on their BCS serialization, which is the natural
+/// order for unsigned integers; see `std::compare` for the order of other types.
+module std::ordered_map {
+    use std::bcs;
+    use std::compare;
+    use std::option::{Self, Option};
+    use std::vector;
+
+    const EQUAL: u8 = 0;
+    const LESS_THAN: u8 = 1;
+
+    /// The key is already in the map
+    const EKEY_ALREADY_EXISTS: u64 = 0x80001;
+    /// The key is not in the map
+    const EKEY_NOT_FOUND: u64 = 0x60002;
+    /// The index is out of the bounds of the map
+    const EINDEX_OUT_OF_BOUNDS: u64 = 0x20003;
+
+    struct OrderedMap<Key, Value> has copy, drop, store {
+        /// The entries, sorted by key
+        entries: vector<Entry<Key, Value>>,
+    }
+    spec OrderedMap {
+        /// Keys are unique
+        invariant forall i in 0..len(entries), j in 0..len(entries) where i != j:
+            entries[i].key != entries[j].key;
+    }
+
+    struct Entry<Key, Value> has copy, drop, store {
+        key: Key,
+        value: Value,
+    }
+
+    /// Return an empty map.
+    public fun empty<Key, Value>(): OrderedMap<Key, Value> {
+        OrderedMap { entries: vector::empty() }
+    }
+    spec empty {
+        aborts_if false;
+        ensures len(result.entries) == 0;
+    }
+
+    /// Return the number of entries in `map`.
+    public fun length<Key, Value>(map: &OrderedMap<Key, Value>): u64 {
+        vector::length(&map.entries)
+    }
+    spec length {
+        aborts_if false;
+        ensures result == len(map.entries);
+    }
+
+    /// Return `true` if `map` has no entries.
+    public fun is_empty<Key, Value>(map: &OrderedMap<Key, Value>): bool {
+        vector::is_empty(&map.entries)
+    }
+    spec is_empty {
+        aborts_if false;
+        ensures result == (len(map.entries) == 0);
+    }
+
+    /// Return `true` if `map` has an entry for `key`.
+    public fun contains_key<Key, Value>(map: &OrderedMap<Key, Value>, key: &Key): bool {
+        let (found, _) = find(map, key);
+        found
+    }
+    spec contains_key {
+        pragma opaque;
+        aborts_if [abstract] false;
+        ensures [abstract] result == spec_contains_key(map, key);
+    }
+
+    /// Return the value of `key`.
+    /// Aborts with `EKEY_NOT_FOUND` if `map` has no entry for `key`.
+    public fun borrow<Key, Value>(map: &OrderedMap<Key, Value>, key: &Key): &Value {
+        let (found, idx) = find(map, key);
+        assert!(found, EKEY_NOT_FOUND);
+        &vector::borrow(&map.entries, idx).value
+    }
+    spec borrow {
+        pragma opaque;
+        aborts_if [abstract] !spec_contains_key(map, key) with EKEY_NOT_FOUND;
+    }
+
+    /// Return a mutable reference to the value of `key`.
+    /// Aborts with `EKEY_NOT_FOUND` if `map` has no entry for `key`.
+    public fun borrow_mut<Key, Value>(map: &mut OrderedMap<Key, Value>, key: &Key): &mut Value {
+        let (found, idx) = find(map, key);
+        assert!(found, EKEY_NOT_FOUND);
+        &mut vector::borrow_mut(&mut map.entries, idx).value
+    }
+    spec borrow_mut {
+        pragma opaque;
+        aborts_if [abstract] !spec_contains_key(map, key) with EKEY_NOT_FOUND;
+    }
+
+    /// Return the `i`-th entry of `map` in the order of keys, to iterate over its entries.
+    /// Aborts with `EINDEX_OUT_OF_BOUNDS` if `i` is not lower than the length of `map`.
+    public fun borrow_at<Key, Value>(map: &OrderedMap<Key, Value>, i: u64): (&Key, &Value) {
+        assert!(i < vector::length(&map.entries), EINDEX_OUT_OF_BOUNDS);
+        let entry = vector::borrow(&map.entries, i);
+        (&entry.key, &entry.value)
+    }
+    spec borrow_at {
+        aborts_if i >= len(map.entries) with EINDEX_OUT_OF_BOUNDS;
+    }
+
+    /// Return the `i`-th entry of `map` in the order of keys with a mutable reference to its value.
+    /// Aborts with `EINDEX_OUT_OF_BOUNDS` if `i` is not lower than the length of `map`.
+    public fun borrow_mut_at<Key, Value>(map: &mut OrderedMap<Key, Value>, i: u64): (&Key, &mut Value) {
+        assert!(i < vector::length(&map.entries), EINDEX_OUT_OF_BOUNDS);
+        let entry = vector::borrow_mut(&mut map.entries, i);
+        (&entry.key, &mut entry.value)
+    }
+    spec borrow_mut_at {
+        aborts_if i >= len(map.entries) with EINDEX_OUT_OF_BOUNDS;
+    }
+
+    /// Add an entry for `key`.
+    /// Aborts with `EKEY_ALREADY_EXISTS` if `map` already has an entry for `key`.
+    public fun add<Key, Value>(map: &mut OrderedMap<Key, Value>, key: Key, value: Value) {
+        let (found, idx) = find(map, &key);
+        assert!(!found, EKEY_ALREADY_EXISTS);
+        vector::insert(&mut map.entries, idx, Entry { key, value });
+    }
+    spec add {
+        pragma opaque;
+        aborts_if [abstract] spec_contains_key(map, key) with EKEY_ALREADY_EXISTS;
+        ensures [abstract] spec_contains_key(map, key);
+        ensures [abstract] len(map.entries) == len(old(map).entries) + 1;
+    }
+
+    /// Set the value of `key`, returning the replaced key and value if `map` already had an entry
+    /// for `key`.
+    public fun upsert<Key, Value>(
+        map: &mut OrderedMap<Key, Value>,
+        key: Key,
+        value: Value
+    ): (Option<Key>, Option<Value>) {
+        let (found, idx) = find(map, &key);
+        if (found) {
+            // Move the new entry in place of the old one.
+            vector::push_back(&mut map.entries, Entry { key, value });
+            let Entry { key, value } = vector::swap_remove(&mut map.entries, idx);
+            (option::some(key), option::some(value))
+        } else {
+            vector::insert(&mut map.entries, idx, Entry { key, value });
+            (option::none(), option::none())
+        }
+    }
+    spec upsert {
+        pragma opaque;
+        aborts_if [abstract] false;
+        ensures [abstract] spec_contains_key(map, key);
+        ensures [abstract] spec_contains_key(old(map), key) ==> len(map.entries) == len(old(map).entries);
+        ensures [abstract] !spec_contains_key(old(map), key) ==> len(map.entries) == len(old(map).entries) + 1;
+    }
+
+    /// Remove the entry for `key`, returning it.
+    /// Aborts with `EKEY_NOT_FOUND` if `map` has no entry for `key`.
+    public fun remove<Key, Value>(map: &mut OrderedMap<Key, Value>, key: &Key): (Key, Value) {
+        let (found, idx) = find(map, key);
+        assert!(found, EKEY_NOT_FOUND);
+        let Entry { key, value } = vector::remove(&mut map.entries, idx);
+        (key, value)
+    }
+    spec remove {
+        pragma opaque;
+        aborts_if [abstract] !spec_contains_key(map, key) with EKEY_NOT_FOUND;
+        ensures [abstract] !spec_contains_key(map, key);
+        ensures [abstract] len(map.entries) == len(old(map).entries) - 1;
+    }
+
+    /// Return the keys of `map`, in increasing order.
+    public fun keys<Key: copy, Value>(map: &OrderedMap<Key, Value>): vector<Key> {
+        let keys = vector::empty();
+        let i = 0;
+        let len = vector::length(&map.entries);
+        while (i < len) {
+            vector::push_back(&mut keys, vector::borrow(&map.entries, i).key);
+            i = i + 1;
+        };
+        keys
+    }
+
+    /// Return the values of `map`, in the order of their keys.
+    public fun values<Key, Value: copy>(map: &OrderedMap<Key, Value>): vector<Value> {
+        let values = vector::empty();
+        let i = 0;
+        let len = vector::length(&map.entries);
+        while (i < len) {
+            vector::push_back(&mut values, vector::borrow(&map.entries, i).value);
+            i = i + 1;
+        };
+        values
+    }
+
+    /// Destroy `map`, returning its keys in increasing order and their values.
+    public fun to_vec_pair<Key, Value>(map: OrderedMap<Key, Value>): (vector<Key>, vector<Value>) {
+        let OrderedMap { entries } = map;
+        let keys = vector::empty();
+        let values = vector::empty();
+        vector::reverse(&mut entries);
+        while (!vector::is_empty(&entries)) {
+            let Entry { key, value } = vector::pop_back(&mut entries);
+            vector::push_back(&mut keys, key);
+            vector::push_back(&mut values, value);
+        };
+        vector::destroy_empty(entries);
+        (keys, values)
+    }
+
+    /// Destroy an empty `map`.
+    /// Aborts if `map` is not empty.
+    public fun destroy_empty<Key, Value>(map: OrderedMap<Key, Value>) {
+        let OrderedMap { entries } = map;
+        vector::destroy_empty(entries);
+    }
+
+    /// Search `key` in `map`, returning whether it was found and either its index or the index at
+    /// which it should be inserted.
+    fun find<Key, Value>(map: &OrderedMap<Key, Value>, key: &Key): (bool, u64) {
+        let key_bytes = bcs::to_bytes(key);
+        let low = 0;
+        let high = vector::length(&map.entries);
+        while (low < high) {
+            let mid = low + (high - low) / 2;
+            let mid_bytes = bcs::to_bytes(&vector::borrow(&map.entries, mid).key);
+            let order = compare::cmp_bcs_bytes(&mid_bytes, &key_bytes);
+            if (order == EQUAL) {
+                return (true, mid)
+            } else if (order == LESS_THAN) {
+                low = mid + 1;
+            } else {
+                high = mid;
+            };
+        };
+        (false, low)
+    }
+
+    spec fun spec_contains_key<Key, Value>(map: OrderedMap<Key, Value>, key: Key): bool {
+        exists i in 0..len(map.entries): map.entries[i].key == key
+    }
+}
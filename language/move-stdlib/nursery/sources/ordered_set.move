@@ -0,0 +1,164 @@
+/// A set backed by a sorted vector, with lookups by binary search. Elements are ordered as the
+/// keys of `std::ordered_map`.
+module std::ordered_set {
+    use std::bcs;
+    use std::compare;
+    use std::vector;
+
+    const EQUAL: u8 = 0;
+    const LESS_THAN: u8 = 1;
+
+    /// The element is already in the set
+    const EELEMENT_ALREADY_EXISTS: u64 = 0x80001;
+    /// The element is not in the set
+    const EELEMENT_NOT_FOUND: u64 = 0x60002;
+    /// The index is out of the bounds of the set
+    const EINDEX_OUT_OF_BOUNDS: u64 = 0x20003;
+
+    struct OrderedSet<Element> has copy, drop, store {
+        /// The elements, sorted
+        elements: vector<Element>,
+    }
+    spec OrderedSet {
+        /// Elements are unique
+        invariant forall i in 0..len(elements), j in 0..len(elements) where i != j:
+            elements[i] != elements[j];
+    }
+
+    /// Return an empty set.
+    public fun empty<Element>(): OrderedSet<Element> {
+        OrderedSet { elements: vector::empty() }
+    }
+    spec empty {
+        aborts_if false;
+        ensures len(result.elements) == 0;
+    }
+
+    /// Return a set holding `e`.
+    public fun singleton<Element>(e: Element): OrderedSet<Element> {
+        OrderedSet { elements: vector::singleton(e) }
+    }
+    spec singleton {
+        aborts_if false;
+        ensures result.elements == vec(e);
+    }
+
+    /// Return the number of elements in `set`.
+    public fun length<Element>(set: &OrderedSet<Element>): u64 {
+        vector::length(&set.elements)
+    }
+    spec length {
+        aborts_if false;
+        ensures result == len(set.elements);
+    }
+
+    /// Return `true` if `set` has no elements.
+    public fun is_empty<Element>(set: &OrderedSet<Element>): bool {
+        vector::is_empty(&set.elements)
+    }
+    spec is_empty {
+        aborts_if false;
+        ensures result == (len(set.elements) == 0);
+    }
+
+    /// Return `true` if `e` is in `set`.
+    public fun contains<Element>(set: &OrderedSet<Element>, e: &Element): bool {
+        let (found, _) = find(set, e);
+        found
+    }
+    spec contains {
+        pragma opaque;
+        aborts_if [abstract] false;
+        ensures [abstract] result == spec_contains(set, e);
+    }
+
+    /// Return the `i`-th element of `set` in increasing order, to iterate over its elements.
+    /// Aborts with `EINDEX_OUT_OF_BOUNDS` if `i` is not lower than the length of `set`.
+    public fun borrow_at<Element>(set: &OrderedSet<Element>, i: u64): &Element {
+        assert!(i < vector::length(&set.elements), EINDEX_OUT_OF_BOUNDS);
+        vector::borrow(&set.elements, i)
+    }
+    spec borrow_at {
+        aborts_if i >= len(set.elements) with EINDEX_OUT_OF_BOUNDS;
+        ensures result == set.elements[i];
+    }
+
+    /// Add `e` to `set`.
+    /// Aborts with `EELEMENT_ALREADY_EXISTS` if `e` is already in `set`.
+    public fun add<Element>(set: &mut OrderedSet<Element>, e: Element) {
+        let (found, idx) = find(set, &e);
+        assert!(!found, EELEMENT_ALREADY_EXISTS);
+        vector::insert(&mut set.elements, idx, e);
+    }
+    spec add {
+        pragma opaque;
+        aborts_if [abstract] spec_contains(set, e) with EELEMENT_ALREADY_EXISTS;
+        ensures [abstract] spec_contains(set, e);
+        ensures [abstract] len(set.elements) == len(old(set).elements) + 1;
+    }
+
+    /// Remove `e` from `set`, returning the removed element.
+    /// Aborts with `EELEMENT_NOT_FOUND` if `e` is not in `set`.
+    public fun remove<Element>(set: &mut OrderedSet<Element>, e: &Element): Element {
+        let (found, idx) = find(set, e);
+        assert!(found, EELEMENT_NOT_FOUND);
+        vector::remove(&mut set.elements, idx)
+    }
+    spec remove {
+        pragma opaque;
+        aborts_if [abstract] !spec_contains(set, e) with EELEMENT_NOT_FOUND;
+        ensures [abstract] !spec_contains(set, e);
+        ensures [abstract] len(set.elements) == len(old(set).elements) - 1;
+    }
+
+    /// Return the elements of `set`, in increasing order.
+    public fun borrow_elements<Element>(set: &OrderedSet<Element>): &vector<Element> {
+        &set.elements
+    }
+    spec borrow_elements {
+        aborts_if false;
+        ensures result == set.elements;
+    }
+
+    /// Destroy `set`, returning its elements in increasing order.
+    public fun into_vector<Element>(set: OrderedSet<Element>): vector<Element> {
+        let OrderedSet { elements } = set;
+        elements
+    }
+    spec into_vector {
+        aborts_if false;
+        ensures result == set.elements;
+    }
+
+    /// Destroy an empty `set`.
+    /// Aborts if `set` is not empty.
+    public fun destroy_empty<Element>(set: OrderedSet<Element>) {
+        let OrderedSet { elements } = set;
+        vector::destroy_empty(elements);
+    }
+
+    /// Search `e` in `set`, returning whether it was found and either its index or the index at
+    /// which it should be inserted.
+    fun find<Element>(set: &OrderedSet<Element>, e: &Element): (bool, u64) {
+        let e_bytes = bcs::to_bytes(e);
+        let low = 0;
+        let high = vector::length(&set.elements);
+        while (low < high) {
+            let mid = low + (high - low) / 2;
+            let mid_bytes = bcs::to_bytes(vector::borrow(&set.elements, mid));
+            let order = compare::cmp_bcs_bytes(&mid_bytes, &e_bytes);
+            if (order == EQUAL) {
+                return (true, mid)
+            } else if (order == LESS_THAN) {
+                low = mid + 1;
+            } else {
+                high = mid;
+            };
+        };
+        (false, low)
+    }
+
+    spec fun spec_contains<Element>(set: OrderedSet<Element>, e: Element): bool {
+        exists i in 0..len(set.elements): set.elements[i] == e
+    }
+}
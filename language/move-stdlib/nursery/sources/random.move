@@ -0,0 +1,81 @@
+/// Deterministic pseudo-random numbers, for simulations and tests.
+///
+/// The numbers are drawn from a generator seeded by the embedder for each session, e.g. with the
+/// `--seed` option of `move test` and `move sandbox run`, so that runs with the same seed draw the
+/// same numbers. They are predictable, and must not be relied upon for anything security related.
+module std::random {
+    use std::vector;
+
+    /// The range to draw from is empty
+    const EINVALID_RANGE: u64 = 0x10001;
+
+    /// More than `MAX_BYTES` bytes were requested at once
+    const EBYTES_TOO_LONG: u64 = 0x20001;
+
+    /// The embedder did not seed a generator for this session
+    const EUNAVAILABLE: u64 = 0xD0001;
+
+    /// The maximum number of bytes which `bytes` returns at once.
+    const MAX_BYTES: u64 = 1048576;
+
+    /// Return the next random `u64`.
+    /// Aborts with `EUNAVAILABLE` if the session has no generator.
+    native public fun next_u64(): u64;
+    spec next_u64 {
+        pragma opaque;
+    }
+
+    /// Return `n` random bytes.
+    /// Aborts with `EBYTES_TOO_LONG` if `n` exceeds `MAX_BYTES`, or with `EUNAVAILABLE` if the
+    /// session has no generator.
+    native public fun bytes(n: u64): vector<u8>;
+    spec bytes {
+        pragma opaque;
+        aborts_if n > MAX_BYTES with EBYTES_TOO_LONG;
+        ensures len(result) == n;
+    }
+
+    /// Return a random `u8`.
+    public fun next_u8(): u8 {
+        ((next_u64() % 256) as u8)
+    }
+
+    /// Return a random `u128`.
+    public fun next_u128(): u128 {
+        ((next_u64() as u128) << 64) + (next_u64() as u128)
+    }
+
+    /// Return a random `bool`.
+    public fun next_bool(): bool {
+        next_u64() % 2 == 1
+    }
+
+    /// Return a random number in the range from `min` inclusive to `max` exclusive.
+    /// Aborts with `EINVALID_RANGE` if `min` is not lower than `max`.
+    public fun u64_range(min: u64, max: u64): u64 {
+        assert!(min < max, EINVALID_RANGE);
+        // Scale the number to the range by multiplication rather than by modulo, which keeps the
+        // bias low and evenly spread over the range.
+        min + ((((next_u64() as u128) * ((max - min) as u128)) >> 64) as u64)
+    }
+    spec u64_range {
+        pragma opaque;
+        aborts_if min >= max with EINVALID_RANGE;
+        ensures min <= result && result < max;
+    }
+
+    /// Shuffle the elements of `v` in place.
+    public fun shuffle<Element>(v: &mut vector<Element>) {
+        // Fisher-Yates: swap each element with one drawn from those before it, or itself.
+        let i = vector::length(v);
+        while (i > 1) {
+            let j = u64_range(0, i);
+            i = i - 1;
+            vector::swap(v, i, j);
+        };
+    }
+    spec shuffle {
+        pragma opaque;
+        ensures len(v) == len(old(v));
+    }
+}
@@ -0,0 +1,119 @@
+#[test_only]
+module std::ordered_map_tests {
+    use std::option;
+    use std::ordered_map;
+    use std::vector;
+
+    #[test]
+    fun add_borrow_remove() {
+        let map = ordered_map::empty<u64, u64>();
+        assert!(ordered_map::is_empty(&map), 0);
+        ordered_map::add(&mut map, 3, 30);
+        ordered_map::add(&mut map, 1, 10);
+        ordered_map::add(&mut map, 2, 20);
+        assert!(ordered_map::length(&map) == 3, 1);
+        assert!(ordered_map::contains_key(&map, &1), 2);
+        assert!(!ordered_map::contains_key(&map, &4), 3);
+        assert!(*ordered_map::borrow(&map, &2) == 20, 4);
+
+        *ordered_map::borrow_mut(&mut map, &2) = 21;
+        assert!(*ordered_map::borrow(&map, &2) == 21, 5);
+
+        let (k, v) = ordered_map::remove(&mut map, &1);
+        assert!(k == 1 && v == 10, 6);
+        assert!(!ordered_map::contains_key(&map, &1), 7);
+        assert!(ordered_map::keys(&map) == vector[2, 3], 8);
+    }
+
+    #[test]
+    fun keys_are_ordered() {
+        let map = ordered_map::empty();
+        let keys = vector[500, 3, 256, 0, 1000000, 255, 1, 65536, 42];
+        let i = 0;
+        while (i < vector::length(&keys)) {
+            let k = *vector::borrow(&keys, i);
+            ordered_map::add(&mut map, k, k * 10);
+            i = i + 1;
+        };
+        assert!(ordered_map::keys(&map) == vector[0, 1, 3, 42, 255, 256, 500, 65536, 1000000], 0);
+        assert!(ordered_map::values(&map) == vector[0, 10, 30, 420, 2550, 2560, 5000, 655360, 10000000], 1);
+
+        // Every key can be found back.
+        let i = 0;
+        while (i < vector::length(&keys)) {
+            let k = *vector::borrow(&keys, i);
+            assert!(*ordered_map::borrow(&map, &k) == k * 10, 2);
+            i = i + 1;
+        };
+        assert!(!ordered_map::contains_key(&map, &2), 3);
+        assert!(!ordered_map::contains_key(&map, &2000000), 4);
+    }
+
+    #[test]
+    fun iterate() {
+        let map = ordered_map::empty();
+        ordered_map::add(&mut map, 2, 20);
+        ordered_map::add(&mut map, 1, 10);
+        let i = 0;
+        while (i < ordered_map::length(&map)) {
+            let (_, v) = ordered_map::borrow_mut_at(&mut map, i);
+            *v = *v + 1;
+            i = i + 1;
+        };
+        let (k, v) = ordered_map::borrow_at(&map, 0);
+        assert!(*k == 1 && *v == 11, 0);
+        let (keys, values) = ordered_map::to_vec_pair(map);
+        assert!(keys == vector[1, 2], 1);
+        assert!(values == vector[11, 21], 2);
+    }
+
+    #[test]
+    fun upsert() {
+        let map = ordered_map::empty();
+        ordered_map::add(&mut map, 5, 50);
+        let (k, v) = ordered_map::upsert(&mut map, 1, 10);
+        assert!(option::is_none(&k) && option::is_none(&v), 0);
+        let (k, v) = ordered_map::upsert(&mut map, 5, 51);
+        assert!(k == option::some(5) && v == option::some(50), 1);
+        assert!(ordered_map::keys(&map) == vector[1, 5], 2);
+        assert!(ordered_map::values(&map) == vector[10, 51], 3);
+    }
+
+    #[test]
+    fun destroy_empty() {
+        let map = ordered_map::empty<u64, u64>();
+        ordered_map::add(&mut map, 1, 10);
+        ordered_map::remove(&mut map, &1);
+        ordered_map::destroy_empty(map);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x80001)]
+    fun add_twice() {
+        let map = ordered_map::empty();
+        ordered_map::add(&mut map, 1, 10);
+        ordered_map::add(&mut map, 1, 11);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x60002)]
+    fun borrow_missing() {
+        let map = ordered_map::empty<u64, u64>();
+        ordered_map::add(&mut map, 2, 20);
+        ordered_map::borrow(&map, &1);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x60002)]
+    fun remove_missing() {
+        let map = ordered_map::empty<u64, u64>();
+        ordered_map::remove(&mut map, &1);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x20003)]
+    fun borrow_at_out_of_bounds() {
+        let map = ordered_map::empty<u64, u64>();
+        ordered_map::borrow_at(&map, 0);
+    }
+}
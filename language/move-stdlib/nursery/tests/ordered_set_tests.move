@@ -0,0 +1,51 @@
+#[test_only]
+module std::ordered_set_tests {
+    use std::ordered_set;
+
+    #[test]
+    fun add_contains_remove() {
+        let set = ordered_set::empty();
+        assert!(ordered_set::is_empty(&set), 0);
+        ordered_set::add(&mut set, 300u64);
+        ordered_set::add(&mut set, 1);
+        ordered_set::add(&mut set, 20);
+        assert!(ordered_set::length(&set) == 3, 1);
+        assert!(ordered_set::contains(&set, &20), 2);
+        assert!(!ordered_set::contains(&set, &2), 3);
+        assert!(*ordered_set::borrow_elements(&set) == vector[1, 20, 300], 4);
+        assert!(*ordered_set::borrow_at(&set, 2) == 300, 5);
+
+        assert!(ordered_set::remove(&mut set, &20) == 20, 6);
+        assert!(!ordered_set::contains(&set, &20), 7);
+        assert!(ordered_set::into_vector(set) == vector[1, 300], 8);
+    }
+
+    #[test]
+    fun singleton_and_destroy() {
+        let set = ordered_set::singleton(7u8);
+        assert!(ordered_set::contains(&set, &7), 0);
+        ordered_set::remove(&mut set, &7);
+        ordered_set::destroy_empty(set);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x80001)]
+    fun add_twice() {
+        let set = ordered_set::singleton(1u64);
+        ordered_set::add(&mut set, 1);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x60002)]
+    fun remove_missing() {
+        let set = ordered_set::singleton(1u64);
+        ordered_set::remove(&mut set, &2);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x20003)]
+    fun borrow_at_out_of_bounds() {
+        let set = ordered_set::singleton(1u64);
+        ordered_set::borrow_at(&set, 1);
+    }
+}
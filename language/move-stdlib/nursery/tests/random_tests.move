@@ -0,0 +1,80 @@
+#[test_only]
+module std::random_tests {
+    use std::random;
+    use std::vector;
+
+    #[test]
+    fun default_seed_sequence() {
+        // The unit test runner seeds the generator with 0 unless told otherwise.
+        assert!(random::next_u64() == 16294208416658607535, 0);
+        assert!(random::next_u64() == 7960286522194355700, 1);
+    }
+
+    #[test]
+    fun bytes_length() {
+        assert!(vector::is_empty(&random::bytes(0)), 0);
+        assert!(vector::length(&random::bytes(5)) == 5, 1);
+        assert!(vector::length(&random::bytes(8)) == 8, 2);
+        assert!(vector::length(&random::bytes(21)) == 21, 3);
+        assert!(random::bytes(16) != random::bytes(16), 4);
+    }
+
+    #[test]
+    fun u64_range_bounds() {
+        let i = 0;
+        while (i < 100) {
+            let n = random::u64_range(10, 20);
+            assert!(n >= 10 && n < 20, i);
+            i = i + 1;
+        };
+        assert!(random::u64_range(7, 8) == 7, 100);
+        let n = random::u64_range(0, 18446744073709551615);
+        assert!(n < 18446744073709551615, 101);
+    }
+
+    #[test]
+    fun shuffle_permutes() {
+        let v = vector::empty();
+        let i = 0;
+        while (i < 20) {
+            vector::push_back(&mut v, i);
+            i = i + 1;
+        };
+        let shuffled = copy v;
+        random::shuffle(&mut shuffled);
+        assert!(shuffled != v, 0);
+        assert!(vector::length(&shuffled) == 20, 1);
+        let i = 0;
+        while (i < 20) {
+            assert!(vector::contains(&shuffled, &i), i);
+            i = i + 1;
+        };
+
+        let empty = vector::empty<u64>();
+        random::shuffle(&mut empty);
+        assert!(vector::is_empty(&empty), 2);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x10001)]
+    fun u64_range_empty() {
+        random::u64_range(5, 5);
+    }
+
+    #[test]
+    fun bytes_max_length() {
+        assert!(vector::length(&random::bytes(1048576)) == 1048576, 0);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x20001)]
+    fun bytes_too_long() {
+        random::bytes(1048577);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x20001)]
+    fun bytes_u64_max() {
+        random::bytes(18446744073709551615);
+    }
+}
@@ -0,0 +1,29 @@
+/// Encoding of bytes as Base64 strings, and decoding of Base64 strings back to bytes, with the
+/// standard alphabet and padding of RFC 4648.
+module std::base64 {
+    use std::string::{Self, String};
+
+    /// The string is not padded to a multiple of 4 characters, has a character outside of the
+    /// alphabet, or does not encode its bytes canonically
+    const EINVALID_BASE64: u64 = 0x10001;
+
+    /// Return the Base64 encoding of `bytes`, padded with `=` to a multiple of 4 characters.
+    native public fun encode(bytes: &vector<u8>): String;
+    spec encode {
+        pragma opaque;
+        aborts_if false;
+        ensures len(string::bytes(result)) == (len(bytes) + 2) / 3 * 4;
+    }
+
+    /// Return the bytes encoded by the Base64 string `s`.
+    /// Aborts with `EINVALID_BASE64` if `s` is not a valid padded Base64 encoding.
+    public fun decode(s: &String): vector<u8> {
+        internal_decode(string::bytes(s))
+    }
+    spec decode {
+        pragma opaque;
+        aborts_if [abstract] len(string::bytes(s)) % 4 != 0 with EINVALID_BASE64;
+    }
+
+    native fun internal_decode(s: &vector<u8>): vector<u8>;
+}
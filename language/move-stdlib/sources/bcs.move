@@ -3,9 +3,23 @@
 /// published on-chain. See https://github.com/diem/bcs#binary-canonical-serialization-bcs for more
 /// details on BCS.
 module std::bcs {
+    /// The bytes are not the BCS representation of a value of the requested type
+    const EMALFORMED_BYTES: u64 = 1;
+    /// The requested type contains a signer, or a struct not declared by the calling module
+    const ETYPE_NOT_ALLOWED: u64 = 2;
+
     /// Return the binary representation of `v` in BCS (Binary Canonical Serialization) format
     native public fun to_bytes<MoveValue>(v: &MoveValue): vector<u8>;
 
+    /// Return the value of type `MoveValue` whose BCS representation is `bytes`.
+    /// Aborts with `EMALFORMED_BYTES` if `bytes` is not exactly such a representation.
+    ///
+    /// To prevent forging values, `MoveValue` can only be made of primitive types, vectors and
+    /// structs declared by the module calling `from_bytes`, or aborts with `ETYPE_NOT_ALLOWED`.
+    /// For instance, a `std::string::String` must be deserialized as a `vector<u8>`, and checked
+    /// with `std::string::utf8`.
+    native public fun from_bytes<MoveValue>(bytes: vector<u8>): MoveValue;
+
     // ==============================
     // Module Specification
     spec module {} // switch to module documentation context
@@ -78,4 +78,13 @@ module std::error {
   public fun internal(r: u64): u64 {  canonical(INTERNAL, r) }
   public fun not_implemented(r: u64): u64 {  canonical(NOT_IMPLEMENTED, r) }
   public fun unavailable(r: u64): u64 { canonical(UNAVAILABLE, r) }
+
+  /// Abort with `code`, attaching the UTF-8 `message` to the abort so that it can be reported
+  /// alongside the code, without needing an error map to explain it. Gas is charged per byte
+  /// of the message.
+  native public fun abort_with_message(code: u64, message: vector<u8>);
+  spec abort_with_message {
+    pragma opaque;
+    aborts_if true with code;
+  }
 }
@@ -0,0 +1,290 @@
+/// Defines a fixed-point numeric type with a 64-bit integer part and
+/// a 64-bit fractional part.
+
+module std::fixed_point64 {
+    use std::fixed_point32::{Self, FixedPoint32};
+    use std::math128;
+
+    /// Define a fixed-point numeric type with 64 fractional bits.
+    /// This is just a u128 integer but it is wrapped in a struct to
+    /// make a unique type. This is a binary representation, so decimal
+    /// values may not be exactly representable, but it provides more
+    /// than 19 decimal digits of precision both before and after the
+    /// decimal point (38 digits total).
+    struct FixedPoint64 has copy, drop, store { value: u128 }
+
+    const MAX_U64: u128 = 18446744073709551615;
+
+    /// The denominator provided was zero
+    const EDENOMINATOR: u64 = 0x10001;
+    /// The quotient value would be too large to be held in a `u64`
+    const EDIVISION: u64 = 0x20002;
+    /// The multiplied value would be too large to be held in a `u64`
+    const EMULTIPLICATION: u64 = 0x20003;
+    /// A division by zero was encountered
+    const EDIVISION_BY_ZERO: u64 = 0x10004;
+    /// The computed ratio when converting to a `FixedPoint64` would be unrepresentable
+    const ERATIO_OUT_OF_RANGE: u64 = 0x20005;
+
+    /// Multiply a u64 integer by a fixed-point number, truncating any
+    /// fractional part of the product. This will abort if the product
+    /// overflows.
+    public fun multiply_u64(val: u64, multiplier: FixedPoint64): u64 {
+        // The unscaled product has 192 bits, so let `math128` keep the full
+        // product and rescale it by dividing away the 64 fractional bits
+        // of the multiplier. The result always fits in a u128.
+        let product = math128::mul_div((val as u128), multiplier.value, 1 << 64);
+        // Check whether the value is too large.
+        assert!(product <= MAX_U64, EMULTIPLICATION);
+        (product as u64)
+    }
+    spec multiply_u64 {
+        pragma opaque;
+        include MultiplyAbortsIf;
+        ensures result == spec_multiply_u64(val, multiplier);
+    }
+    spec schema MultiplyAbortsIf {
+        val: num;
+        multiplier: FixedPoint64;
+        aborts_if spec_multiply_u64(val, multiplier) > MAX_U64 with EMULTIPLICATION;
+    }
+    spec fun spec_multiply_u64(val: num, multiplier: FixedPoint64): num {
+        (val * multiplier.value) >> 64
+    }
+
+    /// Divide a u64 integer by a fixed-point number, truncating any
+    /// fractional part of the quotient. This will abort if the divisor
+    /// is zero or if the quotient overflows.
+    public fun divide_u64(val: u64, divisor: FixedPoint64): u64 {
+        // Check for division by zero.
+        assert!(divisor.value != 0, EDIVISION_BY_ZERO);
+        // Add 64 fractional zero bits to the dividend, which then still
+        // fits in a u128, and divide.
+        let quotient = ((val as u128) << 64) / divisor.value;
+        // Check whether the value is too large.
+        assert!(quotient <= MAX_U64, EDIVISION);
+        (quotient as u64)
+    }
+    spec divide_u64 {
+        pragma opaque;
+        include DivideAbortsIf;
+        ensures result == spec_divide_u64(val, divisor);
+    }
+    spec schema DivideAbortsIf {
+        val: num;
+        divisor: FixedPoint64;
+        aborts_if divisor.value == 0 with EDIVISION_BY_ZERO;
+        aborts_if spec_divide_u64(val, divisor) > MAX_U64 with EDIVISION;
+    }
+    spec fun spec_divide_u64(val: num, divisor: FixedPoint64): num {
+        (val << 64) / divisor.value
+    }
+
+    /// Create a fixed-point value from a rational number specified by its
+    /// numerator and denominator. This will abort if the denominator is zero.
+    /// It will also abort if the numerator is nonzero and the ratio is not in
+    /// the range 2^-64 .. 2^64-1. As for `fixed_point32`, prefer a decimal
+    /// denominator 10^N when specifying decimal fractions.
+    public fun create_from_rational(numerator: u128, denominator: u128): FixedPoint64 {
+        assert!(denominator != 0, EDENOMINATOR);
+        // The scaled quotient fits in a u128 exactly when the integer part of
+        // the ratio fits in a u64.
+        assert!(numerator / denominator <= MAX_U64, ERATIO_OUT_OF_RANGE);
+        // Scale the numerator to have 64 fractional bits, without losing
+        // the high bits of the product.
+        let quotient = math128::mul_div(numerator, 1 << 64, denominator);
+        assert!(quotient != 0 || numerator == 0, ERATIO_OUT_OF_RANGE);
+        FixedPoint64 { value: quotient }
+    }
+    spec create_from_rational {
+        pragma opaque;
+        include CreateFromRationalAbortsIf;
+        ensures result == spec_create_from_rational(numerator, denominator);
+    }
+    spec schema CreateFromRationalAbortsIf {
+        numerator: u128;
+        denominator: u128;
+        let scaled_numerator = numerator << 64;
+        let quotient = scaled_numerator / denominator;
+        aborts_if denominator == 0 with EDENOMINATOR;
+        aborts_if numerator / denominator > MAX_U64 with ERATIO_OUT_OF_RANGE;
+        aborts_if quotient == 0 && numerator != 0 with ERATIO_OUT_OF_RANGE;
+    }
+    spec fun spec_create_from_rational(numerator: num, denominator: num): FixedPoint64 {
+        FixedPoint64{value: (numerator << 64) / denominator}
+    }
+
+    /// Create a fixedpoint value from a raw value.
+    public fun create_from_raw_value(value: u128): FixedPoint64 {
+        FixedPoint64 { value }
+    }
+    spec create_from_raw_value {
+        pragma opaque;
+        aborts_if false;
+        ensures result.value == value;
+    }
+
+    /// Accessor for the raw u128 value. Other less common operations, such as
+    /// adding or subtracting FixedPoint64 values, can be done using the raw
+    /// values directly.
+    public fun get_raw_value(num: FixedPoint64): u128 {
+        num.value
+    }
+
+    /// Returns true if the ratio is zero.
+    public fun is_zero(num: FixedPoint64): bool {
+        num.value == 0
+    }
+
+    /// Returns the smaller of the two FixedPoint64 numbers.
+    public fun min(num1: FixedPoint64, num2: FixedPoint64): FixedPoint64 {
+        if (num1.value < num2.value) {
+            num1
+        } else {
+            num2
+        }
+    }
+    spec min {
+        pragma opaque;
+        aborts_if false;
+        ensures result == spec_min(num1, num2);
+    }
+    spec fun spec_min(num1: FixedPoint64, num2: FixedPoint64): FixedPoint64 {
+        if (num1.value < num2.value) {
+            num1
+        } else {
+            num2
+        }
+    }
+
+    /// Returns the larger of the two FixedPoint64 numbers.
+    public fun max(num1: FixedPoint64, num2: FixedPoint64): FixedPoint64 {
+        if (num1.value > num2.value) {
+            num1
+        } else {
+            num2
+        }
+    }
+    spec max {
+        pragma opaque;
+        aborts_if false;
+        ensures result == spec_max(num1, num2);
+    }
+    spec fun spec_max(num1: FixedPoint64, num2: FixedPoint64): FixedPoint64 {
+        if (num1.value > num2.value) {
+            num1
+        } else {
+            num2
+        }
+    }
+
+    /// Create a fixedpoint value from a u128 value.
+    public fun create_from_u128(val: u128): FixedPoint64 {
+        assert!(val <= MAX_U64, ERATIO_OUT_OF_RANGE);
+        FixedPoint64{value: val << 64}
+    }
+    spec create_from_u128 {
+        pragma opaque;
+        include CreateFromU128AbortsIf;
+        ensures result == spec_create_from_u128(val);
+    }
+    spec schema CreateFromU128AbortsIf {
+        val: num;
+        aborts_if val > MAX_U64 with ERATIO_OUT_OF_RANGE;
+    }
+    spec fun spec_create_from_u128(val: num): FixedPoint64 {
+        FixedPoint64 {value: val << 64}
+    }
+
+    /// Convert a FixedPoint32 number, which is always exactly representable.
+    public fun from_fixed_point32(num: FixedPoint32): FixedPoint64 {
+        FixedPoint64 { value: (fixed_point32::get_raw_value(num) as u128) << 32 }
+    }
+    spec from_fixed_point32 {
+        pragma opaque;
+        aborts_if false;
+        ensures result.value == fixed_point32::get_raw_value(num) << 32;
+    }
+
+    /// Convert to a FixedPoint32 number, truncating the 32 lowest fractional
+    /// bits. This will abort if the integer part does not fit in 32 bits.
+    public fun to_fixed_point32(num: FixedPoint64): FixedPoint32 {
+        let value = num.value >> 32;
+        assert!(value <= MAX_U64, ERATIO_OUT_OF_RANGE);
+        fixed_point32::create_from_raw_value((value as u64))
+    }
+    spec to_fixed_point32 {
+        pragma opaque;
+        aborts_if num.value >> 32 > MAX_U64 with ERATIO_OUT_OF_RANGE;
+        ensures fixed_point32::get_raw_value(result) == num.value >> 32;
+    }
+
+    /// Returns the largest integer less than or equal to a given number.
+    public fun floor(num: FixedPoint64): u128 {
+        num.value >> 64
+    }
+    spec floor {
+        pragma opaque;
+        aborts_if false;
+        ensures result == spec_floor(num);
+    }
+    spec fun spec_floor(val: FixedPoint64): u128 {
+        val.value >> 64
+    }
+
+    /// Rounds up the given FixedPoint64 to the next largest integer.
+    public fun ceil(num: FixedPoint64): u128 {
+        let floored_num = floor(num);
+        if (num.value == floored_num << 64) {
+            floored_num
+        } else {
+            floored_num + 1
+        }
+    }
+    spec ceil {
+        pragma opaque;
+        aborts_if false;
+        ensures result == spec_ceil(num);
+    }
+    spec fun spec_ceil(val: FixedPoint64): u128 {
+        let fractional = val.value % (1 << 64);
+        if (fractional == 0) {
+            val.value >> 64
+        } else {
+            (val.value >> 64) + 1
+        }
+    }
+
+    /// Returns the value of a FixedPoint64 to the nearest integer.
+    public fun round(num: FixedPoint64): u128 {
+        let floored_num = floor(num);
+        let boundary = (floored_num << 64) + ((1 << 64) / 2);
+        if (num.value < boundary) {
+            floored_num
+        } else {
+            ceil(num)
+        }
+    }
+    spec round {
+        pragma opaque;
+        aborts_if false;
+        ensures result == spec_round(num);
+    }
+    spec fun spec_round(val: FixedPoint64): u128 {
+        let fractional = val.value % (1 << 64);
+        let boundary = (1 << 64) / 2;
+        if (fractional < boundary) {
+            val.value >> 64
+        } else {
+            (val.value >> 64) + 1
+        }
+    }
+
+    // **************** SPECIFICATIONS ****************
+
+    spec module {} // switch documentation context to module level
+
+    spec module {
+        pragma aborts_if_is_strict;
+    }
+}
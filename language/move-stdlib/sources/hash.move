@@ -1,8 +1,17 @@
-/// Module which defines SHA hashes for byte vectors.
+/// Module which defines cryptographic hashes for byte vectors.
 ///
 /// The functions in this module are natively declared both in the Move runtime
 /// as in the Move prover's prelude.
 module std::hash {
     native public fun sha2_256(data: vector<u8>): vector<u8>;
     native public fun sha3_256(data: vector<u8>): vector<u8>;
+
+    /// Keccak-256 as used by Ethereum, which differs from `sha3_256` in its padding.
+    native public fun keccak256(data: vector<u8>): vector<u8>;
+    /// BLAKE2b with a 32 byte output.
+    native public fun blake2b_256(data: vector<u8>): vector<u8>;
+    /// RIPEMD-160, with a 20 byte output, as used with `sha2_256` for Bitcoin addresses.
+    native public fun ripemd160(data: vector<u8>): vector<u8>;
+    /// SHA-512, with a 64 byte output.
+    native public fun sha2_512(data: vector<u8>): vector<u8>;
 }
@@ -0,0 +1,28 @@
+/// Encoding of bytes as hex strings, and decoding of hex strings back to bytes.
+module std::hex {
+    use std::string::{Self, String};
+
+    /// The string has an odd length or a character which is not a hex digit
+    const EINVALID_HEX: u64 = 0x10001;
+
+    /// Return the hex encoding of `bytes`, with two lowercase digits per byte and no `0x` prefix.
+    native public fun encode(bytes: &vector<u8>): String;
+    spec encode {
+        pragma opaque;
+        aborts_if false;
+        ensures len(string::bytes(result)) == 2 * len(bytes);
+    }
+
+    /// Return the bytes encoded by the hex string `s`, whose digits may be lowercase or uppercase.
+    /// Aborts with `EINVALID_HEX` if `s` is not a valid hex encoding.
+    public fun decode(s: &String): vector<u8> {
+        internal_decode(string::bytes(s))
+    }
+    spec decode {
+        pragma opaque;
+        aborts_if [abstract] len(string::bytes(s)) % 2 != 0 with EINVALID_HEX;
+        ensures [abstract] len(result) == len(string::bytes(s)) / 2;
+    }
+
+    native fun internal_decode(s: &vector<u8>): vector<u8>;
+}
@@ -0,0 +1,229 @@
+/// Standard math utilities on `u128`, the counterpart of `std::math`. As there is no wider integer
+/// type, `mul_div` and `mul_div_ceil` are native.
+module std::math128 {
+    use std::option::{Self, Option};
+
+    const MAX_U128: u128 = 340282366920938463463374607431768211455;
+
+    /// A division by zero was encountered
+    const EDIVISION_BY_ZERO: u64 = 0x10001;
+    /// The result would be too large to be held in a `u128`
+    const EOVERFLOW: u64 = 0x20002;
+    /// The logarithm of zero is undefined
+    const ELOG2_OF_ZERO: u64 = 0x10003;
+
+    /// Return the largest of two numbers.
+    public fun max(a: u128, b: u128): u128 {
+        if (a >= b) a else b
+    }
+    spec max {
+        aborts_if false;
+        ensures a >= b ==> result == a;
+        ensures a < b ==> result == b;
+    }
+
+    /// Return the smallest of two numbers.
+    public fun min(a: u128, b: u128): u128 {
+        if (a < b) a else b
+    }
+    spec min {
+        aborts_if false;
+        ensures a < b ==> result == a;
+        ensures a >= b ==> result == b;
+    }
+
+    /// Return the average of two numbers, rounded down. Unlike `(a + b) / 2`, this does not
+    /// overflow.
+    public fun average(a: u128, b: u128): u128 {
+        if (a < b) {
+            a + (b - a) / 2
+        } else {
+            b + (a - b) / 2
+        }
+    }
+    spec average {
+        aborts_if false;
+        ensures result == (a + b) / 2;
+    }
+
+    /// Return `n` raised to the power `e`.
+    /// Aborts with `EOVERFLOW` if the result does not fit in a `u128`.
+    public fun pow(n: u128, e: u128): u128 {
+        let result = checked_pow(n, e);
+        assert!(option::is_some(&result), EOVERFLOW);
+        option::destroy_some(result)
+    }
+    spec pow {
+        pragma opaque;
+        aborts_if [abstract] spec_pow(n, e) > MAX_U128 with EOVERFLOW;
+        ensures [abstract] result == spec_pow(n, e);
+    }
+
+    /// Return the square root of `x`, rounded down.
+    public fun sqrt(x: u128): u128 {
+        // Compute the result bit by bit, from the highest power of 4 not above `x`.
+        let bit = 1u128 << 126;
+        while (bit > x) {
+            bit = bit >> 2;
+        };
+        let result = 0;
+        while (bit != 0) {
+            if (x >= result + bit) {
+                x = x - (result + bit);
+                result = (result >> 1) + bit;
+            } else {
+                result = result >> 1;
+            };
+            bit = bit >> 2;
+        };
+        result
+    }
+    spec sqrt {
+        pragma opaque;
+        aborts_if [abstract] false;
+        ensures [abstract] result * result <= x;
+        ensures [abstract] (result + 1) * (result + 1) > x;
+    }
+
+    /// Return the base 2 logarithm of `x`, rounded down.
+    /// Aborts with `ELOG2_OF_ZERO` if `x` is zero.
+    public fun log2(x: u128): u8 {
+        assert!(x != 0, ELOG2_OF_ZERO);
+        // Find the position of the highest bit set by a binary search.
+        let result = 0;
+        let n = 64;
+        while (n > 0) {
+            if (x >= (1u128 << n)) {
+                x = x >> n;
+                result = result + n;
+            };
+            n = n >> 1;
+        };
+        result
+    }
+    spec log2 {
+        pragma opaque;
+        aborts_if [abstract] x == 0 with ELOG2_OF_ZERO;
+        ensures [abstract] spec_pow(2, result) <= x;
+        ensures [abstract] x < spec_pow(2, result + 1);
+    }
+
+    /// Return `a * b / c`, rounded down, without overflowing on the intermediate product.
+    /// Aborts with `EDIVISION_BY_ZERO` if `c` is zero, and with `EOVERFLOW` if the result does not
+    /// fit in a `u128`.
+    public fun mul_div(a: u128, b: u128, c: u128): u128 {
+        assert!(c != 0, EDIVISION_BY_ZERO);
+        let (result, fits) = mul_div_internal(a, b, c, false);
+        assert!(fits, EOVERFLOW);
+        result
+    }
+    spec mul_div {
+        aborts_if c == 0 with EDIVISION_BY_ZERO;
+        aborts_if a * b / c > MAX_U128 with EOVERFLOW;
+        ensures result == a * b / c;
+    }
+
+    /// Return `a * b / c`, rounded up, without overflowing on the intermediate product.
+    /// Aborts with `EDIVISION_BY_ZERO` if `c` is zero, and with `EOVERFLOW` if the result does not
+    /// fit in a `u128`.
+    public fun mul_div_ceil(a: u128, b: u128, c: u128): u128 {
+        assert!(c != 0, EDIVISION_BY_ZERO);
+        let (result, fits) = mul_div_internal(a, b, c, true);
+        assert!(fits, EOVERFLOW);
+        result
+    }
+    spec mul_div_ceil {
+        aborts_if c == 0 with EDIVISION_BY_ZERO;
+        aborts_if (a * b + c - 1) / c > MAX_U128 with EOVERFLOW;
+        ensures result == (a * b + c - 1) / c;
+    }
+
+    /// Return `a + b`, or `none` if it overflows.
+    public fun checked_add(a: u128, b: u128): Option<u128> {
+        if (MAX_U128 - a < b) option::none() else option::some(a + b)
+    }
+    spec checked_add {
+        aborts_if false;
+        ensures a + b > MAX_U128 ==> option::is_none(result);
+        ensures a + b <= MAX_U128 ==> result == option::spec_some(a + b);
+    }
+
+    /// Return `a - b`, or `none` if it underflows.
+    public fun checked_sub(a: u128, b: u128): Option<u128> {
+        if (a < b) option::none() else option::some(a - b)
+    }
+    spec checked_sub {
+        aborts_if false;
+        ensures a < b ==> option::is_none(result);
+        ensures a >= b ==> result == option::spec_some(a - b);
+    }
+
+    /// Return `a * b`, or `none` if it overflows.
+    public fun checked_mul(a: u128, b: u128): Option<u128> {
+        if (b != 0 && a > MAX_U128 / b) option::none() else option::some(a * b)
+    }
+    spec checked_mul {
+        aborts_if false;
+        ensures a * b > MAX_U128 ==> option::is_none(result);
+        ensures a * b <= MAX_U128 ==> result == option::spec_some(a * b);
+    }
+
+    /// Return `a / b`, or `none` if `b` is zero.
+    public fun checked_div(a: u128, b: u128): Option<u128> {
+        if (b == 0) option::none() else option::some(a / b)
+    }
+    spec checked_div {
+        aborts_if false;
+        ensures b == 0 ==> option::is_none(result);
+        ensures b != 0 ==> result == option::spec_some(a / b);
+    }
+
+    /// Return `n` raised to the power `e`, or `none` if it overflows.
+    public fun checked_pow(n: u128, e: u128): Option<u128> {
+        // Exponentiation by squaring. The base is only squared when a higher bit of the exponent
+        // remains, in which case the square divides the result, so its overflow is the result's.
+        let result = 1;
+        while (e > 0) {
+            if (e % 2 == 1) {
+                if (n != 0 && result > MAX_U128 / n) return option::none();
+                result = result * n;
+            };
+            e = e / 2;
+            if (e > 0) {
+                if (n != 0 && n > MAX_U128 / n) return option::none();
+                n = n * n;
+            };
+        };
+        option::some(result)
+    }
+    spec checked_pow {
+        pragma opaque;
+        aborts_if [abstract] false;
+        ensures [abstract] spec_pow(n, e) > MAX_U128 ==> option::is_none(result);
+        ensures [abstract] spec_pow(n, e) <= MAX_U128 ==> result == option::spec_some(spec_pow(n, e));
+    }
+
+    /// Return `a * b / c`, rounded up if `round_up` is set, computed with 256 bits, and whether it
+    /// fits in a `u128`. `c` must not be zero.
+    native fun mul_div_internal(a: u128, b: u128, c: u128, round_up: bool): (u128, bool);
+    spec mul_div_internal {
+        pragma opaque;
+        aborts_if c == 0 with EDIVISION_BY_ZERO;
+        let quotient = if (round_up) { (a * b + c - 1) / c } else { a * b / c };
+        ensures result_2 == (quotient <= MAX_U128);
+        ensures result_2 ==> result_1 == quotient;
+    }
+
+    spec fun spec_pow(n: num, e: num): num {
+        if (e == 0) {
+            1
+        } else {
+            n * spec_pow(n, e - 1)
+        }
+    }
+
+    #[test_only]
+    public fun mul_div_internal_for_testing(a: u128, b: u128, c: u128, round_up: bool): (u128, bool) {
+        mul_div_internal(a, b, c, round_up)
+    }
+}
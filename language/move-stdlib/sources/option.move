@@ -19,6 +19,8 @@ module std::option {
     /// The `Option` is in an invalid state for the operation attempted.
     /// The `Option` is `None` while it should be `Some`.
     const EOPTION_NOT_SET: u64 = 0x40001;
+    /// Cannot construct an option from a vector with 2 or more elements.
+    const EOPTION_VEC_TOO_LONG: u64 = 0x40002;
 
     /// Return an empty `Option`
     public fun none<Element>(): Option<Element> {
@@ -244,6 +246,18 @@ module std::option {
         ensures result == t.vec;
     }
 
+    /// Convert a vector of length at most 1 into an `Option`, which is `Some` if the vector
+    /// holds a value. Aborts if the vector is longer.
+    public fun from_vec<Element>(vec: vector<Element>): Option<Element> {
+        assert!(vector::length(&vec) <= 1, EOPTION_VEC_TOO_LONG);
+        Option { vec }
+    }
+    spec from_vec {
+        pragma opaque;
+        aborts_if len(vec) > 1 with EOPTION_VEC_TOO_LONG;
+        ensures result.vec == vec;
+    }
+
     spec module {} // switch documentation context back to module level
 
     spec module {
@@ -0,0 +1,301 @@
+/// Defines a signed fixed-point numeric type with a 64-bit integer part and
+/// a 64-bit fractional part, the signed counterpart of `std::fixed_point64`.
+
+module std::signed_fixed_point64 {
+    use std::fixed_point64::{Self, FixedPoint64};
+    use std::math128;
+
+    /// Define a signed fixed-point numeric type with 64 fractional bits.
+    /// It is stored as a sign and a magnitude, which is the raw value of
+    /// a `FixedPoint64`. Zero is never negative, so that every number has
+    /// a single representation and can be compared with `==`.
+    struct SignedFixedPoint64 has copy, drop, store {
+        negative: bool,
+        magnitude: u128,
+    }
+    spec SignedFixedPoint64 {
+        invariant magnitude == 0 ==> !negative;
+    }
+
+    const MAX_U128: u128 = 340282366920938463463374607431768211455;
+
+    /// The sum would be too large to be held in a `SignedFixedPoint64`
+    const EADDITION: u64 = 0x20001;
+    /// A division by zero was encountered
+    const EDIVISION_BY_ZERO: u64 = 0x10002;
+    /// A negative number cannot be converted to a `FixedPoint64`
+    const ENEGATIVE: u64 = 0x10003;
+
+    /// Create a signed fixed-point value from a rational number specified by
+    /// its numerator and denominator, and its sign. This aborts for the same
+    /// inputs as `fixed_point64::create_from_rational`.
+    public fun create_from_rational(
+        numerator: u128,
+        denominator: u128,
+        negative: bool,
+    ): SignedFixedPoint64 {
+        let num = fixed_point64::create_from_rational(numerator, denominator);
+        pack(negative, fixed_point64::get_raw_value(num))
+    }
+    spec create_from_rational {
+        pragma opaque;
+        include fixed_point64::CreateFromRationalAbortsIf;
+        ensures result == spec_pack(
+            negative,
+            fixed_point64::get_raw_value(fixed_point64::spec_create_from_rational(numerator, denominator))
+        );
+    }
+
+    /// Create a signed fixed-point value from the raw value of its magnitude
+    /// and its sign.
+    public fun create_from_raw_value(magnitude: u128, negative: bool): SignedFixedPoint64 {
+        pack(negative, magnitude)
+    }
+    spec create_from_raw_value {
+        pragma opaque;
+        aborts_if false;
+        ensures result == spec_pack(negative, magnitude);
+    }
+
+    /// Accessor for the raw u128 value of the magnitude.
+    public fun get_raw_magnitude(num: SignedFixedPoint64): u128 {
+        num.magnitude
+    }
+
+    /// Returns true if the number is less than zero.
+    public fun is_negative(num: SignedFixedPoint64): bool {
+        num.negative
+    }
+
+    /// Returns true if the number is zero.
+    public fun is_zero(num: SignedFixedPoint64): bool {
+        num.magnitude == 0
+    }
+
+    /// Convert a FixedPoint64 number, which is always exactly representable.
+    public fun from_fixed_point64(num: FixedPoint64): SignedFixedPoint64 {
+        SignedFixedPoint64 { negative: false, magnitude: fixed_point64::get_raw_value(num) }
+    }
+    spec from_fixed_point64 {
+        pragma opaque;
+        aborts_if false;
+        ensures !result.negative;
+        ensures result.magnitude == fixed_point64::get_raw_value(num);
+    }
+
+    /// Convert to a FixedPoint64 number. This will abort if the number is
+    /// negative.
+    public fun to_fixed_point64(num: SignedFixedPoint64): FixedPoint64 {
+        assert!(!num.negative, ENEGATIVE);
+        fixed_point64::create_from_raw_value(num.magnitude)
+    }
+    spec to_fixed_point64 {
+        pragma opaque;
+        aborts_if num.negative with ENEGATIVE;
+        ensures fixed_point64::get_raw_value(result) == num.magnitude;
+    }
+
+    /// Returns the absolute value of the number.
+    public fun abs(num: SignedFixedPoint64): FixedPoint64 {
+        fixed_point64::create_from_raw_value(num.magnitude)
+    }
+    spec abs {
+        pragma opaque;
+        aborts_if false;
+        ensures fixed_point64::get_raw_value(result) == num.magnitude;
+    }
+
+    /// Returns the number with the opposite sign.
+    public fun negate(num: SignedFixedPoint64): SignedFixedPoint64 {
+        pack(!num.negative, num.magnitude)
+    }
+    spec negate {
+        pragma opaque;
+        aborts_if false;
+        ensures spec_value(result) == 0 - spec_value(num);
+    }
+
+    /// Add two numbers. This will abort if the magnitude of the sum
+    /// overflows.
+    public fun add(num1: SignedFixedPoint64, num2: SignedFixedPoint64): SignedFixedPoint64 {
+        if (num1.negative == num2.negative) {
+            assert!(num1.magnitude <= MAX_U128 - num2.magnitude, EADDITION);
+            SignedFixedPoint64 {
+                negative: num1.negative,
+                magnitude: num1.magnitude + num2.magnitude,
+            }
+        } else if (num1.magnitude >= num2.magnitude) {
+            pack(num1.negative, num1.magnitude - num2.magnitude)
+        } else {
+            pack(num2.negative, num2.magnitude - num1.magnitude)
+        }
+    }
+    spec add {
+        pragma opaque;
+        include AddAbortsIf;
+        ensures spec_value(result) == spec_value(num1) + spec_value(num2);
+    }
+    spec schema AddAbortsIf {
+        num1: SignedFixedPoint64;
+        num2: SignedFixedPoint64;
+        aborts_if num1.negative == num2.negative && num1.magnitude + num2.magnitude > MAX_U128
+            with EADDITION;
+    }
+
+    /// Subtract the second number from the first. This will abort if the
+    /// magnitude of the difference overflows.
+    public fun sub(num1: SignedFixedPoint64, num2: SignedFixedPoint64): SignedFixedPoint64 {
+        add(num1, negate(num2))
+    }
+    spec sub {
+        pragma opaque;
+        include SubAbortsIf;
+        ensures spec_value(result) == spec_value(num1) - spec_value(num2);
+    }
+    spec schema SubAbortsIf {
+        num1: SignedFixedPoint64;
+        num2: SignedFixedPoint64;
+        aborts_if num1.negative != num2.negative && num1.magnitude + num2.magnitude > MAX_U128
+            with EADDITION;
+    }
+
+    /// Multiply two numbers, truncating the magnitude of the product to 64
+    /// fractional bits. This will abort if the magnitude of the product
+    /// overflows, with the overflow error of `math128::mul_div`.
+    public fun mul(num1: SignedFixedPoint64, num2: SignedFixedPoint64): SignedFixedPoint64 {
+        let magnitude = math128::mul_div(num1.magnitude, num2.magnitude, 1 << 64);
+        pack(num1.negative != num2.negative, magnitude)
+    }
+    spec mul {
+        pragma opaque;
+        aborts_if spec_mul_magnitude(num1, num2) > MAX_U128;
+        ensures result == spec_pack(num1.negative != num2.negative, spec_mul_magnitude(num1, num2));
+    }
+    spec fun spec_mul_magnitude(num1: SignedFixedPoint64, num2: SignedFixedPoint64): num {
+        (num1.magnitude * num2.magnitude) >> 64
+    }
+
+    /// Divide the first number by the second, truncating the magnitude of the
+    /// quotient to 64 fractional bits. This will abort if the divisor is zero,
+    /// or if the magnitude of the quotient overflows, with the overflow error
+    /// of `math128::mul_div`.
+    public fun div(num1: SignedFixedPoint64, num2: SignedFixedPoint64): SignedFixedPoint64 {
+        assert!(num2.magnitude != 0, EDIVISION_BY_ZERO);
+        let magnitude = math128::mul_div(num1.magnitude, 1 << 64, num2.magnitude);
+        pack(num1.negative != num2.negative, magnitude)
+    }
+    spec div {
+        pragma opaque;
+        aborts_if num2.magnitude == 0 with EDIVISION_BY_ZERO;
+        aborts_if spec_div_magnitude(num1, num2) > MAX_U128;
+        ensures result == spec_pack(num1.negative != num2.negative, spec_div_magnitude(num1, num2));
+    }
+    spec fun spec_div_magnitude(num1: SignedFixedPoint64, num2: SignedFixedPoint64): num {
+        (num1.magnitude << 64) / num2.magnitude
+    }
+
+    /// Returns true if the first number is less than the second.
+    public fun less_than(num1: SignedFixedPoint64, num2: SignedFixedPoint64): bool {
+        if (num1.negative != num2.negative) {
+            num1.negative
+        } else if (num1.negative) {
+            num1.magnitude > num2.magnitude
+        } else {
+            num1.magnitude < num2.magnitude
+        }
+    }
+    spec less_than {
+        pragma opaque;
+        aborts_if false;
+        ensures result == (spec_value(num1) < spec_value(num2));
+    }
+
+    /// Returns true if the first number is less than or equal to the second.
+    public fun less_or_equal(num1: SignedFixedPoint64, num2: SignedFixedPoint64): bool {
+        !less_than(num2, num1)
+    }
+    spec less_or_equal {
+        pragma opaque;
+        aborts_if false;
+        ensures result == (spec_value(num1) <= spec_value(num2));
+    }
+
+    /// Returns true if the first number is greater than the second.
+    public fun greater_than(num1: SignedFixedPoint64, num2: SignedFixedPoint64): bool {
+        less_than(num2, num1)
+    }
+    spec greater_than {
+        pragma opaque;
+        aborts_if false;
+        ensures result == (spec_value(num1) > spec_value(num2));
+    }
+
+    /// Returns true if the first number is greater than or equal to the second.
+    public fun greater_or_equal(num1: SignedFixedPoint64, num2: SignedFixedPoint64): bool {
+        !less_than(num1, num2)
+    }
+    spec greater_or_equal {
+        pragma opaque;
+        aborts_if false;
+        ensures result == (spec_value(num1) >= spec_value(num2));
+    }
+
+    /// Returns the smaller of the two SignedFixedPoint64 numbers.
+    public fun min(num1: SignedFixedPoint64, num2: SignedFixedPoint64): SignedFixedPoint64 {
+        if (less_than(num1, num2)) {
+            num1
+        } else {
+            num2
+        }
+    }
+    spec min {
+        pragma opaque;
+        aborts_if false;
+        ensures result == (if (spec_value(num1) < spec_value(num2)) { num1 } else { num2 });
+    }
+
+    /// Returns the larger of the two SignedFixedPoint64 numbers.
+    public fun max(num1: SignedFixedPoint64, num2: SignedFixedPoint64): SignedFixedPoint64 {
+        if (less_than(num2, num1)) {
+            num1
+        } else {
+            num2
+        }
+    }
+    spec max {
+        pragma opaque;
+        aborts_if false;
+        ensures result == (if (spec_value(num1) > spec_value(num2)) { num1 } else { num2 });
+    }
+
+    // Pack a number, making sure that zero is not negative.
+    fun pack(negative: bool, magnitude: u128): SignedFixedPoint64 {
+        SignedFixedPoint64 { negative: negative && magnitude != 0, magnitude }
+    }
+    spec pack {
+        pragma opaque;
+        aborts_if false;
+        ensures result == spec_pack(negative, magnitude);
+    }
+
+    // **************** SPECIFICATIONS ****************
+
+    spec module {} // switch documentation context to module level
+
+    spec module {
+        pragma aborts_if_is_strict;
+    }
+
+    spec fun spec_pack(negative: bool, magnitude: num): SignedFixedPoint64 {
+        SignedFixedPoint64 { negative: negative && magnitude != 0, magnitude }
+    }
+
+    /// The mathematical value of the number, scaled by 2^64.
+    spec fun spec_value(num: SignedFixedPoint64): num {
+        if (num.negative) {
+            0 - num.magnitude
+        } else {
+            num.magnitude
+        }
+    }
+}
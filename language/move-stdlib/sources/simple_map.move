@@ -0,0 +1,215 @@
+/// A map backed by a vector of key-value pairs, kept in insertion order except after removals.
+/// Lookups are linear, so this is meant for small maps; see the nursery `ordered_map` for a map
+/// with binary search and ordered iteration.
+module std::simple_map {
+    use std::option::{Self, Option};
+    use std::vector;
+
+    /// The key is already in the map
+    const EKEY_ALREADY_EXISTS: u64 = 0x80001;
+    /// The key is not in the map
+    const EKEY_NOT_FOUND: u64 = 0x60002;
+    /// The index is out of the bounds of the map
+    const EINDEX_OUT_OF_BOUNDS: u64 = 0x20003;
+
+    struct SimpleMap<Key, Value> has copy, drop, store {
+        data: vector<Element<Key, Value>>,
+    }
+    spec SimpleMap {
+        /// Keys are unique
+        invariant forall i in 0..len(data), j in 0..len(data) where i != j: data[i].key != data[j].key;
+    }
+
+    struct Element<Key, Value> has copy, drop, store {
+        key: Key,
+        value: Value,
+    }
+
+    /// Return an empty map.
+    public fun empty<Key, Value>(): SimpleMap<Key, Value> {
+        SimpleMap { data: vector::empty() }
+    }
+    spec empty {
+        aborts_if false;
+        ensures length(result) == 0;
+    }
+
+    /// Return the number of entries in `map`.
+    public fun length<Key, Value>(map: &SimpleMap<Key, Value>): u64 {
+        vector::length(&map.data)
+    }
+    spec length {
+        aborts_if false;
+        ensures result == len(map.data);
+    }
+
+    /// Return `true` if `map` has no entries.
+    public fun is_empty<Key, Value>(map: &SimpleMap<Key, Value>): bool {
+        vector::is_empty(&map.data)
+    }
+    spec is_empty {
+        aborts_if false;
+        ensures result == (len(map.data) == 0);
+    }
+
+    /// Return `true` if `map` has an entry for `key`.
+    public fun contains_key<Key, Value>(map: &SimpleMap<Key, Value>, key: &Key): bool {
+        option::is_some(&find(map, key))
+    }
+    spec contains_key {
+        pragma opaque;
+        aborts_if [abstract] false;
+        ensures [abstract] result == spec_contains_key(map, key);
+    }
+
+    /// Return the value of `key`.
+    /// Aborts with `EKEY_NOT_FOUND` if `map` has no entry for `key`.
+    public fun borrow<Key, Value>(map: &SimpleMap<Key, Value>, key: &Key): &Value {
+        let idx = find(map, key);
+        assert!(option::is_some(&idx), EKEY_NOT_FOUND);
+        &vector::borrow(&map.data, option::destroy_some(idx)).value
+    }
+    spec borrow {
+        pragma opaque;
+        aborts_if [abstract] !spec_contains_key(map, key) with EKEY_NOT_FOUND;
+    }
+
+    /// Return a mutable reference to the value of `key`.
+    /// Aborts with `EKEY_NOT_FOUND` if `map` has no entry for `key`.
+    public fun borrow_mut<Key, Value>(map: &mut SimpleMap<Key, Value>, key: &Key): &mut Value {
+        let idx = find(map, key);
+        assert!(option::is_some(&idx), EKEY_NOT_FOUND);
+        &mut vector::borrow_mut(&mut map.data, option::destroy_some(idx)).value
+    }
+    spec borrow_mut {
+        pragma opaque;
+        aborts_if [abstract] !spec_contains_key(map, key) with EKEY_NOT_FOUND;
+    }
+
+    /// Return the `i`-th entry of `map`, to iterate over its entries.
+    /// Aborts with `EINDEX_OUT_OF_BOUNDS` if `i` is not lower than the length of `map`.
+    public fun borrow_at<Key, Value>(map: &SimpleMap<Key, Value>, i: u64): (&Key, &Value) {
+        assert!(i < vector::length(&map.data), EINDEX_OUT_OF_BOUNDS);
+        let element = vector::borrow(&map.data, i);
+        (&element.key, &element.value)
+    }
+    spec borrow_at {
+        aborts_if i >= len(map.data) with EINDEX_OUT_OF_BOUNDS;
+    }
+
+    /// Add an entry for `key`.
+    /// Aborts with `EKEY_ALREADY_EXISTS` if `map` already has an entry for `key`.
+    public fun add<Key, Value>(map: &mut SimpleMap<Key, Value>, key: Key, value: Value) {
+        assert!(option::is_none(&find(map, &key)), EKEY_ALREADY_EXISTS);
+        vector::push_back(&mut map.data, Element { key, value });
+    }
+    spec add {
+        pragma opaque;
+        aborts_if [abstract] spec_contains_key(map, key) with EKEY_ALREADY_EXISTS;
+        ensures [abstract] spec_contains_key(map, key);
+        ensures [abstract] len(map.data) == len(old(map).data) + 1;
+    }
+
+    /// Set the value of `key`, returning the replaced key and value if `map` already had an entry
+    /// for `key`.
+    public fun upsert<Key, Value>(
+        map: &mut SimpleMap<Key, Value>,
+        key: Key,
+        value: Value
+    ): (Option<Key>, Option<Value>) {
+        let idx = find(map, &key);
+        vector::push_back(&mut map.data, Element { key, value });
+        if (option::is_some(&idx)) {
+            // Move the new entry in place of the old one.
+            let Element { key, value } = vector::swap_remove(&mut map.data, option::destroy_some(idx));
+            (option::some(key), option::some(value))
+        } else {
+            (option::none(), option::none())
+        }
+    }
+    spec upsert {
+        pragma opaque;
+        aborts_if [abstract] false;
+        ensures [abstract] spec_contains_key(map, key);
+        ensures [abstract] spec_contains_key(old(map), key) ==> len(map.data) == len(old(map).data);
+        ensures [abstract] !spec_contains_key(old(map), key) ==> len(map.data) == len(old(map).data) + 1;
+    }
+
+    /// Remove the entry for `key`, returning it.
+    /// Aborts with `EKEY_NOT_FOUND` if `map` has no entry for `key`.
+    public fun remove<Key, Value>(map: &mut SimpleMap<Key, Value>, key: &Key): (Key, Value) {
+        let idx = find(map, key);
+        assert!(option::is_some(&idx), EKEY_NOT_FOUND);
+        let Element { key, value } = vector::swap_remove(&mut map.data, option::destroy_some(idx));
+        (key, value)
+    }
+    spec remove {
+        pragma opaque;
+        aborts_if [abstract] !spec_contains_key(map, key) with EKEY_NOT_FOUND;
+        ensures [abstract] !spec_contains_key(map, key);
+        ensures [abstract] len(map.data) == len(old(map).data) - 1;
+    }
+
+    /// Return the keys of `map`, in the order of its entries.
+    public fun keys<Key: copy, Value>(map: &SimpleMap<Key, Value>): vector<Key> {
+        let keys = vector::empty();
+        let i = 0;
+        let len = vector::length(&map.data);
+        while (i < len) {
+            vector::push_back(&mut keys, vector::borrow(&map.data, i).key);
+            i = i + 1;
+        };
+        keys
+    }
+
+    /// Return the values of `map`, in the order of its entries.
+    public fun values<Key, Value: copy>(map: &SimpleMap<Key, Value>): vector<Value> {
+        let values = vector::empty();
+        let i = 0;
+        let len = vector::length(&map.data);
+        while (i < len) {
+            vector::push_back(&mut values, vector::borrow(&map.data, i).value);
+            i = i + 1;
+        };
+        values
+    }
+
+    /// Destroy `map`, returning its keys and values in the order of its entries.
+    public fun to_vec_pair<Key, Value>(map: SimpleMap<Key, Value>): (vector<Key>, vector<Value>) {
+        let SimpleMap { data } = map;
+        let keys = vector::empty();
+        let values = vector::empty();
+        vector::reverse(&mut data);
+        while (!vector::is_empty(&data)) {
+            let Element { key, value } = vector::pop_back(&mut data);
+            vector::push_back(&mut keys, key);
+            vector::push_back(&mut values, value);
+        };
+        vector::destroy_empty(data);
+        (keys, values)
+    }
+
+    /// Destroy an empty `map`.
+    /// Aborts if `map` is not empty.
+    public fun destroy_empty<Key, Value>(map: SimpleMap<Key, Value>) {
+        let SimpleMap { data } = map;
+        vector::destroy_empty(data);
+    }
+
+    /// Return the index of the entry for `key`, if any.
+    fun find<Key, Value>(map: &SimpleMap<Key, Value>, key: &Key): Option<u64> {
+        let i = 0;
+        let len = vector::length(&map.data);
+        while (i < len) {
+            if (&vector::borrow(&map.data, i).key == key) {
+                return option::some(i)
+            };
+            i = i + 1;
+        };
+        option::none()
+    }
+
+    spec fun spec_contains_key<Key, Value>(map: SimpleMap<Key, Value>, key: Key): bool {
+        exists i in 0..len(map.data): map.data[i].key == key
+    }
+}
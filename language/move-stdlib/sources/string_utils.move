@@ -0,0 +1,93 @@
+/// Rendering of Move values as strings, and formatting of strings with values.
+///
+/// Values are rendered from their runtime layout:
+/// - integers in decimal, and booleans as `true` or `false`;
+/// - addresses in their shortest hex form prefixed by `@`, e.g. `@0x1`, and signers as
+///   `signer(@0x1)`;
+/// - byte vectors in hex prefixed by `0x`, e.g. `0x0aff`, and other vectors as a list, e.g.
+///   `[1, 2]`;
+/// - strings in quotes, e.g. `"hello"`;
+/// - other structs as their name followed by their fields, e.g. `Coin { value: 10 }`.
+module std::string_utils {
+    use std::string::String;
+
+    /// The number of values does not match the number of `{}` placeholders in the format
+    const EARGS_MISMATCH: u64 = 0x10001;
+    /// The format is not valid UTF-8, or has a brace which is not part of `{}`, `{{` or `}}`
+    const EINVALID_FORMAT: u64 = 0x10002;
+
+    /// A list of values to format, ending with `NIL`.
+    struct Cons<T, N> has copy, drop, store {
+        car: T,
+        cdr: N,
+    }
+
+    /// The end of a list of values to format.
+    struct NIL has copy, drop, store {}
+
+    /// Return the rendering of `v`.
+    native public fun to_string<T>(v: &T): String;
+    spec to_string {
+        pragma opaque;
+    }
+
+    /// Return `fmt` with its `{}` placeholder replaced by the rendering of `a`. `{{` and `}}`
+    /// stand for literal braces.
+    /// Aborts with `EINVALID_FORMAT` if `fmt` is malformed, and with `EARGS_MISMATCH` if it does
+    /// not have exactly one placeholder.
+    public fun format1<T0: drop>(fmt: &vector<u8>, a: T0): String {
+        format_list(fmt, &cons(a, NIL {}))
+    }
+    spec format1 {
+        pragma opaque;
+    }
+
+    /// Return `fmt` with its two `{}` placeholders replaced by the renderings of `a` and `b`,
+    /// like `format1` does.
+    public fun format2<T0: drop, T1: drop>(fmt: &vector<u8>, a: T0, b: T1): String {
+        format_list(fmt, &cons(a, cons(b, NIL {})))
+    }
+    spec format2 {
+        pragma opaque;
+    }
+
+    /// Return `fmt` with its three `{}` placeholders replaced by the renderings of `a`, `b` and
+    /// `c`, like `format1` does.
+    public fun format3<T0: drop, T1: drop, T2: drop>(
+        fmt: &vector<u8>,
+        a: T0,
+        b: T1,
+        c: T2
+    ): String {
+        format_list(fmt, &cons(a, cons(b, cons(c, NIL {}))))
+    }
+    spec format3 {
+        pragma opaque;
+    }
+
+    /// Return `fmt` with its four `{}` placeholders replaced by the renderings of `a`, `b`, `c`
+    /// and `d`, like `format1` does.
+    public fun format4<T0: drop, T1: drop, T2: drop, T3: drop>(
+        fmt: &vector<u8>,
+        a: T0,
+        b: T1,
+        c: T2,
+        d: T3
+    ): String {
+        format_list(fmt, &cons(a, cons(b, cons(c, cons(d, NIL {})))))
+    }
+    spec format4 {
+        pragma opaque;
+    }
+
+    fun cons<T, N>(car: T, cdr: N): Cons<T, N> {
+        Cons { car, cdr }
+    }
+
+    /// Return `fmt` with its placeholders replaced by the renderings of the values of the list
+    /// `values`.
+    native fun format_list<T>(fmt: &vector<u8>, values: &T): String;
+    spec format_list {
+        pragma opaque;
+    }
+}
@@ -0,0 +1,81 @@
+/// Structured information about Move types, complementing `std::type_name`. Use with care!
+module std::type_info {
+    use std::ascii::String;
+    use std::type_name::TypeName;
+
+    /// The type is not a struct
+    const ENOT_STRUCT: u64 = 1;
+
+    /// Ability bits, with the same encoding as in Move bytecode
+    const COPY: u8 = 0x1;
+    const DROP: u8 = 0x2;
+    const STORE: u8 = 0x4;
+    const KEY: u8 = 0x8;
+
+    struct TypeInfo has copy, drop, store {
+        /// The address the struct is defined at
+        account_address: address,
+        /// The name of the module defining the struct, e.g. `string`
+        module_name: String,
+        /// The name of the struct, e.g. `String`
+        struct_name: String,
+        /// The type arguments the struct is instantiated with, in order
+        type_args: vector<TypeName>,
+    }
+
+    /// Return the information of the struct type `T`.
+    /// Aborts with `ENOT_STRUCT` if `T` is not a struct.
+    public native fun type_of<T>(): TypeInfo;
+
+    /// Return the size in bytes of the BCS serialization of `val_ref`.
+    public native fun size_of_val<T>(val_ref: &T): u64;
+
+    /// Return the abilities of `T` as a bit set of `COPY`, `DROP`, `STORE` and `KEY`.
+    native fun abilities<T>(): u8;
+
+    /// Get the address of the module defining the struct
+    public fun account_address(self: &TypeInfo): address {
+        self.account_address
+    }
+
+    /// Get the name of the module defining the struct
+    public fun module_name(self: &TypeInfo): &String {
+        &self.module_name
+    }
+
+    /// Get the name of the struct
+    public fun struct_name(self: &TypeInfo): &String {
+        &self.struct_name
+    }
+
+    /// Get the type arguments of the struct
+    public fun type_args(self: &TypeInfo): &vector<TypeName> {
+        &self.type_args
+    }
+
+    /// Return true if `T` has the `copy` ability
+    public fun has_copy<T>(): bool {
+        has_ability(abilities<T>(), COPY)
+    }
+
+    /// Return true if `T` has the `drop` ability
+    public fun has_drop<T>(): bool {
+        has_ability(abilities<T>(), DROP)
+    }
+
+    /// Return true if `T` has the `store` ability
+    public fun has_store<T>(): bool {
+        has_ability(abilities<T>(), STORE)
+    }
+
+    /// Return true if `T` has the `key` ability
+    public fun has_key<T>(): bool {
+        has_ability(abilities<T>(), KEY)
+    }
+
+    /// Return true if the bit of `ability` is set in `abilities`. This avoids bitwise operators,
+    /// which the prover does not support.
+    fun has_ability(abilities: u8, ability: u8): bool {
+        (abilities / ability) % 2 == 1
+    }
+}
@@ -13,6 +13,9 @@ module std::vector {
     /// The index into the vector is out of bounds
     const EINDEX_OUT_OF_BOUNDS: u64 = 0x20000;
 
+    /// The range of indices is invalid
+    const EINVALID_RANGE: u64 = 0x20001;
+
     #[bytecode_instruction]
     /// Create an empty vector.
     native public fun empty<Element>(): vector<Element>;
@@ -50,6 +53,33 @@ module std::vector {
     /// Aborts if `i` or `j` is out of bounds.
     native public fun swap<Element>(v: &mut vector<Element>, i: u64, j: u64);
 
+    /// Moves the `length` elements of `from` starting at index `removal_position` into `to`, so
+    /// that the first of them ends up at index `insert_position`. The moved elements keep their
+    /// order, and the elements after them in both vectors are shifted.
+    /// This is O(n) in the number of elements moved and shifted, but much cheaper than moving
+    /// them one by one.
+    /// Aborts if the range is out of bounds of `from`, or `insert_position` is greater than the
+    /// length of `to`.
+    native public fun move_range<Element>(
+        from: &mut vector<Element>,
+        removal_position: u64,
+        length: u64,
+        to: &mut vector<Element>,
+        insert_position: u64
+    );
+    spec move_range {
+        pragma opaque;
+        aborts_if removal_position + length > len(from) || insert_position > len(to);
+        ensures from == concat(
+            old(from)[0..removal_position],
+            old(from)[removal_position + length..len(old(from))]
+        );
+        ensures to == concat(
+            concat(old(to)[0..insert_position], old(from)[removal_position..removal_position + length]),
+            old(to)[insert_position..len(old(to))]
+        );
+    }
+
     /// Return an vector of size one containing element `e`.
     public fun singleton<Element>(e: Element): vector<Element> {
         let v = empty();
@@ -159,6 +189,143 @@ module std::vector {
         pragma intrinsic = true;
     }
 
+    /// Insert `e` at index `i` of the vector `v`, shifting all subsequent elements.
+    /// This is O(n) and preserves ordering of elements in the vector.
+    /// Aborts if `i` is greater than the length of `v`.
+    public fun insert<Element>(v: &mut vector<Element>, i: u64, e: Element) {
+        let len = length(v);
+        assert!(i <= len, EINDEX_OUT_OF_BOUNDS);
+        if (i == len) {
+            push_back(v, e);
+        } else {
+            let other = singleton(e);
+            move_range(&mut other, 0, 1, v, i);
+            destroy_empty(other);
+        }
+    }
+    spec insert {
+        pragma opaque;
+        aborts_if i > len(v) with EINDEX_OUT_OF_BOUNDS;
+        ensures len(v) == len(old(v)) + 1;
+        ensures v[0..i] == old(v)[0..i];
+        ensures v[i] == e;
+        ensures v[i + 1..len(v)] == old(v)[i..len(old(v))];
+    }
+
+    /// Return a vector holding copies of the elements of `v` from index `start` up to, but
+    /// excluding, index `end`.
+    /// Aborts if `start` is greater than `end`, or `end` is greater than the length of `v`.
+    public fun slice<Element: copy>(v: &vector<Element>, start: u64, end: u64): vector<Element> {
+        assert!(start <= end && end <= length(v), EINVALID_RANGE);
+        let result = empty();
+        while (start < end) {
+            push_back(&mut result, *borrow(v, start));
+            start = start + 1;
+        };
+        result
+    }
+    spec slice {
+        pragma opaque;
+        aborts_if [abstract] start > end || end > len(v) with EINVALID_RANGE;
+        ensures [abstract] result == v[start..end];
+    }
+
+    /// Rotate the vector `v` in place so that the element at index `rot` becomes the first one,
+    /// and return the new index of the element that was first, which is `length(v) - rot`.
+    /// Aborts if `rot` is greater than the length of `v`.
+    public fun rotate<Element>(v: &mut vector<Element>, rot: u64): u64 {
+        let len = length(v);
+        assert!(rot <= len, EINDEX_OUT_OF_BOUNDS);
+        let front = empty();
+        move_range(v, 0, rot, &mut front, 0);
+        move_range(&mut front, 0, rot, v, len - rot);
+        destroy_empty(front);
+        len - rot
+    }
+    spec rotate {
+        pragma opaque;
+        aborts_if rot > len(v) with EINDEX_OUT_OF_BOUNDS;
+        ensures v == concat(old(v)[rot..len(old(v))], old(v)[0..rot]);
+        ensures result == len(old(v)) - rot;
+    }
+
+    /// Remove the elements of the vector `v` from index `new_len` on, and return them in a new
+    /// vector, in the same order.
+    /// Aborts if `new_len` is greater than the length of `v`.
+    public fun trim<Element>(v: &mut vector<Element>, new_len: u64): vector<Element> {
+        let len = length(v);
+        assert!(new_len <= len, EINDEX_OUT_OF_BOUNDS);
+        let other = empty();
+        move_range(v, new_len, len - new_len, &mut other, 0);
+        other
+    }
+    spec trim {
+        pragma opaque;
+        aborts_if new_len > len(v) with EINDEX_OUT_OF_BOUNDS;
+        ensures v == old(v)[0..new_len];
+        ensures result == old(v)[new_len..len(old(v))];
+    }
+
+    /// Sort the vector `v` in place in the natural order of its elements. Integers, booleans and
+    /// addresses are ordered as usual, while vectors and structs are ordered lexicographically,
+    /// element by element or field by field in declaration order. Equal elements keep their
+    /// relative order.
+    native public fun sort<Element>(v: &mut vector<Element>);
+    spec sort {
+        pragma opaque;
+        aborts_if false;
+        ensures len(v) == len(old(v));
+        ensures forall i in 0..len(v): exists j in 0..len(old(v)): v[i] == old(v)[j];
+    }
+
+    /// Sort the vector `keys` in place like `sort` does, and reorder the vector `v` the same way,
+    /// so that each element of `v` stays at the index of its key.
+    /// Aborts if `v` and `keys` differ in length.
+    native public fun sort_by_key<Element, Key>(v: &mut vector<Element>, keys: &mut vector<Key>);
+    spec sort_by_key {
+        pragma opaque;
+        aborts_if len(v) != len(keys);
+        ensures len(v) == len(old(v));
+        ensures len(keys) == len(old(keys));
+    }
+
+    /// Search the vector `v`, which must be sorted in the natural order of its elements (see
+    /// `sort`), for `e`. Return `(true, i)` if `e` is at index `i`, and otherwise `(false, i)`
+    /// where `i` is the index at which `e` can be inserted to keep `v` sorted.
+    /// If several elements are equal to `e`, the index of any of them may be returned.
+    native public fun binary_search<Element>(v: &vector<Element>, e: &Element): (bool, u64);
+    spec binary_search {
+        pragma opaque;
+        aborts_if false;
+        ensures result_2 <= len(v);
+        ensures result_1 ==> v[result_2] == e;
+    }
+
+    /// Remove consecutive repeated elements from the vector `v`, keeping the first of each run.
+    /// On a sorted vector, this removes all duplicates.
+    public fun dedup<Element: drop>(v: &mut vector<Element>) {
+        let len = length(v);
+        if (len == 0) return ();
+
+        // Swap each element differing from the last one kept right after it, then drop the
+        // repeated elements which ended up at the end.
+        let kept = 1;
+        let i = 1;
+        while (i < len) {
+            if (borrow(v, i) != borrow(v, kept - 1)) {
+                swap(v, i, kept);
+                kept = kept + 1;
+            };
+            i = i + 1;
+        };
+        trim(v, kept);
+    }
+    spec dedup {
+        pragma opaque;
+        aborts_if [abstract] false;
+        ensures [abstract] len(v) <= len(old(v));
+    }
+
     // =================================================================
     // Module Specification
 
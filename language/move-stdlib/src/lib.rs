@@ -16,8 +16,10 @@ pub mod natives;
 
 const MODULES_DIR: &str = "sources";
 const NURSERY_DIR: &str = "nursery";
+const CRYPTO_DIR: &str = "crypto/sources";
 const DOCS_DIR: &str = "docs";
 const NURSERY_DOCS_DIR: &str = "nursery/docs";
+const CRYPTO_DOCS_DIR: &str = "crypto/docs";
 const ERRMAP_FILE: &str = "error_description.errmap";
 
 const REFERENCES_TEMPLATE: &str = "doc_templates/references.md";
@@ -51,6 +53,10 @@ pub fn move_nursery_docs_full_path() -> String {
     format!("{}/{}", env!("CARGO_MANIFEST_DIR"), NURSERY_DOCS_DIR)
 }
 
+pub fn move_crypto_docs_full_path() -> String {
+    format!("{}/{}", env!("CARGO_MANIFEST_DIR"), CRYPTO_DOCS_DIR)
+}
+
 pub fn move_stdlib_errmap_full_path() -> String {
     let prefix = if AccountAddress::LENGTH == 16 {
         "".to_string()
@@ -70,6 +76,11 @@ pub fn move_nursery_files() -> Vec<String> {
     find_filenames(&[path], |p| extension_equals(p, MOVE_EXTENSION)).unwrap()
 }
 
+pub fn move_crypto_files() -> Vec<String> {
+    let path = path_in_crate(CRYPTO_DIR);
+    find_filenames(&[path], |p| extension_equals(p, MOVE_EXTENSION)).unwrap()
+}
+
 pub fn move_stdlib_named_addresses() -> BTreeMap<String, NumericalAddress> {
     let mapping = [("std", "0x1")];
     mapping
@@ -141,6 +152,19 @@ pub fn build_nursery_doc(output_path: &str) {
     )
 }
 
+pub fn build_crypto_doc(output_path: &str) {
+    build_doc(
+        output_path,
+        "",
+        vec![],
+        None,
+        move_crypto_files().as_slice(),
+        vec![move_stdlib_modules_full_path()],
+        false,
+        move_stdlib_named_addresses(),
+    )
+}
+
 pub fn build_error_code_map(output_path: &str) {
     let options = move_prover::cli::Options {
         move_sources: crate::move_stdlib_files(),
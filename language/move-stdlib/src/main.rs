@@ -18,6 +18,11 @@ fn main() {
             move_stdlib::build_nursery_doc(&move_stdlib::move_nursery_docs_full_path());
         });
 
+        time_it("Generating crypto documentation", || {
+            std::fs::remove_dir_all(&move_stdlib::move_crypto_docs_full_path()).unwrap_or(());
+            move_stdlib::build_crypto_doc(&move_stdlib::move_crypto_docs_full_path());
+        });
+
         time_it("Generating error explanations", || {
             std::fs::remove_file(&move_stdlib::move_stdlib_errmap_full_path()).unwrap_or(());
             move_stdlib::build_error_code_map(&move_stdlib::move_stdlib_errmap_full_path());
@@ -0,0 +1,181 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Implementation of native functions for Base64 encoding, with the standard alphabet and
+//! padding of RFC 4648.
+
+use crate::natives::helpers::make_module_natives;
+use move_binary_format::errors::PartialVMResult;
+use move_core_types::gas_algebra::{InternalGas, InternalGasPerByte, NumBytes};
+use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
+use move_vm_types::{
+    loaded_data::runtime_types::Type,
+    natives::function::NativeResult,
+    pop_arg,
+    values::{Struct, Value, VectorRef},
+};
+use smallvec::smallvec;
+use std::{collections::VecDeque, sync::Arc};
+
+/// Abort code when the input is not valid Base64, `std::base64::EINVALID_BASE64`.
+const EINVALID_BASE64: u64 = 0x10001;
+
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const PAD: u8 = b'=';
+
+fn encode(bytes: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity((bytes.len() + 2) / 3 * 4);
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let n = u32::from_be_bytes([0, b[0], b[1], b[2]]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize]);
+            } else {
+                out.push(PAD);
+            }
+        }
+    }
+    out
+}
+
+fn decode_char(c: u8) -> Option<u32> {
+    let v = match c {
+        b'A'..=b'Z' => c - b'A',
+        b'a'..=b'z' => c - b'a' + 26,
+        b'0'..=b'9' => c - b'0' + 52,
+        b'+' => 62,
+        b'/' => 63,
+        _ => return None,
+    };
+    Some(v as u32)
+}
+
+/// Decode `s`, or return `None` if its length is not a multiple of 4, it has a character outside
+/// of the alphabet, it is not padded as expected, or its unused trailing bits are not zero.
+fn decode(s: &[u8]) -> Option<Vec<u8>> {
+    if s.len() % 4 != 0 {
+        return None;
+    }
+    let mut out = Vec::with_capacity(s.len() / 4 * 3);
+    let num_chunks = s.len() / 4;
+    for (i, chunk) in s.chunks(4).enumerate() {
+        // Padding is only allowed at the end of the last chunk, for at most two characters.
+        let padding = chunk.iter().rev().take_while(|c| **c == PAD).count();
+        if padding > 2 || (padding > 0 && i + 1 != num_chunks) {
+            return None;
+        }
+        let mut n = 0u32;
+        for c in &chunk[..4 - padding] {
+            n = n << 6 | decode_char(*c)?;
+        }
+        n <<= 6 * padding as u32;
+        let [_, b0, b1, b2] = n.to_be_bytes();
+        match padding {
+            0 => out.extend([b0, b1, b2]),
+            1 if b2 == 0 => out.extend([b0, b1]),
+            2 if b1 == 0 && b2 == 0 => out.push(b0),
+            _ => return None,
+        }
+    }
+    Some(out)
+}
+
+/***************************************************************************************************
+ * native fun encode
+ *
+ *   gas cost: base_cost + unit_cost * input_length_in_bytes
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct EncodeGasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+}
+
+fn native_encode(
+    gas_params: &EncodeGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(args.len() == 1);
+    let bytes_arg = pop_arg!(args, VectorRef);
+    let bytes_ref = bytes_arg.as_bytes_ref();
+
+    let cost = gas_params.base + gas_params.per_byte * NumBytes::new(bytes_ref.len() as u64);
+
+    // make a std::string::String
+    let string_val = Value::struct_(Struct::pack(vec![Value::vector_u8(encode(
+        bytes_ref.as_slice(),
+    ))]));
+    Ok(NativeResult::ok(cost, smallvec![string_val]))
+}
+
+pub fn make_native_encode(gas_params: EncodeGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_encode(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * native fun internal_decode
+ *
+ *   gas cost: base_cost + unit_cost * input_length_in_bytes
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct DecodeGasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+}
+
+fn native_decode(
+    gas_params: &DecodeGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(args.len() == 1);
+    let s_arg = pop_arg!(args, VectorRef);
+    let s_ref = s_arg.as_bytes_ref();
+
+    let cost = gas_params.base + gas_params.per_byte * NumBytes::new(s_ref.len() as u64);
+
+    match decode(s_ref.as_slice()) {
+        Some(bytes) => Ok(NativeResult::ok(cost, smallvec![Value::vector_u8(bytes)])),
+        None => Ok(NativeResult::err(cost, EINVALID_BASE64)),
+    }
+}
+
+pub fn make_native_decode(gas_params: DecodeGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_decode(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * module
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct GasParameters {
+    pub encode: EncodeGasParameters,
+    pub decode: DecodeGasParameters,
+}
+
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let natives = [
+        ("encode", make_native_encode(gas_params.encode)),
+        ("internal_decode", make_native_decode(gas_params.decode)),
+    ];
+
+    make_module_natives(natives)
+}
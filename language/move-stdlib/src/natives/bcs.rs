@@ -6,6 +6,8 @@ use crate::natives::helpers::make_module_natives;
 use move_binary_format::errors::PartialVMResult;
 use move_core_types::{
     gas_algebra::{InternalGas, InternalGasPerByte, NumBytes},
+    language_storage::ModuleId,
+    value::{MoveStructLayout, MoveTypeLayout},
     vm_status::sub_status::NFE_BCS_SERIALIZATION_FAILURE,
 };
 use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
@@ -89,16 +91,110 @@ pub fn make_native_to_bytes(gas_params: ToBytesGasParameters) -> NativeFunction
     )
 }
 
+/***************************************************************************************************
+ * native fun from_bytes
+ *
+ *   gas cost: base_cost + unit_cost * size_of(input)
+ *
+ *             If the type cannot be deserialized, only the base cost and the failure cost are
+ *             charged.
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct FromBytesGasParameters {
+    pub base: InternalGas,
+    pub per_byte_deserialized: InternalGasPerByte,
+    pub failure: InternalGas,
+}
+
+/// Abort code when the input is not the serialization of a value, `std::bcs::EMALFORMED_BYTES`.
+const EMALFORMED_BYTES: u64 = 1;
+/// Abort code when the type cannot be deserialized, `std::bcs::ETYPE_NOT_ALLOWED`.
+const ETYPE_NOT_ALLOWED: u64 = 2;
+
+/// Return whether a value of `layout` can be built by `module`: it may not contain signers, nor
+/// structs which are declared by another module.
+fn is_constructible_by(layout: &MoveTypeLayout, module: Option<&ModuleId>) -> bool {
+    match layout {
+        MoveTypeLayout::Bool
+        | MoveTypeLayout::U8
+        | MoveTypeLayout::U64
+        | MoveTypeLayout::U128
+        | MoveTypeLayout::Address => true,
+        MoveTypeLayout::Signer => false,
+        MoveTypeLayout::Vector(elem) => is_constructible_by(elem, module),
+        MoveTypeLayout::Struct(MoveStructLayout::WithTypes { type_, fields }) => {
+            module == Some(&type_.module_id())
+                && fields
+                    .iter()
+                    .all(|field| is_constructible_by(&field.layout, module))
+        }
+        // a fully annotated layout only has annotated structs
+        MoveTypeLayout::Struct(_) => false,
+    }
+}
+
+/// Rust implementation of Move's `native public fun from_bytes<T>(vector<u8>): T`
+#[inline]
+fn native_from_bytes(
+    gas_params: &FromBytesGasParameters,
+    context: &mut NativeContext,
+    mut ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.len() == 1);
+    debug_assert!(args.len() == 1);
+
+    let mut cost = gas_params.base;
+
+    let bytes = pop_arg!(args, Vec<u8>);
+    let ty = ty_args.pop().unwrap();
+
+    let (annotated_layout, layout) = match (
+        context.type_to_fully_annotated_layout(&ty)?,
+        context.type_to_type_layout(&ty)?,
+    ) {
+        (Some(annotated_layout), Some(layout)) => (annotated_layout, layout),
+        _ => {
+            cost += gas_params.failure;
+            return Ok(NativeResult::err(cost, NFE_BCS_SERIALIZATION_FAILURE));
+        }
+    };
+    // only the module declaring a struct may deserialize it, as it could build it anyway
+    if !is_constructible_by(&annotated_layout, context.caller_module()) {
+        cost += gas_params.failure;
+        return Ok(NativeResult::err(cost, ETYPE_NOT_ALLOWED));
+    }
+
+    cost += gas_params.per_byte_deserialized * NumBytes::new(bytes.len() as u64);
+    match Value::simple_deserialize(&bytes, &layout) {
+        Some(val) => Ok(NativeResult::ok(cost, smallvec![val])),
+        None => Ok(NativeResult::err(cost, EMALFORMED_BYTES)),
+    }
+}
+
+pub fn make_native_from_bytes(gas_params: FromBytesGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_from_bytes(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
 /***************************************************************************************************
  * module
  **************************************************************************************************/
 #[derive(Debug, Clone)]
 pub struct GasParameters {
     pub to_bytes: ToBytesGasParameters,
+    pub from_bytes: FromBytesGasParameters,
 }
 
 pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
-    let natives = [("to_bytes", make_native_to_bytes(gas_params.to_bytes))];
+    let natives = [
+        ("to_bytes", make_native_to_bytes(gas_params.to_bytes)),
+        ("from_bytes", make_native_from_bytes(gas_params.from_bytes)),
+    ];
 
     make_module_natives(natives)
 }
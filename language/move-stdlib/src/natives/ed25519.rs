@@ -0,0 +1,164 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::natives::helpers::make_module_natives;
+use ed25519_dalek::{
+    PublicKey, Signature, PUBLIC_KEY_LENGTH as ED25519_PUBLIC_KEY_LENGTH,
+    SIGNATURE_LENGTH as ED25519_SIGNATURE_LENGTH,
+};
+use move_binary_format::errors::PartialVMResult;
+use move_core_types::gas_algebra::{InternalGas, InternalGasPerByte, NumBytes};
+use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
+use move_vm_types::{
+    loaded_data::runtime_types::Type, natives::function::NativeResult, pop_arg, values::Value,
+};
+use smallvec::smallvec;
+use std::{cmp::Ordering, collections::VecDeque, convert::TryFrom, sync::Arc};
+
+/// The order of ed25519 as defined in [RFC8032](https://tools.ietf.org/html/rfc8032).
+const L: [u8; 32] = [
+    0xed, 0xd3, 0xf5, 0x5c, 0x1a, 0x63, 0x12, 0x58, 0xd6, 0x9c, 0xf7, 0xa2, 0xde, 0xf9, 0xde, 0x14,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10,
+];
+
+/// Deserialize a public key, which must be a point on the curve outside of the small subgroup.
+pub(crate) fn deserialize_public_key(bytes: &[u8]) -> Option<PublicKey> {
+    // We need to access the Edwards point which is not directly accessible from
+    // ed25519_dalek::PublicKey, so we need to do some custom deserialization.
+    let bits = <[u8; ED25519_PUBLIC_KEY_LENGTH]>::try_from(bytes).ok()?;
+    let point = curve25519_dalek::edwards::CompressedEdwardsY(bits).decompress()?;
+    // Check if the point lies on a small subgroup. This is required
+    // when using curves with a small cofactor (in ed25519, cofactor = 8).
+    if point.is_small_order() {
+        return None;
+    }
+    PublicKey::from_bytes(bytes).ok()
+}
+
+/// Deserialize a signature, which must be canonical: its `S` must be lower than `L`.
+pub(crate) fn deserialize_signature(bytes: &[u8]) -> Option<Signature> {
+    if bytes.len() != ED25519_SIGNATURE_LENGTH {
+        return None;
+    }
+    for i in (0..32).rev() {
+        match bytes[32 + i].cmp(&L[i]) {
+            Ordering::Less => return Signature::try_from(bytes).ok(),
+            Ordering::Greater => return None,
+            Ordering::Equal => (),
+        }
+    }
+    // As this stage S == L which implies a non canonical S.
+    None
+}
+
+/***************************************************************************************************
+ * native fun public_key_validate_internal
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct PublicKeyValidateGasParameters {
+    pub base: InternalGas,
+}
+
+fn native_public_key_validate(
+    gas_params: &PublicKeyValidateGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 1);
+
+    let key_bytes = pop_arg!(arguments, Vec<u8>);
+
+    let valid = deserialize_public_key(&key_bytes).is_some();
+
+    Ok(NativeResult::ok(
+        gas_params.base,
+        smallvec![Value::bool(valid)],
+    ))
+}
+
+pub fn make_native_public_key_validate(
+    gas_params: PublicKeyValidateGasParameters,
+) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_public_key_validate(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * native fun signature_verify_strict_internal
+ *
+ *   gas cost: base_cost + unit_cost * message_length_in_bytes
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct SignatureVerifyStrictGasParameters {
+    pub base: InternalGas,
+    pub per_msg_byte: InternalGasPerByte,
+}
+
+fn native_signature_verify_strict(
+    gas_params: &SignatureVerifyStrictGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 3);
+
+    let msg = pop_arg!(arguments, Vec<u8>);
+    let pubkey = pop_arg!(arguments, Vec<u8>);
+    let signature = pop_arg!(arguments, Vec<u8>);
+
+    let cost = gas_params.base + gas_params.per_msg_byte * NumBytes::new(msg.len() as u64);
+
+    let valid = match (
+        deserialize_signature(&signature),
+        deserialize_public_key(&pubkey),
+    ) {
+        (Some(sig), Some(pk)) => pk.verify_strict(&msg, &sig).is_ok(),
+        _ => false,
+    };
+
+    Ok(NativeResult::ok(cost, smallvec![Value::bool(valid)]))
+}
+
+pub fn make_native_signature_verify_strict(
+    gas_params: SignatureVerifyStrictGasParameters,
+) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_signature_verify_strict(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * module
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct GasParameters {
+    pub public_key_validate: PublicKeyValidateGasParameters,
+    pub signature_verify_strict: SignatureVerifyStrictGasParameters,
+}
+
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let natives = [
+        (
+            "public_key_validate_internal",
+            make_native_public_key_validate(gas_params.public_key_validate),
+        ),
+        (
+            "signature_verify_strict_internal",
+            make_native_signature_verify_strict(gas_params.signature_verify_strict),
+        ),
+    ];
+
+    make_module_natives(natives)
+}
@@ -0,0 +1,65 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use move_binary_format::errors::PartialVMResult;
+use move_core_types::gas_algebra::{InternalGas, InternalGasPerByte, NumBytes};
+use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
+use move_vm_types::{
+    loaded_data::runtime_types::Type, natives::function::NativeResult, pop_arg, values::Value,
+};
+
+use std::{collections::VecDeque, sync::Arc};
+
+/***************************************************************************************************
+ * native fun abort_with_message
+ *
+ *   gas cost: base_cost + unit_cost * message_length_in_bytes
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct AbortWithMessageGasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+}
+
+fn native_abort_with_message(
+    gas_params: &AbortWithMessageGasParameters,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 2);
+
+    let message = pop_arg!(args, Vec<u8>);
+    let code = pop_arg!(args, u64);
+
+    let cost = gas_params.base + gas_params.per_byte * NumBytes::new(message.len() as u64);
+    // Invalid UTF-8 sequences are replaced rather than failing, the abort code being what matters.
+    let message = String::from_utf8_lossy(&message).into_owned();
+
+    Ok(NativeResult::err_with_message(cost, code, message))
+}
+
+pub fn make_native_abort_with_message(gas_params: AbortWithMessageGasParameters) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| {
+        native_abort_with_message(&gas_params, context, ty_args, args)
+    })
+}
+
+/***************************************************************************************************
+ * module
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct GasParameters {
+    pub abort_with_message: AbortWithMessageGasParameters,
+}
+
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let natives = [(
+        "abort_with_message",
+        make_native_abort_with_message(gas_params.abort_with_message),
+    )];
+
+    crate::natives::helpers::make_module_natives(natives)
+}
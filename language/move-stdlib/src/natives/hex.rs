@@ -0,0 +1,115 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Implementation of native functions for hex encoding.
+
+use crate::natives::helpers::make_module_natives;
+use move_binary_format::errors::PartialVMResult;
+use move_core_types::gas_algebra::{InternalGas, InternalGasPerByte, NumBytes};
+use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
+use move_vm_types::{
+    loaded_data::runtime_types::Type,
+    natives::function::NativeResult,
+    pop_arg,
+    values::{Struct, Value, VectorRef},
+};
+use smallvec::smallvec;
+use std::{collections::VecDeque, sync::Arc};
+
+/// Abort code when the input is not valid hex, `std::hex::EINVALID_HEX`.
+const EINVALID_HEX: u64 = 0x10001;
+
+/***************************************************************************************************
+ * native fun encode
+ *
+ *   gas cost: base_cost + unit_cost * input_length_in_bytes
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct EncodeGasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+}
+
+fn native_encode(
+    gas_params: &EncodeGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(args.len() == 1);
+    let bytes_arg = pop_arg!(args, VectorRef);
+    let bytes_ref = bytes_arg.as_bytes_ref();
+
+    let cost = gas_params.base + gas_params.per_byte * NumBytes::new(bytes_ref.len() as u64);
+
+    // make a std::string::String
+    let string_val = Value::struct_(Struct::pack(vec![Value::vector_u8(
+        hex::encode(bytes_ref.as_slice()).into_bytes(),
+    )]));
+    Ok(NativeResult::ok(cost, smallvec![string_val]))
+}
+
+pub fn make_native_encode(gas_params: EncodeGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_encode(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * native fun internal_decode
+ *
+ *   gas cost: base_cost + unit_cost * input_length_in_bytes
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct DecodeGasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+}
+
+fn native_decode(
+    gas_params: &DecodeGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(args.len() == 1);
+    let s_arg = pop_arg!(args, VectorRef);
+    let s_ref = s_arg.as_bytes_ref();
+
+    let cost = gas_params.base + gas_params.per_byte * NumBytes::new(s_ref.len() as u64);
+
+    match hex::decode(s_ref.as_slice()) {
+        Ok(bytes) => Ok(NativeResult::ok(cost, smallvec![Value::vector_u8(bytes)])),
+        Err(_) => Ok(NativeResult::err(cost, EINVALID_HEX)),
+    }
+}
+
+pub fn make_native_decode(gas_params: DecodeGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_decode(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * module
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct GasParameters {
+    pub encode: EncodeGasParameters,
+    pub decode: DecodeGasParameters,
+}
+
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let natives = [
+        ("encode", make_native_encode(gas_params.encode)),
+        ("internal_decode", make_native_decode(gas_params.decode)),
+    ];
+
+    make_module_natives(natives)
+}
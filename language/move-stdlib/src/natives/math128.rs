@@ -0,0 +1,87 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::natives::helpers::make_module_natives;
+use ethnum::U256;
+use move_binary_format::errors::PartialVMResult;
+use move_core_types::gas_algebra::InternalGas;
+use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
+use move_vm_types::{
+    loaded_data::runtime_types::Type, natives::function::NativeResult, pop_arg, values::Value,
+};
+use smallvec::smallvec;
+use std::{collections::VecDeque, sync::Arc};
+
+/// Abort code when the divisor is zero, `std::math128::EDIVISION_BY_ZERO`.
+const EDIVISION_BY_ZERO: u64 = 0x10001;
+
+/***************************************************************************************************
+ * native fun mul_div_internal
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct MulDivGasParameters {
+    pub base: InternalGas,
+}
+
+fn native_mul_div(
+    gas_params: &MulDivGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 4);
+
+    let round_up = pop_arg!(arguments, bool);
+    let c = pop_arg!(arguments, u128);
+    let b = pop_arg!(arguments, u128);
+    let a = pop_arg!(arguments, u128);
+
+    // The Move callers already rule out a zero divisor, but the native must not rely on them.
+    if c == 0 {
+        return Ok(NativeResult::err(gas_params.base, EDIVISION_BY_ZERO));
+    }
+
+    // The product of two u128 always fits in 256 bits.
+    let c = U256::from(c);
+    let product = U256::from(a) * U256::from(b);
+    let mut quotient = product / c;
+    if round_up && product % c != 0 {
+        quotient += 1;
+    }
+    let (result, fits) = if quotient <= U256::from(u128::MAX) {
+        (quotient.as_u128(), true)
+    } else {
+        (0, false)
+    };
+
+    Ok(NativeResult::ok(
+        gas_params.base,
+        smallvec![Value::u128(result), Value::bool(fits)],
+    ))
+}
+
+pub fn make_native_mul_div(gas_params: MulDivGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_mul_div(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * module
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct GasParameters {
+    pub mul_div: MulDivGasParameters,
+}
+
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let natives = [("mul_div_internal", make_native_mul_div(gas_params.mul_div))];
+
+    make_module_natives(natives)
+}
@@ -2,12 +2,25 @@
 // Copyright (c) The Move Contributors
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod base64;
 pub mod bcs;
 pub mod debug;
+#[cfg(feature = "crypto")]
+pub mod ed25519;
+pub mod error;
 pub mod event;
 pub mod hash;
+pub mod hex;
+pub mod math128;
+#[cfg(feature = "crypto")]
+pub mod multi_ed25519;
+pub mod random;
+#[cfg(feature = "crypto")]
+pub mod secp256k1;
 pub mod signer;
 pub mod string;
+pub mod string_utils;
+pub mod type_info;
 pub mod type_name;
 #[cfg(feature = "testing")]
 pub mod unit_test;
@@ -20,10 +33,16 @@ use move_vm_runtime::native_functions::{make_table_from_iter, NativeFunctionTabl
 
 #[derive(Debug, Clone)]
 pub struct GasParameters {
+    pub base64: base64::GasParameters,
     pub bcs: bcs::GasParameters,
+    pub error: error::GasParameters,
     pub hash: hash::GasParameters,
+    pub hex: hex::GasParameters,
+    pub math128: math128::GasParameters,
     pub signer: signer::GasParameters,
     pub string: string::GasParameters,
+    pub string_utils: string_utils::GasParameters,
+    pub type_info: type_info::GasParameters,
     pub type_name: type_name::GasParameters,
     pub vector: vector::GasParameters,
 
@@ -34,12 +53,34 @@ pub struct GasParameters {
 impl GasParameters {
     pub fn zeros() -> Self {
         Self {
+            base64: base64::GasParameters {
+                encode: base64::EncodeGasParameters {
+                    base: 0.into(),
+                    per_byte: 0.into(),
+                },
+                decode: base64::DecodeGasParameters {
+                    base: 0.into(),
+                    per_byte: 0.into(),
+                },
+            },
             bcs: bcs::GasParameters {
                 to_bytes: bcs::ToBytesGasParameters {
                     per_byte_serialized: 0.into(),
                     legacy_min_output_size: 0.into(),
                     failure: 0.into(),
                 },
+                from_bytes: bcs::FromBytesGasParameters {
+                    base: 0.into(),
+                    per_byte_deserialized: 0.into(),
+                    failure: 0.into(),
+                },
+            },
+
+            error: error::GasParameters {
+                abort_with_message: error::AbortWithMessageGasParameters {
+                    base: 0.into(),
+                    per_byte: 0.into(),
+                },
             },
 
             hash: hash::GasParameters {
@@ -53,6 +94,43 @@ impl GasParameters {
                     per_byte: 0.into(),
                     legacy_min_input_len: 0.into(),
                 },
+                keccak256: hash::Keccak256GasParameters {
+                    base: 0.into(),
+                    per_byte: 0.into(),
+                },
+                blake2b_256: hash::Blake2b256GasParameters {
+                    base: 0.into(),
+                    per_byte: 0.into(),
+                },
+                ripemd160: hash::Ripemd160GasParameters {
+                    base: 0.into(),
+                    per_byte: 0.into(),
+                },
+                sha2_512: hash::Sha2_512GasParameters {
+                    base: 0.into(),
+                    per_byte: 0.into(),
+                },
+            },
+            hex: hex::GasParameters {
+                encode: hex::EncodeGasParameters {
+                    base: 0.into(),
+                    per_byte: 0.into(),
+                },
+                decode: hex::DecodeGasParameters {
+                    base: 0.into(),
+                    per_byte: 0.into(),
+                },
+            },
+            type_info: type_info::GasParameters {
+                type_of: type_info::TypeOfGasParameters {
+                    base: 0.into(),
+                    per_byte: 0.into(),
+                },
+                size_of_val: type_info::SizeOfValGasParameters {
+                    base: 0.into(),
+                    per_byte_serialized: 0.into(),
+                },
+                abilities: type_info::AbilitiesGasParameters { base: 0.into() },
             },
             type_name: type_name::GasParameters {
                 get: type_name::GetGasParameters {
@@ -60,6 +138,10 @@ impl GasParameters {
                     per_byte: 0.into(),
                 },
             },
+            math128: math128::GasParameters {
+                mul_div: math128::MulDivGasParameters { base: 0.into() },
+            },
+
             signer: signer::GasParameters {
                 borrow_address: signer::BorrowAddressGasParameters { base: 0.into() },
             },
@@ -79,6 +161,16 @@ impl GasParameters {
                     per_byte_searched: 0.into(),
                 },
             },
+            string_utils: string_utils::GasParameters {
+                to_string: string_utils::ToStringGasParameters {
+                    base: 0.into(),
+                    per_byte: 0.into(),
+                },
+                format: string_utils::FormatGasParameters {
+                    base: 0.into(),
+                    per_byte: 0.into(),
+                },
+            },
             vector: vector::GasParameters {
                 empty: vector::EmptyGasParameters { base: 0.into() },
                 length: vector::LengthGasParameters { base: 0.into() },
@@ -90,6 +182,20 @@ impl GasParameters {
                 pop_back: vector::PopBackGasParameters { base: 0.into() },
                 destroy_empty: vector::DestroyEmptyGasParameters { base: 0.into() },
                 swap: vector::SwapGasParameters { base: 0.into() },
+                move_range: vector::MoveRangeGasParameters {
+                    base: 0.into(),
+                    per_index_moved: 0.into(),
+                },
+                sort: vector::SortGasParameters {
+                    base: 0.into(),
+                    per_comparison: 0.into(),
+                    legacy_per_abstract_memory_unit: 0.into(),
+                },
+                binary_search: vector::BinarySearchGasParameters {
+                    base: 0.into(),
+                    per_comparison: 0.into(),
+                    legacy_per_abstract_memory_unit: 0.into(),
+                },
             },
             #[cfg(feature = "testing")]
             unit_test: unit_test::GasParameters {
@@ -116,10 +222,19 @@ pub fn all_natives(
         };
     }
 
+    add_natives!("base64", base64::make_all(gas_params.base64));
     add_natives!("bcs", bcs::make_all(gas_params.bcs));
+    add_natives!("error", error::make_all(gas_params.error));
     add_natives!("hash", hash::make_all(gas_params.hash));
+    add_natives!("hex", hex::make_all(gas_params.hex));
+    add_natives!("math128", math128::make_all(gas_params.math128));
     add_natives!("signer", signer::make_all(gas_params.signer));
     add_natives!("string", string::make_all(gas_params.string));
+    add_natives!(
+        "string_utils",
+        string_utils::make_all(move_std_addr, gas_params.string_utils)
+    );
+    add_natives!("type_info", type_info::make_all(gas_params.type_info));
     add_natives!("type_name", type_name::make_all(gas_params.type_name));
     add_natives!("vector", vector::make_all(gas_params.vector));
     #[cfg(feature = "testing")]
@@ -134,6 +249,7 @@ pub fn all_natives(
 pub struct NurseryGasParameters {
     event: event::GasParameters,
     debug: debug::GasParameters,
+    random: random::GasParameters,
 }
 
 impl NurseryGasParameters {
@@ -152,6 +268,13 @@ impl NurseryGasParameters {
                     base_cost: 0.into(),
                 },
             },
+            random: random::GasParameters {
+                next_u64: random::NextU64GasParameters { base: 0.into() },
+                bytes: random::BytesGasParameters {
+                    base: 0.into(),
+                    per_byte: 0.into(),
+                },
+            },
         }
     }
 }
@@ -172,6 +295,71 @@ pub fn nursery_natives(
 
     add_natives!("event", event::make_all(gas_params.event));
     add_natives!("debug", debug::make_all(gas_params.debug));
+    add_natives!("random", random::make_all(gas_params.random));
+
+    make_table_from_iter(move_std_addr, natives)
+}
+
+#[cfg(feature = "crypto")]
+#[derive(Debug, Clone)]
+pub struct CryptoGasParameters {
+    pub ed25519: ed25519::GasParameters,
+    pub multi_ed25519: multi_ed25519::GasParameters,
+    pub secp256k1: secp256k1::GasParameters,
+}
+
+#[cfg(feature = "crypto")]
+impl CryptoGasParameters {
+    pub fn zeros() -> Self {
+        Self {
+            ed25519: ed25519::GasParameters {
+                public_key_validate: ed25519::PublicKeyValidateGasParameters { base: 0.into() },
+                signature_verify_strict: ed25519::SignatureVerifyStrictGasParameters {
+                    base: 0.into(),
+                    per_msg_byte: 0.into(),
+                },
+            },
+            multi_ed25519: multi_ed25519::GasParameters {
+                public_key_validate: multi_ed25519::PublicKeyValidateGasParameters {
+                    base: 0.into(),
+                    per_public_key: 0.into(),
+                },
+                signature_verify_strict: multi_ed25519::SignatureVerifyStrictGasParameters {
+                    base: 0.into(),
+                    per_signature: 0.into(),
+                    per_msg_byte_per_signature: 0.into(),
+                },
+            },
+            secp256k1: secp256k1::GasParameters {
+                ecdsa_recover: secp256k1::EcdsaRecoverGasParameters { base: 0.into() },
+                ecdsa_verify: secp256k1::EcdsaVerifyGasParameters { base: 0.into() },
+            },
+        }
+    }
+}
+
+/// The natives of the `MoveCrypto` package, which provides signature verification.
+#[cfg(feature = "crypto")]
+pub fn crypto_natives(
+    move_std_addr: AccountAddress,
+    gas_params: CryptoGasParameters,
+) -> NativeFunctionTable {
+    let mut natives = vec![];
+
+    macro_rules! add_natives {
+        ($module_name: expr, $natives: expr) => {
+            natives.extend(
+                $natives.map(|(func_name, func)| ($module_name.to_string(), func_name, func)),
+            );
+        };
+    }
+
+    add_natives!("ed25519", ed25519::make_all(gas_params.ed25519));
+    add_natives!(
+        "multi_ed25519",
+        multi_ed25519::make_all(gas_params.multi_ed25519)
+    );
+    add_natives!("secp256k1", secp256k1::make_all(gas_params.secp256k1));
 
     make_table_from_iter(move_std_addr, natives)
 }
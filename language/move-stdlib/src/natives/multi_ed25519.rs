@@ -0,0 +1,191 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! A multi-ed25519 public key is the concatenation of at most `MAX_NUM_OF_KEYS` ed25519 public
+//! keys followed by a one byte threshold. A signature is the concatenation of the ed25519
+//! signatures, ordered by the index of the key which produced them, followed by a four byte
+//! bitmap of these indices, the most significant bit of the first byte being index 0.
+
+use crate::natives::{
+    ed25519::{deserialize_public_key, deserialize_signature},
+    helpers::make_module_natives,
+};
+use ed25519_dalek::{
+    PublicKey, PUBLIC_KEY_LENGTH as ED25519_PUBLIC_KEY_LENGTH,
+    SIGNATURE_LENGTH as ED25519_SIGNATURE_LENGTH,
+};
+use move_binary_format::errors::PartialVMResult;
+use move_core_types::gas_algebra::{
+    InternalGas, InternalGasPerArg, InternalGasPerByte, NumArgs, NumBytes,
+};
+use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
+use move_vm_types::{
+    loaded_data::runtime_types::Type, natives::function::NativeResult, pop_arg, values::Value,
+};
+use smallvec::smallvec;
+use std::{collections::VecDeque, sync::Arc};
+
+/// Maximum number of ed25519 keys in a multi-ed25519 public key.
+const MAX_NUM_OF_KEYS: usize = 32;
+const BITMAP_LENGTH: usize = 4;
+
+/// Deserialize the keys and the threshold of a public key, which must be between 1 and the number
+/// of keys.
+fn deserialize_public_key_and_threshold(bytes: &[u8]) -> Option<(Vec<PublicKey>, usize)> {
+    let (threshold, keys) = bytes.split_last()?;
+    let threshold = *threshold as usize;
+    if keys.len() % ED25519_PUBLIC_KEY_LENGTH != 0 {
+        return None;
+    }
+    let num_keys = keys.len() / ED25519_PUBLIC_KEY_LENGTH;
+    if threshold == 0 || threshold > num_keys || num_keys > MAX_NUM_OF_KEYS {
+        return None;
+    }
+    let keys = keys
+        .chunks_exact(ED25519_PUBLIC_KEY_LENGTH)
+        .map(deserialize_public_key)
+        .collect::<Option<Vec<_>>>()?;
+    Some((keys, threshold))
+}
+
+/// Verify that `signature` has at least `threshold` valid signatures of `msg` by `keys`.
+fn verify_strict(keys: &[PublicKey], threshold: usize, signature: &[u8], msg: &[u8]) -> bool {
+    if signature.len() < BITMAP_LENGTH {
+        return false;
+    }
+    let (signatures, bitmap) = signature.split_at(signature.len() - BITMAP_LENGTH);
+    if signatures.len() % ED25519_SIGNATURE_LENGTH != 0 {
+        return false;
+    }
+    let indices = (0..BITMAP_LENGTH * 8)
+        .filter(|idx| bitmap[idx / 8] & (128 >> (idx % 8)) != 0)
+        .collect::<Vec<_>>();
+    let num_signatures = signatures.len() / ED25519_SIGNATURE_LENGTH;
+    if indices.len() != num_signatures
+        || num_signatures < threshold
+        || indices.iter().any(|idx| *idx >= keys.len())
+    {
+        return false;
+    }
+    signatures
+        .chunks_exact(ED25519_SIGNATURE_LENGTH)
+        .zip(indices)
+        .all(|(sig, idx)| match deserialize_signature(sig) {
+            Some(sig) => keys[idx].verify_strict(msg, &sig).is_ok(),
+            None => false,
+        })
+}
+
+/***************************************************************************************************
+ * native fun public_key_validate_internal
+ *
+ *   gas cost: base_cost + unit_cost * number_of_keys
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct PublicKeyValidateGasParameters {
+    pub base: InternalGas,
+    pub per_public_key: InternalGasPerArg,
+}
+
+fn native_public_key_validate(
+    gas_params: &PublicKeyValidateGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 1);
+
+    let key_bytes = pop_arg!(arguments, Vec<u8>);
+
+    let num_keys = key_bytes.len() / ED25519_PUBLIC_KEY_LENGTH;
+    let cost = gas_params.base + gas_params.per_public_key * NumArgs::new(num_keys as u64);
+
+    let valid = deserialize_public_key_and_threshold(&key_bytes).is_some();
+
+    Ok(NativeResult::ok(cost, smallvec![Value::bool(valid)]))
+}
+
+pub fn make_native_public_key_validate(
+    gas_params: PublicKeyValidateGasParameters,
+) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_public_key_validate(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * native fun signature_verify_strict_internal
+ *
+ *   gas cost: base_cost + number_of_signatures * (unit_cost + unit_cost * message_length_in_bytes)
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct SignatureVerifyStrictGasParameters {
+    pub base: InternalGas,
+    pub per_signature: InternalGasPerArg,
+    pub per_msg_byte_per_signature: InternalGasPerByte,
+}
+
+fn native_signature_verify_strict(
+    gas_params: &SignatureVerifyStrictGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 3);
+
+    let msg = pop_arg!(arguments, Vec<u8>);
+    let pubkey = pop_arg!(arguments, Vec<u8>);
+    let signature = pop_arg!(arguments, Vec<u8>);
+
+    let num_signatures = (signature.len() / ED25519_SIGNATURE_LENGTH) as u64;
+    let cost = gas_params.base
+        + gas_params.per_signature * NumArgs::new(num_signatures)
+        + gas_params.per_msg_byte_per_signature * NumBytes::new(msg.len() as u64 * num_signatures);
+
+    let valid = match deserialize_public_key_and_threshold(&pubkey) {
+        Some((keys, threshold)) => verify_strict(&keys, threshold, &signature, &msg),
+        None => false,
+    };
+
+    Ok(NativeResult::ok(cost, smallvec![Value::bool(valid)]))
+}
+
+pub fn make_native_signature_verify_strict(
+    gas_params: SignatureVerifyStrictGasParameters,
+) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_signature_verify_strict(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * module
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct GasParameters {
+    pub public_key_validate: PublicKeyValidateGasParameters,
+    pub signature_verify_strict: SignatureVerifyStrictGasParameters,
+}
+
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let natives = [
+        (
+            "public_key_validate_internal",
+            make_native_public_key_validate(gas_params.public_key_validate),
+        ),
+        (
+            "signature_verify_strict_internal",
+            make_native_signature_verify_strict(gas_params.signature_verify_strict),
+        ),
+    ];
+
+    make_module_natives(natives)
+}
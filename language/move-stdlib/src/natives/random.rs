@@ -0,0 +1,165 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Implementation of native functions drawing from a deterministic pseudo-random generator.
+//!
+//! The generator is held by the `NativeRandomContext` extension, which must be attached to the
+//! `NativeContextExtensions` of each session running code which calls `std::random`, with a seed
+//! chosen by the embedder. Sessions run with the same seed draw the same numbers.
+
+use crate::natives::helpers::make_module_natives;
+use better_any::{Tid, TidAble};
+use move_binary_format::errors::PartialVMResult;
+use move_core_types::gas_algebra::{InternalGas, InternalGasPerByte, NumBytes};
+use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
+use move_vm_types::{
+    loaded_data::runtime_types::Type, natives::function::NativeResult, pop_arg, values::Value,
+};
+use smallvec::smallvec;
+use std::{collections::VecDeque, sync::Arc};
+
+/// The maximum number of bytes which can be drawn at once, `std::random::MAX_BYTES`.
+const MAX_BYTES: u64 = 1 << 20;
+
+/// Abort code when more than `MAX_BYTES` are requested, `std::random::EBYTES_TOO_LONG`.
+const EBYTES_TOO_LONG: u64 = 0x20001;
+
+/// Abort code when the embedder did not attach a `NativeRandomContext` to the session,
+/// `std::random::EUNAVAILABLE`.
+const EUNAVAILABLE: u64 = 0xD0001;
+
+/// The native random context extension, holding the state of a SplitMix64 generator. This is not
+/// a source of secure randomness, and is meant for simulations and tests only.
+#[derive(Tid)]
+pub struct NativeRandomContext {
+    state: u64,
+}
+
+impl NativeRandomContext {
+    /// Create a new instance of the random context, whose numbers are determined by `seed`.
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Draw the next number of the sequence.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+}
+
+/***************************************************************************************************
+ * native fun next_u64
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct NextU64GasParameters {
+    pub base: InternalGas,
+}
+
+fn native_next_u64(
+    gas_params: &NextU64GasParameters,
+    context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(args.is_empty());
+
+    let random_context = match context
+        .extensions_mut()
+        .try_get_mut::<NativeRandomContext>()
+    {
+        Some(random_context) => random_context,
+        None => return Ok(NativeResult::err(gas_params.base, EUNAVAILABLE)),
+    };
+
+    let n = random_context.next_u64();
+
+    Ok(NativeResult::ok(gas_params.base, smallvec![Value::u64(n)]))
+}
+
+pub fn make_native_next_u64(gas_params: NextU64GasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_next_u64(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * native fun bytes
+ *
+ *   gas cost: base_cost + unit_cost * number_of_bytes
+ *
+ *   The cost is charged for the requested number of bytes even when it exceeds `MAX_BYTES`, in
+ *   which case the native aborts before allocating anything.
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct BytesGasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+}
+
+fn native_bytes(
+    gas_params: &BytesGasParameters,
+    context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(args.len() == 1);
+    let n = pop_arg!(args, u64);
+
+    let cost = gas_params.base + gas_params.per_byte * NumBytes::new(n);
+
+    if n > MAX_BYTES {
+        return Ok(NativeResult::err(cost, EBYTES_TOO_LONG));
+    }
+
+    let random_context = match context
+        .extensions_mut()
+        .try_get_mut::<NativeRandomContext>()
+    {
+        Some(random_context) => random_context,
+        None => return Ok(NativeResult::err(cost, EUNAVAILABLE)),
+    };
+    let mut bytes = Vec::with_capacity(n as usize);
+    while bytes.len() < n as usize {
+        let word = random_context.next_u64().to_le_bytes();
+        let remaining = n as usize - bytes.len();
+        bytes.extend_from_slice(&word[..remaining.min(word.len())]);
+    }
+
+    Ok(NativeResult::ok(cost, smallvec![Value::vector_u8(bytes)]))
+}
+
+pub fn make_native_bytes(gas_params: BytesGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_bytes(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * module
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct GasParameters {
+    pub next_u64: NextU64GasParameters,
+    pub bytes: BytesGasParameters,
+}
+
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let natives = [
+        ("next_u64", make_native_next_u64(gas_params.next_u64)),
+        ("bytes", make_native_bytes(gas_params.bytes)),
+    ];
+
+    make_module_natives(natives)
+}
@@ -0,0 +1,149 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::natives::helpers::make_module_natives;
+use libsecp256k1::{Message, PublicKey, PublicKeyFormat, RecoveryId, Signature};
+use move_binary_format::errors::PartialVMResult;
+use move_core_types::gas_algebra::InternalGas;
+use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
+use move_vm_types::{
+    loaded_data::runtime_types::Type, natives::function::NativeResult, pop_arg, values::Value,
+};
+use smallvec::smallvec;
+use std::{collections::VecDeque, sync::Arc};
+
+/// Deserialize a signature, which must not be malleable: its `s` must be in the lower half of the
+/// curve order.
+fn deserialize_signature(bytes: &[u8]) -> Option<Signature> {
+    let sig = Signature::parse_standard_slice(bytes).ok()?;
+    if sig.s.is_high() {
+        return None;
+    }
+    Some(sig)
+}
+
+/***************************************************************************************************
+ * native fun ecdsa_recover_internal
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct EcdsaRecoverGasParameters {
+    pub base: InternalGas,
+}
+
+fn native_ecdsa_recover(
+    gas_params: &EcdsaRecoverGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 3);
+
+    let signature = pop_arg!(arguments, Vec<u8>);
+    let recovery_id = pop_arg!(arguments, u8);
+    let msg = pop_arg!(arguments, Vec<u8>);
+
+    let recovered = match (
+        Message::parse_slice(&msg),
+        RecoveryId::parse(recovery_id),
+        deserialize_signature(&signature),
+    ) {
+        (Ok(msg), Ok(recovery_id), Some(sig)) => libsecp256k1::recover(&msg, &sig, &recovery_id)
+            .ok()
+            // drop the tag of the uncompressed key
+            .map(|pk| pk.serialize()[1..].to_vec()),
+        _ => None,
+    };
+
+    let (pk, success) = match recovered {
+        Some(pk) => (pk, true),
+        None => (vec![], false),
+    };
+
+    Ok(NativeResult::ok(
+        gas_params.base,
+        smallvec![Value::vector_u8(pk), Value::bool(success)],
+    ))
+}
+
+pub fn make_native_ecdsa_recover(gas_params: EcdsaRecoverGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_ecdsa_recover(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * native fun ecdsa_verify_internal
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct EcdsaVerifyGasParameters {
+    pub base: InternalGas,
+}
+
+fn native_ecdsa_verify(
+    gas_params: &EcdsaVerifyGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 3);
+
+    let pubkey = pop_arg!(arguments, Vec<u8>);
+    let signature = pop_arg!(arguments, Vec<u8>);
+    let msg = pop_arg!(arguments, Vec<u8>);
+
+    let valid = match (
+        Message::parse_slice(&msg),
+        deserialize_signature(&signature),
+        PublicKey::parse_slice(&pubkey, Some(PublicKeyFormat::Raw)),
+    ) {
+        (Ok(msg), Some(sig), Ok(pk)) => libsecp256k1::verify(&msg, &sig, &pk),
+        _ => false,
+    };
+
+    Ok(NativeResult::ok(
+        gas_params.base,
+        smallvec![Value::bool(valid)],
+    ))
+}
+
+pub fn make_native_ecdsa_verify(gas_params: EcdsaVerifyGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_ecdsa_verify(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * module
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct GasParameters {
+    pub ecdsa_recover: EcdsaRecoverGasParameters,
+    pub ecdsa_verify: EcdsaVerifyGasParameters,
+}
+
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let natives = [
+        (
+            "ecdsa_recover_internal",
+            make_native_ecdsa_recover(gas_params.ecdsa_recover),
+        ),
+        (
+            "ecdsa_verify_internal",
+            make_native_ecdsa_verify(gas_params.ecdsa_verify),
+        ),
+    ];
+
+    make_module_natives(natives)
+}
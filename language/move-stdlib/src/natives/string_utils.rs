@@ -0,0 +1,373 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::natives::helpers::make_module_natives;
+use move_binary_format::errors::{PartialVMError, PartialVMResult};
+use move_core_types::{
+    account_address::AccountAddress,
+    gas_algebra::{InternalGas, InternalGasPerByte, NumBytes},
+    identifier::Identifier,
+    language_storage::StructTag,
+    value::{MoveStruct, MoveStructLayout, MoveTypeLayout, MoveValue},
+    vm_status::{sub_status::NFE_BCS_SERIALIZATION_FAILURE, StatusCode},
+};
+use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
+use move_vm_types::{
+    loaded_data::runtime_types::Type,
+    natives::function::NativeResult,
+    pop_arg,
+    values::{values_impl::Reference, Struct, Value, VectorRef},
+};
+use smallvec::smallvec;
+use std::{collections::VecDeque, sync::Arc};
+
+/// Abort code when the number of values does not match the number of placeholders,
+/// `std::string_utils::EARGS_MISMATCH`.
+const EARGS_MISMATCH: u64 = 0x10001;
+/// Abort code when the format is malformed, `std::string_utils::EINVALID_FORMAT`.
+const EINVALID_FORMAT: u64 = 0x10002;
+
+/// Read the value behind `val_ref`, of type `ty`, as an annotated value along with its fully
+/// annotated layout, or `None` if the layout of `ty` cannot be computed.
+fn read_annotated(
+    context: &NativeContext,
+    ty: &Type,
+    val_ref: Reference,
+) -> PartialVMResult<Option<(MoveTypeLayout, MoveValue)>> {
+    let (layout, annotated_layout) = match (
+        context.type_to_type_layout(ty)?,
+        context.type_to_fully_annotated_layout(ty)?,
+    ) {
+        (Some(layout), Some(annotated_layout)) => (layout, annotated_layout),
+        _ => return Ok(None),
+    };
+    let annotated_value = val_ref
+        .read_ref()?
+        .simple_serialize(&layout)
+        .and_then(|bytes| MoveValue::simple_deserialize(&bytes, &annotated_layout).ok())
+        .ok_or_else(|| {
+            PartialVMError::new(StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR)
+                .with_message("failed to annotate value".to_string())
+        })?;
+    Ok(Some((annotated_layout, annotated_value)))
+}
+
+/// Whether `tag` is `std::string::String` or `std::ascii::String`, with the standard library
+/// published at `move_std_addr`.
+fn is_string(
+    move_std_addr: &AccountAddress,
+    tag: &StructTag,
+    fields: &[(Identifier, MoveValue)],
+) -> bool {
+    tag.address == *move_std_addr
+        && (tag.module.as_str() == "string" || tag.module.as_str() == "ascii")
+        && tag.name.as_str() == "String"
+        && matches!(fields, [(_, MoveValue::Vector(_))])
+}
+
+/// Whether a struct has no fields, apart from the one the compiler adds to empty structs.
+fn is_empty_struct(fields: &[(Identifier, MoveValue)]) -> bool {
+    match fields {
+        [] => true,
+        [(name, MoveValue::Bool(false))] => name.as_str() == "dummy_field",
+        _ => false,
+    }
+}
+
+/// Append the rendering of `val`, of annotated layout `layout`, to `out`.
+fn render(
+    move_std_addr: &AccountAddress,
+    layout: &MoveTypeLayout,
+    val: &MoveValue,
+    out: &mut String,
+) -> PartialVMResult<()> {
+    use MoveTypeLayout as L;
+
+    match (layout, val) {
+        (_, MoveValue::U8(n)) => out.push_str(&n.to_string()),
+        (_, MoveValue::U64(n)) => out.push_str(&n.to_string()),
+        (_, MoveValue::U128(n)) => out.push_str(&n.to_string()),
+        (_, MoveValue::Bool(b)) => out.push_str(&b.to_string()),
+        (_, MoveValue::Address(addr)) => {
+            out.push('@');
+            out.push_str(&addr.to_hex_literal());
+        }
+        (_, MoveValue::Signer(addr)) => {
+            out.push_str("signer(@");
+            out.push_str(&addr.to_hex_literal());
+            out.push(')');
+        }
+
+        (L::Vector(elem_layout), MoveValue::Vector(elems)) => {
+            if let L::U8 = elem_layout.as_ref() {
+                out.push_str("0x");
+                for elem in elems {
+                    if let MoveValue::U8(byte) = elem {
+                        out.push_str(&format!("{:02x}", byte));
+                    }
+                }
+            } else {
+                out.push('[');
+                for (i, elem) in elems.iter().enumerate() {
+                    if i > 0 {
+                        out.push_str(", ");
+                    }
+                    render(move_std_addr, elem_layout, elem, out)?;
+                }
+                out.push(']');
+            }
+        }
+
+        (
+            L::Struct(MoveStructLayout::WithTypes {
+                fields: field_layouts,
+                ..
+            }),
+            MoveValue::Struct(MoveStruct::WithTypes { type_, fields }),
+        ) => {
+            if is_string(move_std_addr, type_, fields) {
+                if let MoveValue::Vector(bytes) = &fields[0].1 {
+                    let bytes = bytes
+                        .iter()
+                        .filter_map(|byte| match byte {
+                            MoveValue::U8(byte) => Some(*byte),
+                            _ => None,
+                        })
+                        .collect::<Vec<_>>();
+                    out.push_str(&format!("{:?}", String::from_utf8_lossy(&bytes)));
+                }
+            } else if is_empty_struct(fields) {
+                out.push_str(type_.name.as_str());
+                out.push_str(" {}");
+            } else {
+                out.push_str(type_.name.as_str());
+                out.push_str(" { ");
+                for (i, ((name, field), field_layout)) in
+                    fields.iter().zip(field_layouts.iter()).enumerate()
+                {
+                    if i > 0 {
+                        out.push_str(", ");
+                    }
+                    out.push_str(name.as_str());
+                    out.push_str(": ");
+                    render(move_std_addr, &field_layout.layout, field, out)?;
+                }
+                out.push_str(" }");
+            }
+        }
+
+        (layout, val) => {
+            return Err(
+                PartialVMError::new(StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR)
+                    .with_message(format!("cannot render value {:?} as {:?}", val, layout)),
+            )
+        }
+    }
+    Ok(())
+}
+
+/// Make a `std::string::String` out of `s`.
+fn string_value(s: String) -> Value {
+    Value::struct_(Struct::pack(vec![Value::vector_u8(s.into_bytes())]))
+}
+
+/***************************************************************************************************
+ * native fun to_string
+ *
+ *   gas cost: base_cost + unit_cost * length of the rendering
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct ToStringGasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+}
+
+fn native_to_string(
+    move_std_addr: &AccountAddress,
+    gas_params: &ToStringGasParameters,
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.len() == 1);
+    debug_assert!(args.len() == 1);
+
+    let val_ref = pop_arg!(args, Reference);
+    let (layout, val) = match read_annotated(context, &ty_args[0], val_ref)? {
+        Some(annotated) => annotated,
+        None => {
+            return Ok(NativeResult::err(
+                gas_params.base,
+                NFE_BCS_SERIALIZATION_FAILURE,
+            ))
+        }
+    };
+
+    let mut out = String::new();
+    render(move_std_addr, &layout, &val, &mut out)?;
+
+    let cost = gas_params.base + gas_params.per_byte * NumBytes::new(out.len() as u64);
+
+    Ok(NativeResult::ok(cost, smallvec![string_value(out)]))
+}
+
+pub fn make_native_to_string(
+    move_std_addr: AccountAddress,
+    gas_params: ToStringGasParameters,
+) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| {
+        native_to_string(&move_std_addr, &gas_params, context, ty_args, args)
+    })
+}
+
+/***************************************************************************************************
+ * native fun format_list
+ *
+ *   gas cost: base_cost + unit_cost * length of the formatted string
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct FormatGasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+}
+
+/// Collect the values of a `std::string_utils::Cons` list, along with their layouts.
+fn list_values<'a>(
+    mut layout: &'a MoveTypeLayout,
+    mut val: &'a MoveValue,
+) -> Vec<(&'a MoveTypeLayout, &'a MoveValue)> {
+    let mut values = vec![];
+    // The list ends with a `std::string_utils::NIL`, which has no fields.
+    while let (
+        MoveTypeLayout::Struct(MoveStructLayout::WithTypes {
+            fields: field_layouts,
+            ..
+        }),
+        MoveValue::Struct(MoveStruct::WithTypes { fields, .. }),
+    ) = (layout, val)
+    {
+        if fields.len() != 2 || field_layouts.len() != 2 {
+            break;
+        }
+        values.push((&field_layouts[0].layout, &fields[0].1));
+        layout = &field_layouts[1].layout;
+        val = &fields[1].1;
+    }
+    values
+}
+
+/// Write `fmt` to `out` with its placeholders replaced by the rendering of `values`, or return
+/// the abort code if the format is malformed or the number of values does not match. On abort,
+/// `out` holds what was rendered before the error was found.
+fn format(
+    move_std_addr: &AccountAddress,
+    fmt: &str,
+    values: &[(&MoveTypeLayout, &MoveValue)],
+    out: &mut String,
+) -> PartialVMResult<Result<(), u64>> {
+    let mut values = values.iter();
+    let mut chars = fmt.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (c, chars.peek()) {
+            ('{', Some('{')) | ('}', Some('}')) => {
+                chars.next();
+                out.push(c);
+            }
+            ('{', Some('}')) => {
+                chars.next();
+                match values.next() {
+                    Some((layout, val)) => render(move_std_addr, layout, val, out)?,
+                    None => return Ok(Err(EARGS_MISMATCH)),
+                }
+            }
+            ('{', _) | ('}', _) => return Ok(Err(EINVALID_FORMAT)),
+            _ => out.push(c),
+        }
+    }
+    if values.next().is_some() {
+        return Ok(Err(EARGS_MISMATCH));
+    }
+    Ok(Ok(()))
+}
+
+fn native_format_list(
+    move_std_addr: &AccountAddress,
+    gas_params: &FormatGasParameters,
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.len() == 1);
+    debug_assert!(args.len() == 2);
+
+    let list_ref = pop_arg!(args, Reference);
+    let fmt_ref = pop_arg!(args, VectorRef);
+
+    let (layout, list) = match read_annotated(context, &ty_args[0], list_ref)? {
+        Some(annotated) => annotated,
+        None => {
+            return Ok(NativeResult::err(
+                gas_params.base,
+                NFE_BCS_SERIALIZATION_FAILURE,
+            ))
+        }
+    };
+    let fmt_bytes = fmt_ref.as_bytes_ref();
+    let fmt = match std::str::from_utf8(&fmt_bytes) {
+        Ok(fmt) => fmt,
+        Err(_) => {
+            let cost =
+                gas_params.base + gas_params.per_byte * NumBytes::new(fmt_bytes.len() as u64);
+            return Ok(NativeResult::err(cost, EINVALID_FORMAT));
+        }
+    };
+
+    let mut out = String::new();
+    let result = format(move_std_addr, fmt, &list_values(&layout, &list), &mut out)?;
+
+    // Charge for the rendered bytes even if the format turns out to be invalid
+    let cost = gas_params.base + gas_params.per_byte * NumBytes::new(out.len() as u64);
+
+    match result {
+        Ok(()) => Ok(NativeResult::ok(cost, smallvec![string_value(out)])),
+        Err(code) => Ok(NativeResult::err(cost, code)),
+    }
+}
+
+pub fn make_native_format_list(
+    move_std_addr: AccountAddress,
+    gas_params: FormatGasParameters,
+) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| {
+        native_format_list(&move_std_addr, &gas_params, context, ty_args, args)
+    })
+}
+
+/***************************************************************************************************
+ * module
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct GasParameters {
+    pub to_string: ToStringGasParameters,
+    pub format: FormatGasParameters,
+}
+
+pub fn make_all(
+    move_std_addr: AccountAddress,
+    gas_params: GasParameters,
+) -> impl Iterator<Item = (String, NativeFunction)> {
+    let natives = [
+        (
+            "to_string",
+            make_native_to_string(move_std_addr, gas_params.to_string),
+        ),
+        (
+            "format_list",
+            make_native_format_list(move_std_addr, gas_params.format),
+        ),
+    ];
+
+    make_module_natives(natives)
+}
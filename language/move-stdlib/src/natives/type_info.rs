@@ -0,0 +1,189 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::natives::helpers::make_module_natives;
+use move_binary_format::errors::PartialVMResult;
+use move_core_types::{
+    gas_algebra::{InternalGas, InternalGasPerByte, NumBytes},
+    language_storage::TypeTag,
+    vm_status::sub_status::NFE_BCS_SERIALIZATION_FAILURE,
+};
+use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
+use move_vm_types::{
+    loaded_data::runtime_types::Type,
+    natives::function::NativeResult,
+    pop_arg,
+    values::{values_impl::Reference, Struct, Value},
+};
+use smallvec::smallvec;
+use std::{collections::VecDeque, sync::Arc};
+
+/// Abort code when the type passed to `type_of` is not a struct, `std::type_info::ENOT_STRUCT`.
+const ENOT_STRUCT: u64 = 1;
+
+/// Make a `std::ascii::String` out of `s`.
+fn ascii_string(s: &str) -> Value {
+    Value::struct_(Struct::pack(vec![Value::vector_u8(s.as_bytes().to_vec())]))
+}
+
+/***************************************************************************************************
+ * native fun type_of
+ *
+ *   gas cost: base_cost + unit_cost * (length of the names of the struct and its type arguments)
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct TypeOfGasParameters {
+    pub base: InternalGas,
+    pub per_byte: InternalGasPerByte,
+}
+
+fn native_type_of(
+    gas_params: &TypeOfGasParameters,
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert_eq!(ty_args.len(), 1);
+    debug_assert!(arguments.is_empty());
+
+    let struct_tag = match context.type_to_type_tag(&ty_args[0])? {
+        TypeTag::Struct(struct_tag) => struct_tag,
+        _ => return Ok(NativeResult::err(gas_params.base, ENOT_STRUCT)),
+    };
+
+    let mut num_bytes = struct_tag.module.len() + struct_tag.name.len();
+    let type_args = struct_tag
+        .type_params
+        .iter()
+        .map(|type_arg| {
+            let type_name = type_arg.to_canonical_string();
+            num_bytes += type_name.len();
+            // make a std::type_name::TypeName
+            Struct::pack(vec![ascii_string(&type_name)])
+        })
+        .collect::<Vec<_>>();
+    // make a std::type_info::TypeInfo
+    let type_info_val = Value::struct_(Struct::pack(vec![
+        Value::address(struct_tag.address),
+        ascii_string(struct_tag.module.as_str()),
+        ascii_string(struct_tag.name.as_str()),
+        Value::vector_struct(type_args),
+    ]));
+
+    let cost = gas_params.base + gas_params.per_byte * NumBytes::new(num_bytes as u64);
+
+    Ok(NativeResult::ok(cost, smallvec![type_info_val]))
+}
+
+pub fn make_native_type_of(gas_params: TypeOfGasParameters) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| native_type_of(&gas_params, context, ty_args, args))
+}
+
+/***************************************************************************************************
+ * native fun size_of_val
+ *
+ *   gas cost: base_cost + unit_cost * size_of(serialized val)
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct SizeOfValGasParameters {
+    pub base: InternalGas,
+    pub per_byte_serialized: InternalGasPerByte,
+}
+
+fn native_size_of_val(
+    gas_params: &SizeOfValGasParameters,
+    context: &mut NativeContext,
+    mut ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.len() == 1);
+    debug_assert!(args.len() == 1);
+
+    let ref_to_val = pop_arg!(args, Reference);
+    let arg_type = ty_args.pop().unwrap();
+
+    let layout = match context.type_to_type_layout(&arg_type)? {
+        Some(layout) => layout,
+        None => {
+            return Ok(NativeResult::err(
+                gas_params.base,
+                NFE_BCS_SERIALIZATION_FAILURE,
+            ))
+        }
+    };
+    let serialized_value = match ref_to_val.read_ref()?.simple_serialize(&layout) {
+        Some(serialized_value) => serialized_value,
+        None => {
+            return Ok(NativeResult::err(
+                gas_params.base,
+                NFE_BCS_SERIALIZATION_FAILURE,
+            ))
+        }
+    };
+    let size = serialized_value.len() as u64;
+
+    let cost = gas_params.base + gas_params.per_byte_serialized * NumBytes::new(size);
+
+    Ok(NativeResult::ok(cost, smallvec![Value::u64(size)]))
+}
+
+pub fn make_native_size_of_val(gas_params: SizeOfValGasParameters) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| native_size_of_val(&gas_params, context, ty_args, args))
+}
+
+/***************************************************************************************************
+ * native fun abilities
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct AbilitiesGasParameters {
+    pub base: InternalGas,
+}
+
+fn native_abilities(
+    gas_params: &AbilitiesGasParameters,
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert_eq!(ty_args.len(), 1);
+    debug_assert!(arguments.is_empty());
+
+    let abilities = context.type_abilities(&ty_args[0])?;
+
+    Ok(NativeResult::ok(
+        gas_params.base,
+        smallvec![Value::u8(abilities.into_u8())],
+    ))
+}
+
+pub fn make_native_abilities(gas_params: AbilitiesGasParameters) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| native_abilities(&gas_params, context, ty_args, args))
+}
+
+/***************************************************************************************************
+ * module
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct GasParameters {
+    pub type_of: TypeOfGasParameters,
+    pub size_of_val: SizeOfValGasParameters,
+    pub abilities: AbilitiesGasParameters,
+}
+
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let natives = [
+        ("type_of", make_native_type_of(gas_params.type_of)),
+        (
+            "size_of_val",
+            make_native_size_of_val(gas_params.size_of_val),
+        ),
+        ("abilities", make_native_abilities(gas_params.abilities)),
+    ];
+
+    make_module_natives(natives)
+}
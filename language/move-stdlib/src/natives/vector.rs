@@ -5,7 +5,7 @@
 use crate::natives::helpers::make_module_natives;
 use move_binary_format::errors::{PartialVMError, PartialVMResult};
 use move_core_types::{
-    gas_algebra::{InternalGas, InternalGasPerAbstractMemoryUnit},
+    gas_algebra::{InternalGas, InternalGasPerAbstractMemoryUnit, InternalGasPerArg, NumArgs},
     vm_status::StatusCode,
 };
 use move_vm_runtime::native_functions::{NativeContext, NativeFunction};
@@ -13,9 +13,10 @@ use move_vm_types::{
     loaded_data::runtime_types::Type,
     natives::function::NativeResult,
     pop_arg,
-    values::{Value, Vector, VectorRef},
+    values::{Reference, Value, Vector, VectorRef},
     views::ValueView,
 };
+use smallvec::smallvec;
 use std::{collections::VecDeque, sync::Arc};
 
 /***************************************************************************************************
@@ -264,6 +265,200 @@ pub fn make_native_swap(gas_params: SwapGasParameters) -> NativeFunction {
     )
 }
 
+/***************************************************************************************************
+ * native fun move_range
+ *
+ *   gas cost: base_cost + unit_cost * num_elements_moved
+ *
+ *   The elements moved include those shifted in both vectors.
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct MoveRangeGasParameters {
+    pub base: InternalGas,
+    pub per_index_moved: InternalGasPerArg,
+}
+
+pub fn native_move_range(
+    gas_params: &MoveRangeGasParameters,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.len() == 1);
+    debug_assert!(args.len() == 5);
+
+    let insert_position = pop_arg!(args, u64);
+    let to = pop_arg!(args, VectorRef);
+    let length = pop_arg!(args, u64);
+    let removal_position = pop_arg!(args, u64);
+    let from = pop_arg!(args, VectorRef);
+
+    let from_len = from.len(&ty_args[0])?.value_as::<u64>()?;
+    let to_len = to.len(&ty_args[0])?.value_as::<u64>()?;
+    let cost = gas_params.base
+        + gas_params.per_index_moved
+            * NumArgs::new(
+                from_len.saturating_sub(removal_position) + to_len.saturating_sub(insert_position),
+            );
+
+    NativeResult::map_partial_vm_result_empty(
+        cost,
+        VectorRef::move_range(
+            &from,
+            removal_position as usize,
+            length as usize,
+            &to,
+            insert_position as usize,
+            &ty_args[0],
+        )
+        .map_err(native_error_to_abort),
+    )
+}
+
+pub fn make_native_move_range(gas_params: MoveRangeGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_move_range(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * native fun sort
+ *
+ *   gas cost: base_cost + unit_cost * num_comparisons
+ *             + legacy_unit_cost * total size_of(compared values)
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct SortGasParameters {
+    pub base: InternalGas,
+    pub per_comparison: InternalGasPerArg,
+    pub legacy_per_abstract_memory_unit: InternalGasPerAbstractMemoryUnit,
+}
+
+pub fn native_sort(
+    gas_params: &SortGasParameters,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.len() == 1);
+    debug_assert!(args.len() == 1);
+
+    let r = pop_arg!(args, VectorRef);
+    match r.sort(&ty_args[0]) {
+        Ok(comparisons) => {
+            let cost = gas_params.base
+                + gas_params.per_comparison * NumArgs::new(comparisons.count)
+                + gas_params.legacy_per_abstract_memory_unit * comparisons.size;
+            Ok(NativeResult::ok(cost, smallvec![]))
+        }
+        Err(err) => NativeResult::map_partial_vm_result_empty(
+            gas_params.base,
+            Err(native_error_to_abort(err)),
+        ),
+    }
+}
+
+pub fn make_native_sort(gas_params: SortGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_sort(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * native fun sort_by_key
+ *
+ *   gas cost: base_cost + unit_cost * num_comparisons
+ *             + legacy_unit_cost * total size_of(compared values)
+ *
+ **************************************************************************************************/
+pub fn native_sort_by_key(
+    gas_params: &SortGasParameters,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.len() == 2);
+    debug_assert!(args.len() == 2);
+
+    let keys = pop_arg!(args, VectorRef);
+    let r = pop_arg!(args, VectorRef);
+    match r.sort_by_key(&ty_args[0], &keys, &ty_args[1]) {
+        Ok(comparisons) => {
+            let cost = gas_params.base
+                + gas_params.per_comparison * NumArgs::new(comparisons.count)
+                + gas_params.legacy_per_abstract_memory_unit * comparisons.size;
+            Ok(NativeResult::ok(cost, smallvec![]))
+        }
+        Err(err) => NativeResult::map_partial_vm_result_empty(
+            gas_params.base,
+            Err(native_error_to_abort(err)),
+        ),
+    }
+}
+
+pub fn make_native_sort_by_key(gas_params: SortGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_sort_by_key(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
+/***************************************************************************************************
+ * native fun binary_search
+ *
+ *   gas cost: base_cost + unit_cost * num_comparisons
+ *             + legacy_unit_cost * total size_of(compared values)
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct BinarySearchGasParameters {
+    pub base: InternalGas,
+    pub per_comparison: InternalGasPerArg,
+    pub legacy_per_abstract_memory_unit: InternalGasPerAbstractMemoryUnit,
+}
+
+pub fn native_binary_search(
+    gas_params: &BinarySearchGasParameters,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.len() == 1);
+    debug_assert!(args.len() == 2);
+
+    let e = pop_arg!(args, Reference).read_ref()?;
+    let r = pop_arg!(args, VectorRef);
+    let (res, comparisons) = r.binary_search(&e, &ty_args[0])?;
+    let (found, idx) = match res {
+        Ok(idx) => (true, idx),
+        Err(idx) => (false, idx),
+    };
+
+    let cost = gas_params.base
+        + gas_params.per_comparison * NumArgs::new(comparisons.count)
+        + gas_params.legacy_per_abstract_memory_unit * comparisons.size;
+
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::bool(found), Value::u64(idx as u64)],
+    ))
+}
+
+pub fn make_native_binary_search(gas_params: BinarySearchGasParameters) -> NativeFunction {
+    Arc::new(
+        move |context, ty_args, args| -> PartialVMResult<NativeResult> {
+            native_binary_search(&gas_params, context, ty_args, args)
+        },
+    )
+}
+
 fn native_error_to_abort(err: PartialVMError) -> PartialVMError {
     let (major_status, sub_status_opt, message_opt, exec_state_opt, indices, offsets) =
         err.all_data();
@@ -298,6 +493,9 @@ pub struct GasParameters {
     pub pop_back: PopBackGasParameters,
     pub destroy_empty: DestroyEmptyGasParameters,
     pub swap: SwapGasParameters,
+    pub move_range: MoveRangeGasParameters,
+    pub sort: SortGasParameters,
+    pub binary_search: BinarySearchGasParameters,
 }
 
 pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
@@ -313,6 +511,13 @@ pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, Nati
             make_native_destroy_empty(gas_params.destroy_empty),
         ),
         ("swap", make_native_swap(gas_params.swap)),
+        ("move_range", make_native_move_range(gas_params.move_range)),
+        ("sort", make_native_sort(gas_params.sort.clone())),
+        ("sort_by_key", make_native_sort_by_key(gas_params.sort)),
+        (
+            "binary_search",
+            make_native_binary_search(gas_params.binary_search),
+        ),
     ];
 
     make_module_natives(natives)
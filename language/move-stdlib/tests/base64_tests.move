@@ -0,0 +1,73 @@
+#[test_only]
+module std::base64_tests {
+    use std::base64;
+    use std::string;
+    use std::vector;
+
+    #[test]
+    fun encode_rfc4648_vectors() {
+        assert!(*string::bytes(&base64::encode(&b"")) == b"", 0);
+        assert!(*string::bytes(&base64::encode(&b"f")) == b"Zg==", 1);
+        assert!(*string::bytes(&base64::encode(&b"fo")) == b"Zm8=", 2);
+        assert!(*string::bytes(&base64::encode(&b"foo")) == b"Zm9v", 3);
+        assert!(*string::bytes(&base64::encode(&b"foob")) == b"Zm9vYg==", 4);
+        assert!(*string::bytes(&base64::encode(&b"fooba")) == b"Zm9vYmE=", 5);
+        assert!(*string::bytes(&base64::encode(&b"foobar")) == b"Zm9vYmFy", 6);
+        assert!(*string::bytes(&base64::encode(&x"fbff")) == b"+/8=", 7);
+    }
+
+    #[test]
+    fun decode_rfc4648_vectors() {
+        assert!(base64::decode(&string::utf8(b"")) == b"", 0);
+        assert!(base64::decode(&string::utf8(b"Zg==")) == b"f", 1);
+        assert!(base64::decode(&string::utf8(b"Zm8=")) == b"fo", 2);
+        assert!(base64::decode(&string::utf8(b"Zm9v")) == b"foo", 3);
+        assert!(base64::decode(&string::utf8(b"Zm9vYg==")) == b"foob", 4);
+        assert!(base64::decode(&string::utf8(b"Zm9vYmE=")) == b"fooba", 5);
+        assert!(base64::decode(&string::utf8(b"Zm9vYmFy")) == b"foobar", 6);
+        assert!(base64::decode(&string::utf8(b"+/8=")) == x"fbff", 7);
+    }
+
+    #[test]
+    fun round_trip_all_bytes() {
+        let bytes = vector::empty();
+        let i = 0;
+        while (i < 256) {
+            vector::push_back(&mut bytes, (i as u8));
+            i = i + 1;
+        };
+        let s = base64::encode(&bytes);
+        assert!(string::length(&s) == 344, 0);
+        assert!(base64::decode(&s) == bytes, 1);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x10001)]
+    fun decode_unpadded() {
+        base64::decode(&string::utf8(b"Zg"));
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x10001)]
+    fun decode_invalid_character() {
+        base64::decode(&string::utf8(b"Zm9-"));
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x10001)]
+    fun decode_padding_in_the_middle() {
+        base64::decode(&string::utf8(b"Zg==Zm9v"));
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x10001)]
+    fun decode_too_much_padding() {
+        base64::decode(&string::utf8(b"Z==="));
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x10001)]
+    fun decode_non_zero_trailing_bits() {
+        base64::decode(&string::utf8(b"Zh=="));
+    }
+}
@@ -10,6 +10,8 @@ module std::bcs_tests {
     struct Box63<T> has copy, drop, store { x: Box31<Box31<T>> }
     struct Box127<T> has copy, drop, store { x: Box63<Box63<T>> }
 
+    struct Holder has drop { s: std::string::String }
+
     #[test]
     fun bcs_address() {
         let addr = @0x89b9f9d1fadc027cf9532d6f99041522;
@@ -82,4 +84,52 @@ module std::bcs_tests {
     fun encode_129() {
         bcs::to_bytes(&Box { x: box127(true) });
     }
+
+    #[test]
+    fun from_bytes_primitives() {
+        assert!(bcs::from_bytes<bool>(bcs::to_bytes(&true)) == true, 0);
+        assert!(bcs::from_bytes<u8>(x"01") == 1, 1);
+        assert!(bcs::from_bytes<u64>(x"0100000000000000") == 1, 2);
+        assert!(bcs::from_bytes<u128>(bcs::to_bytes(&340282366920938463463374607431768211455u128)) == 340282366920938463463374607431768211455, 3);
+        assert!(bcs::from_bytes<address>(bcs::to_bytes(&@0x89b9f9d1fadc027cf9532d6f99041522)) == @0x89b9f9d1fadc027cf9532d6f99041522, 4);
+        assert!(bcs::from_bytes<vector<u8>>(x"020f10") == x"0f10", 5);
+    }
+
+    #[test]
+    fun from_bytes_structs() {
+        let v = vector[Box { x: 1u64 }, Box { x: 2u64 }];
+        assert!(bcs::from_bytes<vector<Box<u64>>>(bcs::to_bytes(&v)) == v, 0);
+        let b = box3(x"0f");
+        assert!(bcs::from_bytes<Box3<vector<u8>>>(bcs::to_bytes(&b)) == b, 1);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 1)]
+    fun from_bytes_trailing_bytes() {
+        bcs::from_bytes<u8>(x"0102");
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 1)]
+    fun from_bytes_invalid_bool() {
+        bcs::from_bytes<bool>(x"02");
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 1)]
+    fun from_bytes_too_short() {
+        bcs::from_bytes<Box<u64>>(x"01000000");
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 2)]
+    fun from_bytes_foreign_struct() {
+        bcs::from_bytes<std::option::Option<u64>>(x"00");
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 2)]
+    fun from_bytes_foreign_struct_field() {
+        bcs::from_bytes<Holder>(x"00");
+    }
 }
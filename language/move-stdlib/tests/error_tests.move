@@ -0,0 +1,16 @@
+#[test_only]
+module std::error_tests {
+    use std::error;
+
+    #[test]
+    #[expected_failure(abort_code = 42)]
+    fun abort_with_message() {
+        error::abort_with_message(42, b"something went wrong");
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 42)]
+    fun abort_with_invalid_utf8_message() {
+        error::abort_with_message(42, x"ff");
+    }
+}
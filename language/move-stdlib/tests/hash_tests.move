@@ -15,4 +15,36 @@ module std::hash_tests {
         let expected_output = x"3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532";
         assert!(hash::sha3_256(input) == expected_output, 0);
     }
+
+    #[test]
+    fun keccak256_expected_hash() {
+        let input = x"616263";
+        let expected_output = x"4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45";
+        assert!(hash::keccak256(input) == expected_output, 0);
+        let expected_output = x"c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470";
+        assert!(hash::keccak256(x"") == expected_output, 1);
+    }
+
+    #[test]
+    fun blake2b_256_expected_hash() {
+        let input = x"616263";
+        let expected_output = x"bddd813c634239723171ef3fee98579b94964e3bb1cb3e427262c8c068d52319";
+        assert!(hash::blake2b_256(input) == expected_output, 0);
+        let expected_output = x"0e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a8";
+        assert!(hash::blake2b_256(x"") == expected_output, 1);
+    }
+
+    #[test]
+    fun ripemd160_expected_hash() {
+        let input = x"616263";
+        let expected_output = x"8eb208f7e05d987a9b044a8e98c6b087f15a0bfc";
+        assert!(hash::ripemd160(input) == expected_output, 0);
+    }
+
+    #[test]
+    fun sha2_512_expected_hash() {
+        let input = x"616263";
+        let expected_output = x"ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f";
+        assert!(hash::sha2_512(input) == expected_output, 0);
+    }
 }
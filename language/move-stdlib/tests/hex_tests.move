@@ -0,0 +1,51 @@
+#[test_only]
+module std::hex_tests {
+    use std::hex;
+    use std::string;
+    use std::vector;
+
+    #[test]
+    fun encode_bytes() {
+        assert!(*string::bytes(&hex::encode(&x"")) == b"", 0);
+        assert!(*string::bytes(&hex::encode(&x"00ff0a")) == b"00ff0a", 1);
+        assert!(*string::bytes(&hex::encode(&b"move")) == b"6d6f7665", 2);
+    }
+
+    #[test]
+    fun decode_lowercase_and_uppercase() {
+        assert!(hex::decode(&string::utf8(b"")) == x"", 0);
+        assert!(hex::decode(&string::utf8(b"00ff0a")) == x"00ff0a", 1);
+        assert!(hex::decode(&string::utf8(b"ABcdEF")) == x"abcdef", 2);
+    }
+
+    #[test]
+    fun round_trip_all_bytes() {
+        let bytes = vector::empty();
+        let i = 0;
+        while (i < 256) {
+            vector::push_back(&mut bytes, (i as u8));
+            i = i + 1;
+        };
+        let s = hex::encode(&bytes);
+        assert!(string::length(&s) == 512, 0);
+        assert!(hex::decode(&s) == bytes, 1);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x10001)]
+    fun decode_odd_length() {
+        hex::decode(&string::utf8(b"abc"));
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x10001)]
+    fun decode_invalid_digit() {
+        hex::decode(&string::utf8(b"0g"));
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x10001)]
+    fun decode_prefixed() {
+        hex::decode(&string::utf8(b"0x00"));
+    }
+}
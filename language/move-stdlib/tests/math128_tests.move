@@ -0,0 +1,165 @@
+#[test_only]
+module std::math128_tests {
+    use std::math128;
+    use std::option;
+
+    const MAX_U128: u128 = 340282366920938463463374607431768211455;
+    const MAX_U64: u128 = 18446744073709551615;
+
+    #[test]
+    fun max_min() {
+        assert!(math128::max(1, 2) == 2, 0);
+        assert!(math128::max(2, 1) == 2, 1);
+        assert!(math128::max(3, 3) == 3, 2);
+        assert!(math128::min(1, 2) == 1, 3);
+        assert!(math128::min(2, 1) == 1, 4);
+        assert!(math128::min(0, MAX_U128) == 0, 5);
+    }
+
+    #[test]
+    fun average() {
+        assert!(math128::average(0, 0) == 0, 0);
+        assert!(math128::average(1, 2) == 1, 1);
+        assert!(math128::average(2, 1) == 1, 2);
+        assert!(math128::average(MAX_U128, MAX_U128) == MAX_U128, 3);
+        assert!(math128::average(MAX_U128, MAX_U128 - 1) == MAX_U128 - 1, 4);
+        assert!(math128::average(0, MAX_U128) == MAX_U128 / 2, 5);
+    }
+
+    #[test]
+    fun pow() {
+        assert!(math128::pow(0, 0) == 1, 0);
+        assert!(math128::pow(0, 5) == 0, 1);
+        assert!(math128::pow(1, MAX_U128) == 1, 2);
+        assert!(math128::pow(2, 10) == 1024, 3);
+        assert!(math128::pow(10, 38) == 100000000000000000000000000000000000000, 4);
+        assert!(math128::pow(2, 127) == 170141183460469231731687303715884105728, 5);
+        assert!(math128::pow(MAX_U64 + 1, 1) == MAX_U64 + 1, 6);
+        assert!(math128::pow(MAX_U64, 2) == 340282366920938463426481119284349108225, 7);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x20002)]
+    fun pow_overflow() {
+        math128::pow(2, 128);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x20002)]
+    fun pow_overflow_square() {
+        math128::pow(MAX_U64 + 1, 2);
+    }
+
+    #[test]
+    fun checked_pow() {
+        assert!(math128::checked_pow(2, 127) == option::some(170141183460469231731687303715884105728), 0);
+        assert!(option::is_none(&math128::checked_pow(2, 128)), 1);
+        assert!(option::is_none(&math128::checked_pow(10, 39)), 2);
+        assert!(option::is_none(&math128::checked_pow(MAX_U128, 3)), 3);
+        assert!(math128::checked_pow(MAX_U128, 1) == option::some(MAX_U128), 4);
+    }
+
+    #[test]
+    fun sqrt_small_values() {
+        let x = 0;
+        while (x < 1000) {
+            let r = math128::sqrt(x);
+            assert!(r * r <= x, (x as u64));
+            assert!((r + 1) * (r + 1) > x, (x as u64));
+            x = x + 1;
+        };
+    }
+
+    #[test]
+    fun sqrt_large_values() {
+        assert!(math128::sqrt(MAX_U128) == MAX_U64, 0);
+        assert!(math128::sqrt(340282366920938463426481119284349108225) == MAX_U64, 1);
+        assert!(math128::sqrt(340282366920938463426481119284349108224) == MAX_U64 - 1, 2);
+        assert!(math128::sqrt(1 << 126) == 1 << 63, 3);
+        assert!(math128::sqrt((1 << 126) - 1) == (1 << 63) - 1, 4);
+    }
+
+    #[test]
+    fun log2() {
+        let i = 0;
+        while (i < 128) {
+            let x = 1 << i;
+            assert!(math128::log2(x) == i, (i as u64));
+            if (i > 1) {
+                assert!(math128::log2(x - 1) == i - 1, (i as u64));
+                assert!(math128::log2(x + 1) == i, (i as u64));
+            };
+            i = i + 1;
+        };
+        assert!(math128::log2(MAX_U128) == 127, 128);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x10003)]
+    fun log2_of_zero() {
+        math128::log2(0);
+    }
+
+    #[test]
+    fun mul_div() {
+        assert!(math128::mul_div(6, 7, 4) == 10, 0);
+        assert!(math128::mul_div_ceil(6, 7, 4) == 11, 1);
+        assert!(math128::mul_div(6, 8, 4) == 12, 2);
+        assert!(math128::mul_div_ceil(6, 8, 4) == 12, 3);
+        assert!(math128::mul_div(0, MAX_U128, 1) == 0, 4);
+        // The intermediate product does not fit in a u128.
+        assert!(math128::mul_div(MAX_U128, MAX_U128, MAX_U128) == MAX_U128, 5);
+        assert!(math128::mul_div(MAX_U128, 3, 4) == 255211775190703847597530955573826158591, 6);
+        assert!(math128::mul_div_ceil(MAX_U128, 3, 4) == 255211775190703847597530955573826158592, 7);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x10001)]
+    fun mul_div_by_zero() {
+        math128::mul_div(1, 1, 0);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x10001)]
+    fun mul_div_ceil_by_zero() {
+        math128::mul_div_ceil(1, 1, 0);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x10001)]
+    fun mul_div_internal_by_zero() {
+        // The native checks the divisor itself, rather than relying on its Move callers.
+        math128::mul_div_internal_for_testing(1, 1, 0, false);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x20002)]
+    fun mul_div_overflow() {
+        math128::mul_div(MAX_U128, MAX_U128, MAX_U64);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x20002)]
+    fun mul_div_ceil_overflow() {
+        // The quotient is MAX_U128 with a remainder.
+        assert!(math128::mul_div(7, 97223533405982418132392744980505203273, 2) == MAX_U128, 0);
+        math128::mul_div_ceil(7, 97223533405982418132392744980505203273, 2);
+    }
+
+    #[test]
+    fun checked_arithmetic() {
+        assert!(math128::checked_add(1, 2) == option::some(3), 0);
+        assert!(math128::checked_add(MAX_U128, 0) == option::some(MAX_U128), 1);
+        assert!(option::is_none(&math128::checked_add(MAX_U128, 1)), 2);
+        assert!(math128::checked_sub(3, 2) == option::some(1), 3);
+        assert!(math128::checked_sub(3, 3) == option::some(0), 4);
+        assert!(option::is_none(&math128::checked_sub(2, 3)), 5);
+        assert!(math128::checked_mul(MAX_U128, 1) == option::some(MAX_U128), 6);
+        assert!(math128::checked_mul(0, MAX_U128) == option::some(0), 7);
+        assert!(math128::checked_mul(MAX_U128, 0) == option::some(0), 8);
+        assert!(option::is_none(&math128::checked_mul(MAX_U64 + 1, MAX_U64 + 1)), 9);
+        assert!(math128::checked_mul(MAX_U64, MAX_U64 + 2) == option::some(MAX_U128), 10);
+        assert!(math128::checked_div(7, 2) == option::some(3), 11);
+        assert!(option::is_none(&math128::checked_div(7, 0)), 12);
+    }
+}
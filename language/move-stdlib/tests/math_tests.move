@@ -0,0 +1,153 @@
+#[test_only]
+module std::math_tests {
+    use std::math;
+    use std::option;
+
+    const MAX_U64: u64 = 18446744073709551615;
+
+    #[test]
+    fun max_min() {
+        assert!(math::max(1, 2) == 2, 0);
+        assert!(math::max(2, 1) == 2, 1);
+        assert!(math::max(3, 3) == 3, 2);
+        assert!(math::min(1, 2) == 1, 3);
+        assert!(math::min(2, 1) == 1, 4);
+        assert!(math::min(0, MAX_U64) == 0, 5);
+    }
+
+    #[test]
+    fun average() {
+        assert!(math::average(0, 0) == 0, 0);
+        assert!(math::average(1, 2) == 1, 1);
+        assert!(math::average(2, 1) == 1, 2);
+        assert!(math::average(3, 5) == 4, 3);
+        assert!(math::average(MAX_U64, MAX_U64) == MAX_U64, 4);
+        assert!(math::average(MAX_U64, MAX_U64 - 1) == MAX_U64 - 1, 5);
+        assert!(math::average(0, MAX_U64) == MAX_U64 / 2, 6);
+    }
+
+    #[test]
+    fun pow() {
+        assert!(math::pow(0, 0) == 1, 0);
+        assert!(math::pow(0, 5) == 0, 1);
+        assert!(math::pow(1, MAX_U64) == 1, 2);
+        assert!(math::pow(2, 10) == 1024, 3);
+        assert!(math::pow(3, 5) == 243, 4);
+        assert!(math::pow(10, 19) == 10000000000000000000, 5);
+        assert!(math::pow(2, 63) == 9223372036854775808, 6);
+        assert!(math::pow(4294967296, 1) == 4294967296, 7);
+        assert!(math::pow(4294967295, 2) == 18446744065119617025, 8);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x20002)]
+    fun pow_overflow() {
+        math::pow(2, 64);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x20002)]
+    fun pow_overflow_square() {
+        math::pow(4294967296, 2);
+    }
+
+    #[test]
+    fun checked_pow() {
+        assert!(math::checked_pow(2, 63) == option::some(9223372036854775808), 0);
+        assert!(option::is_none(&math::checked_pow(2, 64)), 1);
+        assert!(option::is_none(&math::checked_pow(10, 20)), 2);
+        assert!(option::is_none(&math::checked_pow(MAX_U64, 3)), 3);
+        assert!(math::checked_pow(MAX_U64, 1) == option::some(MAX_U64), 4);
+    }
+
+    #[test]
+    fun sqrt_small_values() {
+        let x = 0;
+        while (x < 1000) {
+            let r = math::sqrt(x);
+            assert!(r * r <= x, x);
+            assert!((r + 1) * (r + 1) > x, x);
+            x = x + 1;
+        };
+    }
+
+    #[test]
+    fun sqrt_large_values() {
+        assert!(math::sqrt(MAX_U64) == 4294967295, 0);
+        assert!(math::sqrt(18446744065119617025) == 4294967295, 1);
+        assert!(math::sqrt(18446744065119617024) == 4294967294, 2);
+        assert!(math::sqrt(1 << 62) == 1 << 31, 3);
+        assert!(math::sqrt((1 << 62) - 1) == (1 << 31) - 1, 4);
+    }
+
+    #[test]
+    fun log2() {
+        let i = 0;
+        while (i < 64) {
+            let x = 1 << i;
+            assert!(math::log2(x) == i, (i as u64));
+            if (i > 1) {
+                assert!(math::log2(x - 1) == i - 1, (i as u64));
+                assert!(math::log2(x + 1) == i, (i as u64));
+            };
+            i = i + 1;
+        };
+        assert!(math::log2(MAX_U64) == 63, 64);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x10003)]
+    fun log2_of_zero() {
+        math::log2(0);
+    }
+
+    #[test]
+    fun mul_div() {
+        assert!(math::mul_div(6, 7, 4) == 10, 0);
+        assert!(math::mul_div_ceil(6, 7, 4) == 11, 1);
+        assert!(math::mul_div(6, 8, 4) == 12, 2);
+        assert!(math::mul_div_ceil(6, 8, 4) == 12, 3);
+        assert!(math::mul_div(0, MAX_U64, 1) == 0, 4);
+        // The intermediate product does not fit in a u64.
+        assert!(math::mul_div(MAX_U64, MAX_U64, MAX_U64) == MAX_U64, 5);
+        assert!(math::mul_div(MAX_U64, 3, 4) == 13835058055282163711, 6);
+        assert!(math::mul_div_ceil(MAX_U64, 3, 4) == 13835058055282163712, 7);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x10001)]
+    fun mul_div_by_zero() {
+        math::mul_div(1, 1, 0);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x20002)]
+    fun mul_div_overflow() {
+        math::mul_div(MAX_U64, 2, 1);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x20002)]
+    fun mul_div_ceil_overflow() {
+        // The quotient is MAX_U64 with a remainder.
+        assert!(math::mul_div(31, 1190112520884487201, 2) == MAX_U64, 0);
+        math::mul_div_ceil(31, 1190112520884487201, 2);
+    }
+
+    #[test]
+    fun checked_arithmetic() {
+        assert!(math::checked_add(1, 2) == option::some(3), 0);
+        assert!(math::checked_add(MAX_U64, 0) == option::some(MAX_U64), 1);
+        assert!(option::is_none(&math::checked_add(MAX_U64, 1)), 2);
+        assert!(math::checked_sub(3, 2) == option::some(1), 3);
+        assert!(math::checked_sub(3, 3) == option::some(0), 4);
+        assert!(option::is_none(&math::checked_sub(2, 3)), 5);
+        assert!(math::checked_mul(MAX_U64, 1) == option::some(MAX_U64), 6);
+        assert!(math::checked_mul(0, MAX_U64) == option::some(0), 7);
+        assert!(math::checked_mul(MAX_U64, 0) == option::some(0), 8);
+        assert!(option::is_none(&math::checked_mul(4294967296, 4294967296)), 9);
+        assert!(math::checked_mul(4294967295, 4294967297) == option::some(MAX_U64), 10);
+        assert!(math::checked_div(7, 2) == option::some(3), 11);
+        assert!(option::is_none(&math::checked_div(7, 0)), 12);
+    }
+}
@@ -4,16 +4,17 @@
 
 use move_cli::base::test::{run_move_unit_tests, UnitTestResult};
 use move_core_types::account_address::AccountAddress;
+#[cfg(feature = "crypto")]
+use move_stdlib::natives::{crypto_natives, CryptoGasParameters};
 use move_stdlib::{
     natives::{all_natives, nursery_natives, GasParameters, NurseryGasParameters},
     path_in_crate,
 };
 use move_unit_test::UnitTestingConfig;
+use move_vm_runtime::native_functions::NativeFunctionTable;
 use tempfile::tempdir;
 
 fn run_tests_for_pkg(path_to_pkg: impl Into<String>, include_nursery_natives: bool) {
-    let pkg_path = path_in_crate(path_to_pkg);
-
     let mut natives = all_natives(
         AccountAddress::from_hex_literal("0x1").unwrap(),
         GasParameters::zeros(),
@@ -24,6 +25,11 @@ fn run_tests_for_pkg(path_to_pkg: impl Into<String>, include_nursery_natives: bo
             NurseryGasParameters::zeros(),
         ))
     }
+    run_tests_for_pkg_with_natives(path_to_pkg, natives)
+}
+
+fn run_tests_for_pkg_with_natives(path_to_pkg: impl Into<String>, natives: NativeFunctionTable) {
+    let pkg_path = path_in_crate(path_to_pkg);
 
     let result = run_move_unit_tests(
         &pkg_path,
@@ -48,3 +54,17 @@ fn move_unit_tests() {
     run_tests_for_pkg(".", false);
     run_tests_for_pkg("nursery", true);
 }
+
+#[cfg(feature = "crypto")]
+#[test]
+fn move_crypto_unit_tests() {
+    let mut natives = all_natives(
+        AccountAddress::from_hex_literal("0x1").unwrap(),
+        GasParameters::zeros(),
+    );
+    natives.extend(crypto_natives(
+        AccountAddress::from_hex_literal("0x1").unwrap(),
+        CryptoGasParameters::zeros(),
+    ));
+    run_tests_for_pkg_with_natives("crypto", natives);
+}
@@ -9,5 +9,6 @@ use move_cli::base::prove::ProverTest;
 #[test]
 fn prove() {
     ProverTest::create(".").run();
-    ProverTest::create("nursery").run()
+    ProverTest::create("nursery").run();
+    ProverTest::create("crypto").run()
 }
@@ -167,4 +167,18 @@ module std::option_tests {
         let v: vector<u64> = option::to_vec(option::none());
         assert!(vector::is_empty(&v), 0);
     }
+
+    #[test]
+    fun from_vec() {
+        assert!(option::from_vec(vector::empty<u64>()) == option::none(), 0);
+        assert!(option::from_vec(vector::singleton(1)) == option::some(1), 1);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x40002)]
+    fun from_vec_too_long() {
+        let v = vector::singleton(1);
+        vector::push_back(&mut v, 2);
+        option::from_vec(v);
+    }
 }
@@ -0,0 +1,196 @@
+#[test_only]
+module std::signed_fixed_point64_tests {
+    use std::fixed_point64;
+    use std::signed_fixed_point64::{Self, SignedFixedPoint64};
+    use std::vector;
+
+    const MAX_U128: u128 = 340282366920938463463374607431768211455;
+    const ONE: u128 = 18446744073709551616; // 0x1.0000000000000000
+
+    fun from_u128(val: u128, negative: bool): SignedFixedPoint64 {
+        signed_fixed_point64::create_from_rational(val, 1, negative)
+    }
+
+    #[test]
+    fun create_from_rational() {
+        let f = signed_fixed_point64::create_from_rational(3, 4, true); // -0.75
+        assert!(signed_fixed_point64::is_negative(f), 0);
+        assert!(signed_fixed_point64::get_raw_magnitude(f) == 13835058055282163712, 1);
+        let f = signed_fixed_point64::create_from_rational(3, 4, false); // 0.75
+        assert!(!signed_fixed_point64::is_negative(f), 2);
+        assert!(signed_fixed_point64::get_raw_magnitude(f) == 13835058055282163712, 3);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x10001)]
+    fun create_div_zero() {
+        signed_fixed_point64::create_from_rational(2, 0, true);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x20005)]
+    fun create_overflow() {
+        signed_fixed_point64::create_from_rational(18446744073709551616, 1, true); // -2^64
+    }
+
+    #[test]
+    fun zero_is_not_negative() {
+        let zero = signed_fixed_point64::create_from_rational(0, 1, true);
+        assert!(signed_fixed_point64::is_zero(zero), 0);
+        assert!(!signed_fixed_point64::is_negative(zero), 1);
+        assert!(zero == signed_fixed_point64::create_from_raw_value(0, false), 2);
+        assert!(zero == signed_fixed_point64::negate(zero), 3);
+        let one = from_u128(1, false);
+        assert!(signed_fixed_point64::sub(one, one) == zero, 4);
+        let minus_one = from_u128(1, true);
+        assert!(signed_fixed_point64::add(minus_one, one) == zero, 5);
+        assert!(signed_fixed_point64::mul(minus_one, zero) == zero, 6);
+        assert!(signed_fixed_point64::div(zero, minus_one) == zero, 7);
+    }
+
+    #[test]
+    fun negate_and_abs() {
+        let f = signed_fixed_point64::create_from_rational(7, 4, false); // 1.75
+        let minus_f = signed_fixed_point64::negate(f);
+        assert!(signed_fixed_point64::is_negative(minus_f), 0);
+        assert!(signed_fixed_point64::negate(minus_f) == f, 1);
+        assert!(signed_fixed_point64::abs(minus_f) == fixed_point64::create_from_rational(7, 4), 2);
+    }
+
+    #[test]
+    fun convert_from_and_to_fixed_point64() {
+        let f64 = fixed_point64::create_from_rational(7, 4); // 1.75
+        let f = signed_fixed_point64::from_fixed_point64(f64);
+        assert!(f == signed_fixed_point64::create_from_rational(7, 4, false), 0);
+        assert!(signed_fixed_point64::to_fixed_point64(f) == f64, 1);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x10003)]
+    fun to_fixed_point64_negative() {
+        signed_fixed_point64::to_fixed_point64(from_u128(1, true));
+    }
+
+    #[test]
+    fun add_and_sub() {
+        let two = from_u128(2, false);
+        let three = from_u128(3, false);
+        let minus_two = from_u128(2, true);
+        let minus_three = from_u128(3, true);
+        assert!(signed_fixed_point64::add(two, three) == from_u128(5, false), 0);
+        assert!(signed_fixed_point64::add(two, minus_three) == from_u128(1, true), 1);
+        assert!(signed_fixed_point64::add(minus_two, three) == from_u128(1, false), 2);
+        assert!(signed_fixed_point64::add(minus_two, minus_three) == from_u128(5, true), 3);
+        assert!(signed_fixed_point64::sub(two, three) == from_u128(1, true), 4);
+        assert!(signed_fixed_point64::sub(two, minus_three) == from_u128(5, false), 5);
+        assert!(signed_fixed_point64::sub(minus_two, three) == from_u128(5, true), 6);
+        assert!(signed_fixed_point64::sub(minus_two, minus_three) == from_u128(1, false), 7);
+    }
+
+    #[test]
+    fun add_extremes() {
+        let max = signed_fixed_point64::create_from_raw_value(MAX_U128, false);
+        let min = signed_fixed_point64::create_from_raw_value(MAX_U128, true);
+        assert!(signed_fixed_point64::is_zero(signed_fixed_point64::add(max, min)), 0);
+        let f = signed_fixed_point64::sub(max, signed_fixed_point64::create_from_raw_value(1, false));
+        assert!(signed_fixed_point64::get_raw_magnitude(f) == MAX_U128 - 1, 1);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x20001)]
+    fun add_overflow() {
+        let min = signed_fixed_point64::create_from_raw_value(MAX_U128, true);
+        signed_fixed_point64::add(min, signed_fixed_point64::create_from_raw_value(1, true));
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x20001)]
+    fun sub_overflow() {
+        let max = signed_fixed_point64::create_from_raw_value(MAX_U128, false);
+        signed_fixed_point64::sub(max, signed_fixed_point64::create_from_raw_value(1, true));
+    }
+
+    #[test]
+    fun mul() {
+        let f = signed_fixed_point64::create_from_rational(3, 4, true); // -0.75
+        let product = signed_fixed_point64::mul(f, from_u128(12, false));
+        assert!(product == from_u128(9, true), 0);
+        let product = signed_fixed_point64::mul(f, from_u128(12, true));
+        assert!(product == from_u128(9, false), 1);
+        // The product of the magnitudes does not fit in a u128.
+        let max = signed_fixed_point64::create_from_raw_value(MAX_U128, true);
+        assert!(signed_fixed_point64::mul(max, from_u128(1, true)) == signed_fixed_point64::negate(max), 2);
+    }
+
+    #[test]
+    fun mul_truncates() {
+        let f = signed_fixed_point64::create_from_raw_value(1, true); // -2^-64
+        let product = signed_fixed_point64::mul(f, signed_fixed_point64::create_from_rational(1, 2, false));
+        assert!(signed_fixed_point64::is_zero(product), 0);
+        assert!(!signed_fixed_point64::is_negative(product), 1);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x20002)]
+    fun mul_overflow() {
+        let max = signed_fixed_point64::create_from_raw_value(MAX_U128, true);
+        signed_fixed_point64::mul(max, from_u128(2, false));
+    }
+
+    #[test]
+    fun div() {
+        let f = signed_fixed_point64::create_from_rational(3, 4, true); // -0.75
+        assert!(signed_fixed_point64::div(from_u128(9, false), f) == from_u128(12, true), 0);
+        assert!(signed_fixed_point64::div(from_u128(9, true), f) == from_u128(12, false), 1);
+        let third = signed_fixed_point64::div(from_u128(1, true), from_u128(3, false));
+        assert!(signed_fixed_point64::is_negative(third), 2);
+        assert!(signed_fixed_point64::get_raw_magnitude(third) == 6148914691236517205, 3);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x10002)]
+    fun div_by_zero() {
+        signed_fixed_point64::div(from_u128(1, true), signed_fixed_point64::create_from_raw_value(0, true));
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x20002)]
+    fun div_overflow() {
+        let max = signed_fixed_point64::create_from_raw_value(MAX_U128, false);
+        signed_fixed_point64::div(max, signed_fixed_point64::create_from_rational(1, 2, true));
+    }
+
+    #[test]
+    fun compare() {
+        let minus_two = from_u128(2, true);
+        let minus_one = from_u128(1, true);
+        let zero = from_u128(0, false);
+        let one = from_u128(1, false);
+        let ordered = vector[minus_two, minus_one, zero, one];
+        let i = 0;
+        while (i < 4) {
+            let a = *vector::borrow(&ordered, i);
+            let j = 0;
+            while (j < 4) {
+                let b = *vector::borrow(&ordered, j);
+                assert!(signed_fixed_point64::less_than(a, b) == (i < j), i * 4 + j);
+                assert!(signed_fixed_point64::less_or_equal(a, b) == (i <= j), i * 4 + j);
+                assert!(signed_fixed_point64::greater_than(a, b) == (i > j), i * 4 + j);
+                assert!(signed_fixed_point64::greater_or_equal(a, b) == (i >= j), i * 4 + j);
+                j = j + 1;
+            };
+            i = i + 1;
+        }
+    }
+
+    #[test]
+    fun min_and_max() {
+        let minus_one = from_u128(1, true);
+        let one = from_u128(1, false);
+        assert!(signed_fixed_point64::min(minus_one, one) == minus_one, 0);
+        assert!(signed_fixed_point64::min(one, minus_one) == minus_one, 1);
+        assert!(signed_fixed_point64::max(minus_one, one) == one, 2);
+        assert!(signed_fixed_point64::max(one, minus_one) == one, 3);
+        assert!(signed_fixed_point64::get_raw_magnitude(one) == ONE, 4);
+    }
+}
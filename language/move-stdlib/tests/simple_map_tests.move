@@ -0,0 +1,93 @@
+#[test_only]
+module std::simple_map_tests {
+    use std::option;
+    use std::simple_map;
+
+    #[test]
+    fun add_borrow_remove() {
+        let map = simple_map::empty<u64, u64>();
+        assert!(simple_map::is_empty(&map), 0);
+        simple_map::add(&mut map, 3, 30);
+        simple_map::add(&mut map, 1, 10);
+        simple_map::add(&mut map, 2, 20);
+        assert!(simple_map::length(&map) == 3, 1);
+        assert!(simple_map::contains_key(&map, &1), 2);
+        assert!(!simple_map::contains_key(&map, &4), 3);
+        assert!(*simple_map::borrow(&map, &2) == 20, 4);
+
+        *simple_map::borrow_mut(&mut map, &2) = 21;
+        assert!(*simple_map::borrow(&map, &2) == 21, 5);
+
+        let (k, v) = simple_map::remove(&mut map, &3);
+        assert!(k == 3 && v == 30, 6);
+        assert!(!simple_map::contains_key(&map, &3), 7);
+        assert!(simple_map::length(&map) == 2, 8);
+    }
+
+    #[test]
+    fun keys_values_in_insertion_order() {
+        // Byte keys and values, as the stackless VM only supports byte and address vector
+        // constants.
+        let map = simple_map::empty<u8, u8>();
+        simple_map::add(&mut map, 3, 30);
+        simple_map::add(&mut map, 1, 10);
+        simple_map::add(&mut map, 2, 20);
+        assert!(simple_map::keys(&map) == vector[3u8, 1u8, 2u8], 0);
+        assert!(simple_map::values(&map) == vector[30u8, 10u8, 20u8], 1);
+
+        let (k, v) = simple_map::borrow_at(&map, 1);
+        assert!(*k == 1 && *v == 10, 2);
+
+        let (keys, values) = simple_map::to_vec_pair(map);
+        assert!(keys == vector[3u8, 1u8, 2u8], 3);
+        assert!(values == vector[30u8, 10u8, 20u8], 4);
+    }
+
+    #[test]
+    fun upsert() {
+        let map = simple_map::empty();
+        let (k, v) = simple_map::upsert(&mut map, 1, 10);
+        assert!(option::is_none(&k) && option::is_none(&v), 0);
+        let (k, v) = simple_map::upsert(&mut map, 1, 11);
+        assert!(k == option::some(1) && v == option::some(10), 1);
+        assert!(*simple_map::borrow(&map, &1) == 11, 2);
+        assert!(simple_map::length(&map) == 1, 3);
+    }
+
+    #[test]
+    fun destroy_empty() {
+        let map = simple_map::empty<u64, u64>();
+        simple_map::add(&mut map, 1, 10);
+        simple_map::remove(&mut map, &1);
+        simple_map::destroy_empty(map);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x80001)]
+    fun add_twice() {
+        let map = simple_map::empty();
+        simple_map::add(&mut map, 1, 10);
+        simple_map::add(&mut map, 1, 11);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x60002)]
+    fun borrow_missing() {
+        let map = simple_map::empty<u64, u64>();
+        simple_map::borrow(&map, &1);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x60002)]
+    fun remove_missing() {
+        let map = simple_map::empty<u64, u64>();
+        simple_map::remove(&mut map, &1);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x20003)]
+    fun borrow_at_out_of_bounds() {
+        let map = simple_map::empty<u64, u64>();
+        simple_map::borrow_at(&map, 0);
+    }
+}
@@ -0,0 +1,123 @@
+#[test_only]
+module std::string_utils_tests {
+    use std::option;
+    use std::string;
+    use std::string_utils::{format1, format2, format3, format4, to_string};
+    use std::vector;
+
+    struct Empty has copy, drop {}
+    struct Point has copy, drop { x: u64, y: u64 }
+    struct Named<T> has copy, drop { name: string::String, inner: T }
+
+    #[test]
+    fun to_string_primitives() {
+        assert!(*string::bytes(&to_string(&7u8)) == b"7", 0);
+        assert!(*string::bytes(&to_string(&18446744073709551615)) == b"18446744073709551615", 1);
+        assert!(*string::bytes(&to_string(&(1u128 << 100))) == b"1267650600228229401496703205376", 2);
+        assert!(*string::bytes(&to_string(&true)) == b"true", 3);
+        assert!(*string::bytes(&to_string(&false)) == b"false", 4);
+    }
+
+    #[test]
+    fun to_string_addresses() {
+        assert!(*string::bytes(&to_string(&@0x1)) == b"@0x1", 0);
+        assert!(*string::bytes(&to_string(&@0xcafe)) == b"@0xcafe", 1);
+        assert!(*string::bytes(&to_string(&@0x0)) == b"@0x0", 2);
+    }
+
+    #[test(s = @0x42)]
+    fun to_string_signer(s: signer) {
+        assert!(*string::bytes(&to_string(&s)) == b"signer(@0x42)", 0);
+    }
+
+    #[test]
+    fun to_string_vectors() {
+        assert!(*string::bytes(&to_string(&x"0aff")) == b"0x0aff", 0);
+        assert!(*string::bytes(&to_string(&vector::empty<u8>())) == b"0x", 1);
+
+        let v = vector::singleton(1);
+        vector::push_back(&mut v, 2);
+        assert!(*string::bytes(&to_string(&v)) == b"[1, 2]", 2);
+        assert!(*string::bytes(&to_string(&vector::empty<u64>())) == b"[]", 3);
+
+        let v = vector::singleton(vector::singleton(true));
+        vector::push_back(&mut v, vector::empty());
+        assert!(*string::bytes(&to_string(&v)) == b"[[true], []]", 4);
+    }
+
+    #[test]
+    fun to_string_strings() {
+        assert!(*string::bytes(&to_string(&string::utf8(b"hello"))) == b"\"hello\"", 0);
+        assert!(*string::bytes(&to_string(&string::utf8(b"a \"b\""))) == b"\"a \\\"b\\\"\"", 1);
+    }
+
+    #[test]
+    fun to_string_structs() {
+        assert!(*string::bytes(&to_string(&Empty {})) == b"Empty {}", 0);
+        assert!(*string::bytes(&to_string(&Point { x: 1, y: 2 })) == b"Point { x: 1, y: 2 }", 1);
+
+        let named = Named { name: string::utf8(b"origin"), inner: Point { x: 0, y: 0 } };
+        assert!(
+            *string::bytes(&to_string(&named)) == b"Named { name: \"origin\", inner: Point { x: 0, y: 0 } }",
+            2
+        );
+        assert!(*string::bytes(&to_string(&option::some(3))) == b"Option { vec: [3] }", 3);
+    }
+
+    #[test]
+    fun format_values() {
+        assert!(*string::bytes(&format1(&b"x = {}", 10)) == b"x = 10", 0);
+        assert!(*string::bytes(&format2(&b"{} at {}", true, @0x2)) == b"true at @0x2", 1);
+        assert!(
+            *string::bytes(&format3(&b"{}{}{}", 1u8, string::utf8(b"-"), Empty {})) == b"1\"-\"Empty {}",
+            2
+        );
+        assert!(
+            *string::bytes(&format4(&b"({}, {}) {} {}", 1, 2, x"ab", Point { x: 3, y: 4 })) ==
+                b"(1, 2) 0xab Point { x: 3, y: 4 }",
+            3
+        );
+    }
+
+    #[test]
+    fun format_escaped_braces() {
+        assert!(*string::bytes(&format1(&b"{{{}}}", 5)) == b"{5}", 0);
+        assert!(*string::bytes(&format1(&b"}}{}{{", 5)) == b"}5{", 1);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x10001)]
+    fun format_too_few_placeholders() {
+        format2(&b"{}", 1, 2);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x10001)]
+    fun format_too_many_placeholders() {
+        format1(&b"{} {}", 1);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x10002)]
+    fun format_unmatched_open_brace() {
+        format1(&b"{} {", 1);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x10002)]
+    fun format_trailing_open_brace() {
+        format1(&b"{}{", 1);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x10002)]
+    fun format_unmatched_close_brace() {
+        format1(&b"} {}", 1);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x10002)]
+    fun format_invalid_utf8() {
+        format1(&x"ff7b7d", 1);
+    }
+}
@@ -0,0 +1,71 @@
+// note: intentionally using 0xa here to test non-0x1 module addresses
+module 0xA::type_info_tests {
+    #[test_only]
+    use std::type_info::{Self, type_of, size_of_val, has_copy, has_drop, has_store, has_key};
+    #[test_only]
+    use std::type_name;
+    #[test_only]
+    use std::ascii::string;
+    #[test_only]
+    use std::vector;
+
+    struct TestStruct has copy, drop { x: u64 }
+
+    struct TestGenerics<phantom T> has drop { }
+
+    struct TestResource has key, store { }
+
+    #[test]
+    fun test_structs() {
+        let info = type_of<TestStruct>();
+        assert!(type_info::account_address(&info) == @0xA, 0);
+        assert!(*type_info::module_name(&info) == string(b"type_info_tests"), 1);
+        assert!(*type_info::struct_name(&info) == string(b"TestStruct"), 2);
+        assert!(vector::is_empty(type_info::type_args(&info)), 3);
+
+        let info = type_of<std::string::String>();
+        assert!(type_info::account_address(&info) == @0x1, 4);
+        assert!(*type_info::module_name(&info) == string(b"string"), 5);
+        assert!(*type_info::struct_name(&info) == string(b"String"), 6);
+    }
+
+    #[test]
+    fun test_generics() {
+        let info = type_of<TestGenerics<vector<TestStruct>>>();
+        assert!(*type_info::struct_name(&info) == string(b"TestGenerics"), 0);
+        let type_args = type_info::type_args(&info);
+        assert!(vector::length(type_args) == 1, 1);
+        assert!(*vector::borrow(type_args, 0) == type_name::get<vector<TestStruct>>(), 2);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 1)]
+    fun test_not_struct() {
+        type_of<vector<TestStruct>>();
+    }
+
+    #[test]
+    fun test_abilities() {
+        assert!(has_copy<u64>() && has_drop<u64>() && has_store<u64>() && !has_key<u64>(), 0);
+        assert!(!has_copy<signer>() && has_drop<signer>() && !has_store<signer>(), 1);
+        assert!(has_copy<TestStruct>() && has_drop<TestStruct>() && !has_store<TestStruct>(), 2);
+        assert!(!has_copy<TestResource>() && !has_drop<TestResource>(), 3);
+        assert!(has_store<TestResource>() && has_key<TestResource>(), 4);
+        // the abilities of a generic struct depend on its type arguments
+        assert!(has_drop<TestGenerics<u64>>(), 5);
+        assert!(!has_copy<vector<TestResource>>() && has_store<vector<TestResource>>(), 6);
+        assert!(!has_drop<vector<TestResource>>(), 7);
+    }
+
+    #[test]
+    fun test_size_of_val() {
+        assert!(size_of_val(&0u8) == 1, 0);
+        assert!(size_of_val(&0u64) == 8, 1);
+        assert!(size_of_val(&true) == 1, 2);
+        assert!(size_of_val(&TestStruct { x: 0 }) == 8, 3);
+        // the length of a vector is prefixed as a ULEB128
+        assert!(size_of_val(&vector<u8>[]) == 1, 4);
+        assert!(size_of_val(&vector[TestStruct { x: 1 }, TestStruct { x: 2 }]) == 17, 5);
+        assert!(size_of_val(&string(b"abc")) == 4, 6);
+    }
+}
@@ -5,6 +5,7 @@ module std::vector_tests {
     struct R has store { }
     struct Droppable has drop {}
     struct NotDroppable {}
+    struct Pair has copy, drop { a: u64, b: bool }
 
     #[test]
     fun test_singleton_contains() {
@@ -500,4 +501,282 @@ module std::vector_tests {
             NotDroppable {}
         );
     }
+
+    #[test_only]
+    /// Widen a vector of bytes, as the stackless VM only supports byte and address vector
+    /// constants.
+    fun u64s(bytes: vector<u8>): vector<u64> {
+        let v = V::empty();
+        let i = 0;
+        while (i < V::length(&bytes)) {
+            V::push_back(&mut v, (*V::borrow(&bytes, i) as u64));
+            i = i + 1;
+        };
+        v
+    }
+
+    #[test]
+    fun move_range_between_vectors() {
+        let from = vector[0u8, 1, 2, 3, 4];
+        let to = vector[10u8, 11, 12];
+        V::move_range(&mut from, 1, 3, &mut to, 1);
+        assert!(from == vector[0u8, 4], 0);
+        assert!(to == vector[10u8, 1, 2, 3, 11, 12], 1);
+
+        V::move_range(&mut from, 0, 2, &mut to, 6);
+        assert!(V::is_empty(&from), 2);
+        assert!(to == vector[10u8, 1, 2, 3, 11, 12, 0, 4], 3);
+
+        V::move_range(&mut to, 3, 0, &mut from, 0);
+        assert!(V::is_empty(&from), 4);
+        assert!(V::length(&to) == 8, 5);
+    }
+
+    #[test]
+    fun move_range_generic_elements() {
+        let from = V::singleton(vector[1u8]);
+        V::push_back(&mut from, vector[2u8, 3]);
+        let to = V::singleton(vector[]);
+        V::move_range(&mut from, 0, 2, &mut to, 0);
+        assert!(V::is_empty(&from), 0);
+        assert!(V::length(&to) == 3, 1);
+        assert!(*V::borrow(&to, 0) == vector[1u8], 2);
+        assert!(*V::borrow(&to, 1) == vector[2u8, 3], 3);
+        assert!(V::is_empty(V::borrow(&to, 2)), 4);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 1)]
+    fun move_range_out_of_range() {
+        let from = vector[0u8, 1, 2];
+        let to = V::empty();
+        V::move_range(&mut from, 2, 2, &mut to, 0);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 1)]
+    fun move_range_insert_out_of_range() {
+        let from = vector[0u8, 1, 2];
+        let to = vector[0u8];
+        V::move_range(&mut from, 0, 1, &mut to, 2);
+    }
+
+    #[test]
+    fun insert_at_all_positions() {
+        let v = u64s(vector[1, 3]);
+        V::insert(&mut v, 0, 0);
+        assert!(v == u64s(vector[0, 1, 3]), 0);
+        V::insert(&mut v, 2, 2);
+        assert!(v == u64s(vector[0, 1, 2, 3]), 1);
+        V::insert(&mut v, 4, 4);
+        assert!(v == u64s(vector[0, 1, 2, 3, 4]), 2);
+
+        let v = V::empty();
+        V::insert(&mut v, 0, NotDroppable {});
+        NotDroppable {} = V::pop_back(&mut v);
+        V::destroy_empty(v);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x20000)]
+    fun insert_out_of_range() {
+        let v = vector[0u8, 1];
+        V::insert(&mut v, 3, 3);
+    }
+
+    #[test]
+    fun slice_ranges() {
+        let v = vector[0u8, 1, 2, 3, 4];
+        assert!(V::slice(&v, 1, 4) == vector[1u8, 2, 3], 0);
+        assert!(V::slice(&v, 0, 5) == v, 1);
+        assert!(V::is_empty(&V::slice(&v, 2, 2)), 2);
+        assert!(V::is_empty(&V::slice(&v, 5, 5)), 3);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x20001)]
+    fun slice_end_out_of_range() {
+        V::slice(&vector[0u8, 1, 2], 1, 4);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x20001)]
+    fun slice_start_after_end() {
+        V::slice(&vector[0u8, 1, 2], 2, 1);
+    }
+
+    #[test]
+    fun rotate_vector() {
+        let v = vector[0u8, 1, 2, 3, 4];
+        assert!(V::rotate(&mut v, 2) == 3, 0);
+        assert!(v == vector[2u8, 3, 4, 0, 1], 1);
+        assert!(V::rotate(&mut v, 0) == 5, 2);
+        assert!(v == vector[2u8, 3, 4, 0, 1], 3);
+        assert!(V::rotate(&mut v, 5) == 0, 4);
+        assert!(v == vector[2u8, 3, 4, 0, 1], 5);
+
+        let v = V::empty<u64>();
+        assert!(V::rotate(&mut v, 0) == 0, 6);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x20000)]
+    fun rotate_out_of_range() {
+        let v = vector[0u8, 1];
+        V::rotate(&mut v, 3);
+    }
+
+    #[test]
+    fun trim_vector() {
+        let v = vector[0u8, 1, 2, 3];
+        assert!(V::trim(&mut v, 1) == vector[1u8, 2, 3], 0);
+        assert!(v == vector[0u8], 1);
+        assert!(V::is_empty(&V::trim(&mut v, 1)), 2);
+        assert!(V::trim(&mut v, 0) == vector[0u8], 3);
+        assert!(V::is_empty(&v), 4);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x20000)]
+    fun trim_out_of_range() {
+        let v = vector[0u8, 1];
+        V::trim(&mut v, 3);
+    }
+
+    #[test]
+    fun sort_primitives() {
+        let v = vector[200u8, 0, 17, 0];
+        V::sort(&mut v);
+        assert!(v == vector[0u8, 0, 17, 200], 0);
+
+        let v = u64s(vector[3, 1, 4, 1, 5, 9, 2, 6]);
+        V::sort(&mut v);
+        assert!(v == u64s(vector[1, 1, 2, 3, 4, 5, 6, 9]), 1);
+
+        let v = V::singleton(1u128 << 100);
+        V::push_back(&mut v, 1);
+        V::push_back(&mut v, 1 << 64);
+        V::sort(&mut v);
+        assert!(*V::borrow(&v, 0) == 1, 2);
+        assert!(*V::borrow(&v, 1) == 1 << 64, 3);
+        assert!(*V::borrow(&v, 2) == 1 << 100, 4);
+
+        let v = V::singleton(true);
+        V::push_back(&mut v, false);
+        V::push_back(&mut v, true);
+        V::sort(&mut v);
+        assert!(!*V::borrow(&v, 0) && *V::borrow(&v, 1) && *V::borrow(&v, 2), 5);
+
+        let v = vector[@0x300, @0x2, @0x10];
+        V::sort(&mut v);
+        assert!(v == vector[@0x2, @0x10, @0x300], 6);
+
+        let v = V::empty<u64>();
+        V::sort(&mut v);
+        assert!(V::is_empty(&v), 7);
+    }
+
+    #[test]
+    fun sort_vectors_lexicographically() {
+        let v = V::singleton(vector[2u8]);
+        V::push_back(&mut v, vector[1u8, 5]);
+        V::push_back(&mut v, vector[]);
+        V::push_back(&mut v, vector[1u8]);
+        V::sort(&mut v);
+        assert!(V::is_empty(V::borrow(&v, 0)), 0);
+        assert!(*V::borrow(&v, 1) == vector[1u8], 1);
+        assert!(*V::borrow(&v, 2) == vector[1u8, 5], 2);
+        assert!(*V::borrow(&v, 3) == vector[2u8], 3);
+
+        let v = V::singleton(u64s(vector[3, 1]));
+        V::push_back(&mut v, u64s(vector[2, 7, 0]));
+        V::push_back(&mut v, u64s(vector[3]));
+        V::sort(&mut v);
+        assert!(*V::borrow(&v, 0) == u64s(vector[2, 7, 0]), 4);
+        assert!(*V::borrow(&v, 1) == u64s(vector[3]), 5);
+        assert!(*V::borrow(&v, 2) == u64s(vector[3, 1]), 6);
+    }
+
+    #[test]
+    fun sort_structs_by_fields() {
+        let v = vector[
+            Pair { a: 2, b: false },
+            Pair { a: 1, b: true },
+            Pair { a: 2, b: true },
+            Pair { a: 1, b: false },
+        ];
+        V::sort(&mut v);
+        assert!(v == vector[
+            Pair { a: 1, b: false },
+            Pair { a: 1, b: true },
+            Pair { a: 2, b: false },
+            Pair { a: 2, b: true },
+        ], 0);
+    }
+
+    #[test]
+    fun sort_by_key_is_stable() {
+        let v = vector[@0xa, @0xb, @0xc, @0xd, @0xe];
+        let keys = u64s(vector[3, 1, 2, 1, 0]);
+        V::sort_by_key(&mut v, &mut keys);
+        assert!(keys == u64s(vector[0, 1, 1, 2, 3]), 0);
+        assert!(v == vector[@0xe, @0xb, @0xd, @0xc, @0xa], 1);
+
+        let v = V::singleton(NotDroppable {});
+        let keys = V::singleton(vector[1u8]);
+        V::sort_by_key(&mut v, &mut keys);
+        NotDroppable {} = V::pop_back(&mut v);
+        V::destroy_empty(v);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 1)]
+    fun sort_by_key_length_mismatch() {
+        let v = vector[0u8, 1];
+        let keys = vector[0u8];
+        V::sort_by_key(&mut v, &mut keys);
+    }
+
+    #[test]
+    fun binary_search_sorted() {
+        let v = u64s(vector[1, 3, 5, 7]);
+        let (found, i) = V::binary_search(&v, &5);
+        assert!(found && i == 2, 0);
+        let (found, i) = V::binary_search(&v, &1);
+        assert!(found && i == 0, 1);
+        let (found, i) = V::binary_search(&v, &4);
+        assert!(!found && i == 2, 2);
+        let (found, i) = V::binary_search(&v, &0);
+        assert!(!found && i == 0, 3);
+        let (found, i) = V::binary_search(&v, &8);
+        assert!(!found && i == 4, 4);
+        let (found, i) = V::binary_search(&V::empty(), &8);
+        assert!(!found && i == 0, 5);
+
+        let v = vector[Pair { a: 1, b: false }, Pair { a: 1, b: true }, Pair { a: 4, b: false }];
+        let (found, i) = V::binary_search(&v, &Pair { a: 1, b: true });
+        assert!(found && i == 1, 6);
+        let (found, i) = V::binary_search(&v, &Pair { a: 2, b: false });
+        assert!(!found && i == 2, 7);
+    }
+
+    #[test]
+    fun dedup_consecutive() {
+        let v = u64s(vector[1, 1, 2, 3, 3, 3, 1, 4, 4]);
+        V::dedup(&mut v);
+        assert!(v == u64s(vector[1, 2, 3, 1, 4]), 0);
+
+        let v = vector[5u8, 5, 5];
+        V::dedup(&mut v);
+        assert!(v == vector[5u8], 1);
+
+        let v = V::empty<u64>();
+        V::dedup(&mut v);
+        assert!(V::is_empty(&v), 2);
+
+        let v = vector[3u8, 1, 3, 2, 1];
+        V::sort(&mut v);
+        V::dedup(&mut v);
+        assert!(v == vector[1u8, 2, 3], 3);
+    }
 }
@@ -9,6 +9,18 @@ use std::{fs::File, io::Write, path::Path};
 use tempfile::tempdir;
 
 pub fn compile_units(s: &str) -> Result<Vec<AnnotatedCompiledUnit>> {
+    compile_units_with_targets(s, vec![])
+}
+
+/// Compile `s` together with the standard library, returning the units of both.
+pub fn compile_units_with_stdlib(s: &str) -> Result<Vec<AnnotatedCompiledUnit>> {
+    compile_units_with_targets(s, move_stdlib::move_stdlib_files())
+}
+
+fn compile_units_with_targets(
+    s: &str,
+    mut targets: Vec<String>,
+) -> Result<Vec<AnnotatedCompiledUnit>> {
     let dir = tempdir()?;
 
     let file_path = dir.path().join("modules.move");
@@ -17,12 +29,10 @@ pub fn compile_units(s: &str) -> Result<Vec<AnnotatedCompiledUnit>> {
         writeln!(file, "{}", s)?;
     }
 
-    let (_, units) = MoveCompiler::from_files(
-        vec![file_path.to_str().unwrap().to_string()],
-        vec![],
-        move_stdlib::move_stdlib_named_addresses(),
-    )
-    .build_and_report()?;
+    targets.push(file_path.to_str().unwrap().to_string());
+    let (_, units) =
+        MoveCompiler::from_files(targets, vec![], move_stdlib::move_stdlib_named_addresses())
+            .build_and_report()?;
 
     dir.close()?;
 
@@ -0,0 +1,249 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::compiler::{as_module, compile_units_with_stdlib};
+use move_binary_format::errors::VMResult;
+use move_core_types::{
+    account_address::AccountAddress,
+    identifier::Identifier,
+    language_storage::{ModuleId, TypeTag},
+    value::MoveValue,
+    vm_status::StatusCode,
+};
+use move_vm_runtime::{
+    config::{StructArgConstructor, VMConfig},
+    move_vm::MoveVM,
+};
+use move_vm_test_utils::InMemoryStorage;
+use move_vm_types::gas::UnmeteredGasMeter;
+
+const TEST_ADDR: AccountAddress = AccountAddress::new([42; AccountAddress::LENGTH]);
+
+const CODE: &str = r#"
+    module 0x{ADDR}::M {
+        use std::option::{Self, Option};
+        use std::string::{Self, String};
+        use std::vector;
+
+        struct S has drop { x: u64 }
+
+        public entry fun take_string(s: String) {
+            assert!(s == string::utf8(b"hello"), 1);
+        }
+
+        public entry fun take_string_ref(_account: &signer, s: &String) {
+            assert!(*s == string::utf8(b"hello"), 2);
+        }
+
+        public entry fun take_option(o: Option<u64>, expected: u64) {
+            assert!(option::destroy_with_default(o, 0) == expected, 3);
+        }
+
+        public entry fun take_nested(v: vector<vector<String>>) {
+            assert!(vector::length(&v) == 2, 4);
+            assert!(*vector::borrow(vector::borrow(&v, 1), 0) == string::utf8(b"b"), 5);
+        }
+
+        public entry fun take_struct(s: S) {
+            assert!(s.x == 1, 6);
+        }
+
+        public entry fun take_option_struct(_o: Option<S>) {}
+
+        struct W<T> has drop { v: vector<vector<T>> }
+
+        public fun new_w<T>(v: vector<vector<T>>): W<T> {
+            W { v }
+        }
+
+        public entry fun take_w(w: W<u64>) {
+            assert!(vector::length(&w.v) == 1, 7);
+        }
+    }
+"#;
+
+fn setup(vm_config: VMConfig) -> (MoveVM, InMemoryStorage) {
+    let code = CODE.replace("{ADDR}", &format!("{}", TEST_ADDR));
+    let mut storage = InMemoryStorage::new();
+    for unit in compile_units_with_stdlib(&code).unwrap() {
+        let module = as_module(unit);
+        let mut blob = vec![];
+        module.serialize(&mut blob).unwrap();
+        storage.publish_or_overwrite_module(module.self_id(), blob);
+    }
+    let vm = MoveVM::new_with_config(
+        move_stdlib::natives::all_natives(
+            AccountAddress::from_hex_literal("0x1").unwrap(),
+            move_stdlib::natives::GasParameters::zeros(),
+        ),
+        vm_config,
+    )
+    .unwrap();
+    (vm, storage)
+}
+
+fn run(name: &str, args: Vec<Vec<u8>>) -> VMResult<()> {
+    run_with_config(VMConfig::default(), name, args)
+}
+
+fn run_with_config(vm_config: VMConfig, name: &str, args: Vec<Vec<u8>>) -> VMResult<()> {
+    let (vm, storage) = setup(vm_config);
+    let mut sess = vm.new_session(&storage);
+    let module_id = ModuleId::new(TEST_ADDR, Identifier::new("M").unwrap());
+    sess.execute_entry_function(
+        &module_id,
+        &Identifier::new(name).unwrap(),
+        vec![],
+        args,
+        &mut UnmeteredGasMeter,
+    )
+    .map(|_| ())
+}
+
+fn bytes(s: &str) -> MoveValue {
+    MoveValue::vector_u8(s.as_bytes().to_vec())
+}
+
+fn serialize(value: MoveValue) -> Vec<u8> {
+    value.simple_serialize().unwrap()
+}
+
+fn status(result: VMResult<()>) -> StatusCode {
+    result.unwrap_err().major_status()
+}
+
+#[test]
+fn string_args() {
+    run("take_string", vec![serialize(bytes("hello"))]).unwrap();
+    run(
+        "take_string_ref",
+        vec![
+            serialize(MoveValue::Signer(TEST_ADDR)),
+            serialize(bytes("hello")),
+        ],
+    )
+    .unwrap();
+
+    // not UTF-8
+    assert_eq!(
+        status(run(
+            "take_string",
+            vec![serialize(MoveValue::vector_u8(vec![0xff]))]
+        )),
+        StatusCode::FAILED_TO_DESERIALIZE_ARGUMENT
+    );
+    // trailing bytes
+    let mut arg = serialize(bytes("hello"));
+    arg.push(0);
+    assert_eq!(
+        status(run("take_string", vec![arg])),
+        StatusCode::FAILED_TO_DESERIALIZE_ARGUMENT
+    );
+    // truncated
+    let mut arg = serialize(bytes("hello"));
+    arg.pop();
+    assert_eq!(
+        status(run("take_string", vec![arg])),
+        StatusCode::FAILED_TO_DESERIALIZE_ARGUMENT
+    );
+}
+
+#[test]
+fn option_args() {
+    let option = |values: Vec<u64>| {
+        serialize(MoveValue::Vector(
+            values.into_iter().map(MoveValue::U64).collect(),
+        ))
+    };
+    run(
+        "take_option",
+        vec![option(vec![]), serialize(MoveValue::U64(0))],
+    )
+    .unwrap();
+    run(
+        "take_option",
+        vec![option(vec![7]), serialize(MoveValue::U64(7))],
+    )
+    .unwrap();
+    assert_eq!(
+        status(run(
+            "take_option",
+            vec![option(vec![1, 2]), serialize(MoveValue::U64(1))]
+        )),
+        StatusCode::FAILED_TO_DESERIALIZE_ARGUMENT
+    );
+}
+
+#[test]
+fn nested_vector_args() {
+    let arg = MoveValue::Vector(vec![
+        MoveValue::Vector(vec![bytes("a")]),
+        MoveValue::Vector(vec![bytes("b"), bytes("c")]),
+    ]);
+    run("take_nested", vec![serialize(arg)]).unwrap();
+
+    let arg = MoveValue::Vector(vec![
+        MoveValue::Vector(vec![]),
+        MoveValue::Vector(vec![MoveValue::vector_u8(vec![0xff])]),
+    ]);
+    assert_eq!(
+        status(run("take_nested", vec![serialize(arg)])),
+        StatusCode::FAILED_TO_DESERIALIZE_ARGUMENT
+    );
+}
+
+#[test]
+fn struct_args_must_be_allowed() {
+    let s = serialize(MoveValue::U64(1));
+    assert_eq!(
+        status(run("take_struct", vec![s.clone()])),
+        StatusCode::INVALID_MAIN_FUNCTION_SIGNATURE
+    );
+    // the type arguments of allowed structs are checked, even for empty values
+    assert_eq!(
+        status(run(
+            "take_option_struct",
+            vec![serialize(MoveValue::Vector(vec![]))]
+        )),
+        StatusCode::INVALID_MAIN_FUNCTION_SIGNATURE
+    );
+
+    // arguments are not checked when bypassing the entry function checks
+    let (vm, storage) = setup(VMConfig::default());
+    let mut sess = vm.new_session(&storage);
+    sess.execute_function_bypass_visibility(
+        &ModuleId::new(TEST_ADDR, Identifier::new("M").unwrap()),
+        &Identifier::new("take_struct").unwrap(),
+        Vec::<TypeTag>::new(),
+        vec![s],
+        &mut UnmeteredGasMeter,
+    )
+    .unwrap();
+}
+
+#[test]
+fn struct_arg_constructor_type_depth() {
+    let vm_config = |max_type_depth| {
+        let mut struct_arg_constructors = StructArgConstructor::std_constructors();
+        struct_arg_constructors.push(StructArgConstructor::new(
+            ModuleId::new(TEST_ADDR, Identifier::new("M").unwrap()),
+            "W",
+            "new_w",
+        ));
+        VMConfig {
+            max_type_depth,
+            struct_arg_constructors,
+            ..VMConfig::default()
+        }
+    };
+    let arg = serialize(MoveValue::Vector(vec![MoveValue::Vector(vec![
+        MoveValue::U64(1),
+    ])]));
+    run_with_config(vm_config(3), "take_w", vec![arg.clone()]).unwrap();
+
+    // `W<u64>` is within the limit, but the parameter of its constructor is not
+    assert_eq!(
+        status(run_with_config(vm_config(2), "take_w", vec![arg])),
+        StatusCode::TOO_MANY_TYPE_NODES
+    );
+}
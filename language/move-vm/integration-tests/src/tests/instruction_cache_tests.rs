@@ -0,0 +1,158 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::compiler::{as_module, compile_units};
+use move_core_types::{
+    account_address::AccountAddress,
+    identifier::Identifier,
+    language_storage::{ModuleId, TypeTag},
+    value::MoveValue,
+};
+use move_vm_runtime::{config::VMConfig, move_vm::MoveVM};
+use move_vm_test_utils::InMemoryStorage;
+use move_vm_types::gas::UnmeteredGasMeter;
+
+const TEST_ADDR: AccountAddress = AccountAddress::new([42; AccountAddress::LENGTH]);
+
+const CODE: &str = r#"
+    module 0x{ADDR}::M {
+        struct R<T> has key { v: vector<vector<T>> }
+
+        fun pair<T: copy + drop>(x: T, n: u64): vector<T> {
+            if (n == 0) vector[x, x] else pair(x, n - 1)
+        }
+
+        fun nested<T: copy + drop>(x: T): vector<vector<T>> {
+            vector[pair(x, 3), pair(x, 1)]
+        }
+
+        public fun foo<T: copy + drop>(x: T): vector<vector<T>> {
+            nested(x)
+        }
+
+        public fun store<T: copy + drop + store>(s: signer, x: T) {
+            move_to(&s, R { v: nested(x) })
+        }
+
+        public fun load<T: copy + drop + store>(addr: address): vector<vector<T>> acquires R {
+            assert!(exists<R<T>>(addr), 1);
+            let R { v } = move_from<R<T>>(addr);
+            v
+        }
+    }
+"#;
+
+fn values() -> Vec<(TypeTag, MoveValue)> {
+    vec![
+        (TypeTag::U64, MoveValue::U64(7)),
+        (TypeTag::Bool, MoveValue::Bool(true)),
+        (TypeTag::U8, MoveValue::U8(1)),
+        (TypeTag::Address, MoveValue::Address(TEST_ADDR)),
+    ]
+}
+
+fn expected(value: &MoveValue) -> Vec<u8> {
+    let pair = MoveValue::Vector(vec![value.clone(), value.clone()]);
+    MoveValue::Vector(vec![pair.clone(), pair])
+        .simple_serialize()
+        .unwrap()
+}
+
+fn setup() -> (InMemoryStorage, ModuleId) {
+    let code = CODE.replace("{ADDR}", &format!("{}", TEST_ADDR));
+    let mut units = compile_units(&code).unwrap();
+    let mut blob = vec![];
+    as_module(units.pop().unwrap())
+        .serialize(&mut blob)
+        .unwrap();
+    let mut storage = InMemoryStorage::new();
+    let module_id = ModuleId::new(TEST_ADDR, Identifier::new("M").unwrap());
+    storage.publish_or_overwrite_module(module_id.clone(), blob);
+    (storage, module_id)
+}
+
+fn instantiations(vm_config: VMConfig) {
+    let (storage, module_id) = setup();
+    let vm = MoveVM::new_with_config(vec![], vm_config).unwrap();
+    let mut sess = vm.new_session(&storage);
+    let fun_name = Identifier::new("foo").unwrap();
+
+    let values = values();
+    // run every instantiation twice, so that the second runs use the resolved operands
+    for (ty, value) in values.iter().chain(values.iter()) {
+        let results = sess
+            .execute_function_bypass_visibility(
+                &module_id,
+                &fun_name,
+                vec![ty.clone()],
+                vec![value.simple_serialize().unwrap()],
+                &mut UnmeteredGasMeter,
+            )
+            .unwrap()
+            .return_values;
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].0, expected(value));
+    }
+}
+
+#[test]
+fn instantiations_are_cached_separately() {
+    instantiations(VMConfig::default());
+}
+
+#[test]
+fn instantiations_beyond_cache_limit() {
+    for max_cached_instantiations in [0, 1] {
+        instantiations(VMConfig {
+            max_cached_instantiations,
+            ..VMConfig::default()
+        });
+    }
+}
+
+#[test]
+fn resources_are_loaded_with_cached_layouts() {
+    let (mut storage, module_id) = setup();
+    let vm = MoveVM::new(vec![]).unwrap();
+    let store = Identifier::new("store").unwrap();
+    let load = Identifier::new("load").unwrap();
+
+    // publish and load the resources twice, so that the second round uses the cached layouts
+    for _ in 0..2 {
+        let mut sess = vm.new_session(&storage);
+        for (ty, value) in values() {
+            sess.execute_function_bypass_visibility(
+                &module_id,
+                &store,
+                vec![ty],
+                vec![
+                    MoveValue::Signer(TEST_ADDR).simple_serialize().unwrap(),
+                    value.simple_serialize().unwrap(),
+                ],
+                &mut UnmeteredGasMeter,
+            )
+            .unwrap();
+        }
+        let (changeset, _) = sess.finish().unwrap();
+        storage.apply(changeset).unwrap();
+
+        // the resources are deserialized from storage in a new session
+        let mut sess = vm.new_session(&storage);
+        for (ty, value) in values() {
+            let results = sess
+                .execute_function_bypass_visibility(
+                    &module_id,
+                    &load,
+                    vec![ty],
+                    vec![MoveValue::Address(TEST_ADDR).simple_serialize().unwrap()],
+                    &mut UnmeteredGasMeter,
+                )
+                .unwrap()
+                .return_values;
+            assert_eq!(results.len(), 1);
+            assert_eq!(results[0].0, expected(&value));
+        }
+        let (changeset, _) = sess.finish().unwrap();
+        storage.apply(changeset).unwrap();
+    }
+}
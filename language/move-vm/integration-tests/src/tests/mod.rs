@@ -4,9 +4,17 @@
 
 mod bad_entry_point_tests;
 mod bad_storage_tests;
+mod entry_arg_tests;
 mod exec_func_effects_tests;
 mod function_arg_tests;
+mod instruction_cache_tests;
 mod loader_tests;
+mod module_cache_tests;
 mod mutated_accounts_tests;
 mod nested_loop_tests;
+mod publish_gas_tests;
 mod return_value_tests;
+mod statistics_tests;
+mod upgrade_policy_tests;
+mod vector_gas_tests;
+mod vm_config_tests;
@@ -0,0 +1,192 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::compiler::{as_module, compile_units};
+use move_core_types::{
+    account_address::AccountAddress,
+    identifier::Identifier,
+    language_storage::ModuleId,
+    value::{MoveTypeLayout, MoveValue},
+};
+use move_vm_runtime::{
+    config::VMConfig, module_cache::SharedModuleCache, move_vm::MoveVM,
+    session::SerializedReturnValues,
+};
+use move_vm_test_utils::InMemoryStorage;
+use move_vm_types::gas::UnmeteredGasMeter;
+use std::collections::BTreeMap;
+
+const TEST_ADDR: AccountAddress = AccountAddress::new([42; AccountAddress::LENGTH]);
+
+// `B` depends on `A`, `C` stands alone.
+fn compile(a_value: u64) -> BTreeMap<String, Vec<u8>> {
+    let code = format!(
+        r#"
+        module 0x{0}::A {{
+            public fun value(): u64 {{ {1} }}
+        }}
+
+        module 0x{0}::B {{
+            use 0x{0}::A;
+            public fun value(): u64 {{ A::value() + 10 }}
+        }}
+
+        module 0x{0}::C {{
+            public fun value(): u64 {{ 100 }}
+        }}
+    "#,
+        TEST_ADDR, a_value
+    );
+    compile_units(&code)
+        .unwrap()
+        .into_iter()
+        .map(|unit| {
+            let module = as_module(unit);
+            let mut blob = vec![];
+            module.serialize(&mut blob).unwrap();
+            (module.self_id().name().to_string(), blob)
+        })
+        .collect()
+}
+
+fn setup_storage() -> (InMemoryStorage, BTreeMap<String, Vec<u8>>) {
+    let modules = compile(1);
+    let mut storage = InMemoryStorage::new();
+    for (name, blob) in &modules {
+        storage.publish_or_overwrite_module(module_id(name), blob.clone());
+    }
+    (storage, modules)
+}
+
+fn module_id(name: &str) -> ModuleId {
+    ModuleId::new(TEST_ADDR, Identifier::new(name).unwrap())
+}
+
+fn call_value(vm: &MoveVM, storage: &InMemoryStorage, module: &str) -> u64 {
+    let mut sess = vm.new_session(storage);
+    let SerializedReturnValues { return_values, .. } = sess
+        .execute_function_bypass_visibility(
+            &module_id(module),
+            &Identifier::new("value").unwrap(),
+            vec![],
+            Vec::<Vec<u8>>::new(),
+            &mut UnmeteredGasMeter,
+        )
+        .unwrap();
+    match MoveValue::simple_deserialize(&return_values[0].0, &MoveTypeLayout::U64).unwrap() {
+        MoveValue::U64(value) => value,
+        value => panic!("unexpected return value {:?}", value),
+    }
+}
+
+#[test]
+fn republishing_evicts_module_and_dependents() {
+    let (mut storage, _) = setup_storage();
+    let vm = MoveVM::new(vec![]).unwrap();
+    assert_eq!(call_value(&vm, &storage, "B"), 11);
+    assert_eq!(call_value(&vm, &storage, "C"), 100);
+    assert_eq!(vm.cache_metrics().cached_modules, 3);
+
+    // republish `A`, the cache is only updated once flushed
+    let mut sess = vm.new_session(&storage);
+    sess.publish_module(compile(2)["A"].clone(), TEST_ADDR, &mut UnmeteredGasMeter)
+        .unwrap();
+    let (changeset, _) = sess.finish().unwrap();
+    storage.apply(changeset).unwrap();
+    assert_eq!(call_value(&vm, &storage, "B"), 11);
+
+    vm.flush_loader_cache_if_invalidated();
+    let metrics = vm.cache_metrics();
+    assert_eq!(metrics.cached_modules, 1);
+    assert_eq!(metrics.module_evictions, 2);
+
+    assert_eq!(call_value(&vm, &storage, "B"), 12);
+    let hits = vm.cache_metrics().module_hits;
+    assert_eq!(call_value(&vm, &storage, "C"), 100);
+    assert_eq!(vm.cache_metrics().module_hits, hits + 1);
+}
+
+#[test]
+fn explicit_module_invalidation() {
+    let (storage, _) = setup_storage();
+    let vm = MoveVM::new(vec![]).unwrap();
+    assert_eq!(call_value(&vm, &storage, "B"), 11);
+    assert_eq!(call_value(&vm, &storage, "C"), 100);
+
+    vm.mark_module_as_invalid(&module_id("C"));
+    vm.flush_loader_cache_if_invalidated();
+    let metrics = vm.cache_metrics();
+    assert_eq!(metrics.cached_modules, 2);
+    assert_eq!(metrics.module_evictions, 1);
+
+    let misses = metrics.module_misses;
+    assert_eq!(call_value(&vm, &storage, "C"), 100);
+    assert_eq!(vm.cache_metrics().module_misses, misses + 1);
+}
+
+#[test]
+fn capacity_evicts_least_recently_used() {
+    let (storage, modules) = setup_storage();
+    let vm = MoveVM::new_with_config(
+        vec![],
+        VMConfig {
+            module_cache_capacity: Some(modules["C"].len()),
+            ..VMConfig::default()
+        },
+    )
+    .unwrap();
+    assert_eq!(call_value(&vm, &storage, "B"), 11);
+    assert_eq!(call_value(&vm, &storage, "C"), 100);
+    let metrics = vm.cache_metrics();
+    assert_eq!(metrics.cached_modules, 3);
+    assert_eq!(
+        metrics.cached_module_bytes,
+        modules.values().map(|blob| blob.len() as u64).sum::<u64>()
+    );
+
+    // `A` is only used through `B`, so both go while the more recently used `C` stays
+    vm.flush_loader_cache_if_invalidated();
+    let metrics = vm.cache_metrics();
+    assert_eq!(metrics.cached_modules, 1);
+    assert_eq!(metrics.cached_module_bytes, modules["C"].len() as u64);
+    assert_eq!(metrics.module_evictions, 2);
+
+    assert_eq!(call_value(&vm, &storage, "C"), 100);
+    assert_eq!(vm.cache_metrics().module_hits, metrics.module_hits + 1);
+    assert_eq!(call_value(&vm, &storage, "B"), 11);
+    assert_eq!(vm.cache_metrics().module_misses, metrics.module_misses + 2);
+}
+
+#[test]
+fn shared_cache_skips_verification() {
+    let (storage, _) = setup_storage();
+    let shared_cache = SharedModuleCache::new(None);
+
+    let vm1 =
+        MoveVM::new_with_shared_cache(vec![], VMConfig::default(), shared_cache.clone()).unwrap();
+    assert_eq!(call_value(&vm1, &storage, "B"), 11);
+    let metrics = vm1.cache_metrics();
+    assert_eq!(metrics.shared_module_hits, 0);
+    assert_eq!(metrics.shared_module_misses, 2);
+    assert_eq!(shared_cache.len(), 2);
+
+    let vm2 =
+        MoveVM::new_with_shared_cache(vec![], VMConfig::default(), shared_cache.clone()).unwrap();
+    assert_eq!(call_value(&vm2, &storage, "B"), 11);
+    let metrics = vm2.cache_metrics();
+    assert_eq!(metrics.shared_module_hits, 2);
+    assert_eq!(metrics.shared_module_misses, 0);
+    assert_eq!(metrics.module_misses, 2);
+}
+
+#[test]
+fn shared_cache_capacity() {
+    let (storage, modules) = setup_storage();
+    let shared_cache = SharedModuleCache::new(Some(modules["C"].len()));
+    let vm =
+        MoveVM::new_with_shared_cache(vec![], VMConfig::default(), shared_cache.clone()).unwrap();
+    assert_eq!(call_value(&vm, &storage, "B"), 11);
+    assert_eq!(call_value(&vm, &storage, "C"), 100);
+    assert_eq!(shared_cache.len(), 1);
+    assert_eq!(shared_cache.size(), modules["C"].len());
+}
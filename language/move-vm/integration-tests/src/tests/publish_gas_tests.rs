@@ -0,0 +1,334 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::compiler::compile_modules;
+use move_binary_format::{
+    errors::{PartialVMError, PartialVMResult},
+    CompiledModule,
+};
+use move_core_types::{
+    account_address::AccountAddress,
+    gas_algebra::{InternalGas, NumArgs, NumBytes, NumInstructions},
+    identifier::Identifier,
+    language_storage::ModuleId,
+    vm_status::StatusCode,
+};
+use move_vm_runtime::move_vm::MoveVM;
+use move_vm_test_utils::InMemoryStorage;
+use move_vm_types::{
+    gas::{GasMeter, SimpleInstruction, UnmeteredGasMeter},
+    views::{TypeView, ValueView},
+};
+
+const TEST_ADDR: AccountAddress = AccountAddress::new([42; AccountAddress::LENGTH]);
+
+const CODE: &str = r#"
+    module 0x{ADDR}::A {
+        public fun f(x: u64): u64 { x + 1 }
+    }
+
+    module 0x{ADDR}::B {
+        use 0x{ADDR}::A;
+
+        public fun g(): u64 { A::f(1) }
+    }
+
+    module 0x{ADDR}::C {
+        use 0x{ADDR}::A;
+        use 0x{ADDR}::B;
+
+        public fun h(): u64 { A::f(B::g()) }
+    }
+"#;
+
+// A gas meter recording the publishing charges, and failing once a number of charges is reached.
+#[derive(Default)]
+struct PublishGasMeter {
+    bytes: u64,
+    dependencies: Vec<ModuleId>,
+    verified: Vec<(ModuleId, u64)>,
+    charges_left: Option<usize>,
+}
+
+impl PublishGasMeter {
+    fn charge(&mut self) -> PartialVMResult<()> {
+        match &mut self.charges_left {
+            Some(0) => Err(PartialVMError::new(StatusCode::OUT_OF_GAS)),
+            Some(n) => {
+                *n -= 1;
+                Ok(())
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+impl GasMeter for PublishGasMeter {
+    fn charge_simple_instr(&mut self, _instr: SimpleInstruction) -> PartialVMResult<()> {
+        Ok(())
+    }
+
+    fn charge_call(
+        &mut self,
+        _module_id: &ModuleId,
+        _func_name: &str,
+        _args: impl ExactSizeIterator<Item = impl ValueView>,
+    ) -> PartialVMResult<()> {
+        Ok(())
+    }
+
+    fn charge_call_generic(
+        &mut self,
+        _module_id: &ModuleId,
+        _func_name: &str,
+        _ty_args: impl ExactSizeIterator<Item = impl TypeView>,
+        _args: impl ExactSizeIterator<Item = impl ValueView>,
+    ) -> PartialVMResult<()> {
+        Ok(())
+    }
+
+    fn charge_ld_const(&mut self, _size: NumBytes) -> PartialVMResult<()> {
+        Ok(())
+    }
+
+    fn charge_copy_loc(&mut self, _val: impl ValueView) -> PartialVMResult<()> {
+        Ok(())
+    }
+
+    fn charge_move_loc(&mut self, _val: impl ValueView) -> PartialVMResult<()> {
+        Ok(())
+    }
+
+    fn charge_store_loc(&mut self, _val: impl ValueView) -> PartialVMResult<()> {
+        Ok(())
+    }
+
+    fn charge_pack(
+        &mut self,
+        _is_generic: bool,
+        _args: impl ExactSizeIterator<Item = impl ValueView>,
+    ) -> PartialVMResult<()> {
+        Ok(())
+    }
+
+    fn charge_unpack(
+        &mut self,
+        _is_generic: bool,
+        _args: impl ExactSizeIterator<Item = impl ValueView>,
+    ) -> PartialVMResult<()> {
+        Ok(())
+    }
+
+    fn charge_read_ref(&mut self, _val: impl ValueView) -> PartialVMResult<()> {
+        Ok(())
+    }
+
+    fn charge_write_ref(&mut self, _val: impl ValueView) -> PartialVMResult<()> {
+        Ok(())
+    }
+
+    fn charge_eq(&mut self, _lhs: impl ValueView, _rhs: impl ValueView) -> PartialVMResult<()> {
+        Ok(())
+    }
+
+    fn charge_neq(&mut self, _lhs: impl ValueView, _rhs: impl ValueView) -> PartialVMResult<()> {
+        Ok(())
+    }
+
+    fn charge_borrow_global(
+        &mut self,
+        _is_mut: bool,
+        _is_generic: bool,
+        _ty: impl TypeView,
+        _is_success: bool,
+    ) -> PartialVMResult<()> {
+        Ok(())
+    }
+
+    fn charge_exists(
+        &mut self,
+        _is_generic: bool,
+        _ty: impl TypeView,
+        _exists: bool,
+    ) -> PartialVMResult<()> {
+        Ok(())
+    }
+
+    fn charge_move_from(
+        &mut self,
+        _is_generic: bool,
+        _ty: impl TypeView,
+        _val: Option<impl ValueView>,
+    ) -> PartialVMResult<()> {
+        Ok(())
+    }
+
+    fn charge_move_to(
+        &mut self,
+        _is_generic: bool,
+        _ty: impl TypeView,
+        _val: impl ValueView,
+        _is_success: bool,
+    ) -> PartialVMResult<()> {
+        Ok(())
+    }
+
+    fn charge_vec_pack<'a>(
+        &mut self,
+        _ty: impl TypeView + 'a,
+        _args: impl ExactSizeIterator<Item = impl ValueView>,
+    ) -> PartialVMResult<()> {
+        Ok(())
+    }
+
+    fn charge_vec_len(&mut self, _ty: impl TypeView) -> PartialVMResult<()> {
+        Ok(())
+    }
+
+    fn charge_vec_borrow(
+        &mut self,
+        _is_mut: bool,
+        _ty: impl TypeView,
+        _is_success: bool,
+    ) -> PartialVMResult<()> {
+        Ok(())
+    }
+
+    fn charge_vec_push_back(
+        &mut self,
+        _ty: impl TypeView,
+        _val: impl ValueView,
+    ) -> PartialVMResult<()> {
+        Ok(())
+    }
+
+    fn charge_vec_pop_back(
+        &mut self,
+        _ty: impl TypeView,
+        _val: Option<impl ValueView>,
+    ) -> PartialVMResult<()> {
+        Ok(())
+    }
+
+    fn charge_vec_unpack(
+        &mut self,
+        _ty: impl TypeView,
+        _expect_num_elements: NumArgs,
+    ) -> PartialVMResult<()> {
+        Ok(())
+    }
+
+    fn charge_vec_swap(&mut self, _ty: impl TypeView) -> PartialVMResult<()> {
+        Ok(())
+    }
+
+    fn charge_load_resource(&mut self, _loaded: Option<NumBytes>) -> PartialVMResult<()> {
+        Ok(())
+    }
+
+    fn charge_native_function(&mut self, _amount: InternalGas) -> PartialVMResult<()> {
+        Ok(())
+    }
+
+    fn charge_publish_module_bytes(&mut self, size: NumBytes) -> PartialVMResult<()> {
+        self.charge()?;
+        self.bytes += u64::from(size);
+        Ok(())
+    }
+
+    fn charge_publish_dependency(&mut self, module_id: &ModuleId) -> PartialVMResult<()> {
+        self.charge()?;
+        self.dependencies.push(module_id.clone());
+        Ok(())
+    }
+
+    fn charge_verify_module(
+        &mut self,
+        module_id: &ModuleId,
+        num_instructions: NumInstructions,
+    ) -> PartialVMResult<()> {
+        self.charge()?;
+        self.verified
+            .push((module_id.clone(), u64::from(num_instructions)));
+        Ok(())
+    }
+}
+
+fn module_id(name: &str) -> ModuleId {
+    ModuleId::new(TEST_ADDR, Identifier::new(name).unwrap())
+}
+
+fn compile() -> Vec<CompiledModule> {
+    compile_modules(&CODE.replace("{ADDR}", &format!("{}", TEST_ADDR))).unwrap()
+}
+
+fn serialize(modules: &[CompiledModule]) -> Vec<Vec<u8>> {
+    modules
+        .iter()
+        .map(|module| {
+            let mut blob = vec![];
+            module.serialize(&mut blob).unwrap();
+            blob
+        })
+        .collect()
+}
+
+#[test]
+fn publish_charges_bytes_dependencies_and_verification() {
+    let modules = compile();
+    let blobs = serialize(&modules);
+    let vm = MoveVM::new(vec![]).unwrap();
+    let mut storage = InMemoryStorage::new();
+
+    // publish A on its own, then B and C in a bundle depending on it
+    let mut sess = vm.new_session(&storage);
+    sess.publish_module(blobs[0].clone(), TEST_ADDR, &mut UnmeteredGasMeter)
+        .unwrap();
+    let (changeset, _) = sess.finish().unwrap();
+    storage.apply(changeset).unwrap();
+
+    let mut gas_meter = PublishGasMeter::default();
+    let mut sess = vm.new_session(&storage);
+    sess.publish_module_bundle(blobs[1..].to_vec(), TEST_ADDR, &mut gas_meter)
+        .unwrap();
+
+    assert_eq!(gas_meter.bytes, (blobs[1].len() + blobs[2].len()) as u64);
+    // B is published in the same bundle as C, and A is charged once
+    assert_eq!(gas_meter.dependencies, vec![module_id("A")]);
+    let verified: Vec<_> = gas_meter
+        .verified
+        .iter()
+        .map(|(id, _)| id.clone())
+        .collect();
+    assert_eq!(verified, vec![module_id("B"), module_id("C")]);
+    assert!(gas_meter.verified.iter().all(|(_, n)| *n > 0));
+}
+
+#[test]
+fn publish_runs_out_of_gas() {
+    let blobs = serialize(&compile());
+    let vm = MoveVM::new(vec![]).unwrap();
+    let storage = InMemoryStorage::new();
+
+    // the bundle needs 3 byte charges and 3 verification charges, without any dependency
+    for charges in 0..6 {
+        let mut gas_meter = PublishGasMeter {
+            charges_left: Some(charges),
+            ..Default::default()
+        };
+        let mut sess = vm.new_session(&storage);
+        let err = sess
+            .publish_module_bundle(blobs.clone(), TEST_ADDR, &mut gas_meter)
+            .unwrap_err();
+        assert_eq!(err.major_status(), StatusCode::OUT_OF_GAS);
+    }
+
+    let mut gas_meter = PublishGasMeter {
+        charges_left: Some(6),
+        ..Default::default()
+    };
+    let mut sess = vm.new_session(&storage);
+    sess.publish_module_bundle(blobs, TEST_ADDR, &mut gas_meter)
+        .unwrap();
+    assert!(gas_meter.dependencies.is_empty());
+}
@@ -7,22 +7,19 @@ use move_binary_format::errors::VMResult;
 use move_core_types::{
     account_address::AccountAddress,
     identifier::Identifier,
-    language_storage::{ModuleId, TypeTag},
-    value::{MoveTypeLayout, MoveValue},
+    language_storage::{ModuleId, StructTag, TypeTag},
+    value::{MoveStruct, MoveTypeLayout, MoveValue},
+};
+use move_vm_runtime::{
+    move_vm::MoveVM,
+    session::{AnnotatedReturnValues, SerializedReturnValues},
 };
-use move_vm_runtime::{move_vm::MoveVM, session::SerializedReturnValues};
 use move_vm_test_utils::InMemoryStorage;
 use move_vm_types::gas::UnmeteredGasMeter;
 
 const TEST_ADDR: AccountAddress = AccountAddress::new([42; AccountAddress::LENGTH]);
 
-fn run(
-    structs: &[&str],
-    fun_sig: &str,
-    fun_body: &str,
-    ty_args: Vec<TypeTag>,
-    args: Vec<MoveValue>,
-) -> VMResult<Vec<Vec<u8>>> {
+fn publish(structs: &[&str], fun_sig: &str, fun_body: &str) -> (InMemoryStorage, ModuleId) {
     let structs = structs.to_vec().join("\n");
 
     let code = format!(
@@ -46,7 +43,17 @@ fn run(
     let mut storage = InMemoryStorage::new();
     let module_id = ModuleId::new(TEST_ADDR, Identifier::new("M").unwrap());
     storage.publish_or_overwrite_module(module_id.clone(), blob);
+    (storage, module_id)
+}
 
+fn run(
+    structs: &[&str],
+    fun_sig: &str,
+    fun_body: &str,
+    ty_args: Vec<TypeTag>,
+    args: Vec<MoveValue>,
+) -> VMResult<Vec<Vec<u8>>> {
+    let (storage, module_id) = publish(structs, fun_sig, fun_body);
     let vm = MoveVM::new(vec![]).unwrap();
     let mut sess = vm.new_session(&storage);
 
@@ -123,3 +130,101 @@ fn return_signer_ref() {
         &[MoveTypeLayout::Signer],
     )
 }
+
+fn run_annotated(
+    structs: &[&str],
+    fun_sig: &str,
+    fun_body: &str,
+    ty_args: Vec<TypeTag>,
+    args: Vec<MoveValue>,
+) -> AnnotatedReturnValues {
+    let (storage, module_id) = publish(structs, fun_sig, fun_body);
+    let vm = MoveVM::new(vec![]).unwrap();
+    let mut sess = vm.new_session(&storage);
+    let args: Vec<_> = args
+        .into_iter()
+        .map(|val| val.simple_serialize().unwrap())
+        .collect();
+    sess.execute_function_bypass_visibility_annotated(
+        &module_id,
+        &Identifier::new("foo").unwrap(),
+        ty_args,
+        args,
+        &mut UnmeteredGasMeter,
+    )
+    .unwrap()
+}
+
+fn struct_tag(name: &str, type_params: Vec<TypeTag>) -> StructTag {
+    StructTag {
+        address: TEST_ADDR,
+        module: Identifier::new("M").unwrap(),
+        name: Identifier::new(name).unwrap(),
+        type_params,
+    }
+}
+
+fn annotated_struct(tag: &StructTag, fields: Vec<(&str, MoveValue)>) -> MoveValue {
+    MoveValue::Struct(MoveStruct::WithTypes {
+        type_: tag.clone(),
+        fields: fields
+            .into_iter()
+            .map(|(name, value)| (Identifier::new(name).unwrap(), value))
+            .collect(),
+    })
+}
+
+#[test]
+fn return_annotated_generic_struct() {
+    let AnnotatedReturnValues {
+        mutable_reference_outputs,
+        return_values,
+    } = run_annotated(
+        &["struct Foo<T> { x: T, y: bool }"],
+        "<T>(x: T): (Foo<T>, u64)",
+        "(Foo { x, y: true }, 7)",
+        vec![TypeTag::U64],
+        vec![MoveValue::U64(42)],
+    );
+    assert!(mutable_reference_outputs.is_empty());
+
+    let tag = struct_tag("Foo", vec![TypeTag::U64]);
+    assert_eq!(
+        return_values,
+        vec![
+            (
+                annotated_struct(
+                    &tag,
+                    vec![("x", MoveValue::U64(42)), ("y", MoveValue::Bool(true))]
+                ),
+                TypeTag::Struct(tag.clone()),
+            ),
+            (MoveValue::U64(7), TypeTag::U64),
+        ]
+    );
+}
+
+#[test]
+fn return_annotated_mutable_reference_output() {
+    let AnnotatedReturnValues {
+        mutable_reference_outputs,
+        return_values,
+    } = run_annotated(
+        &["struct Counter has drop { value: u64 }"],
+        "(c: &mut Counter, v: &u64): &Counter",
+        "c.value = c.value + *v; c",
+        vec![],
+        vec![
+            MoveValue::Struct(MoveStruct::Runtime(vec![MoveValue::U64(1)])),
+            MoveValue::U64(2),
+        ],
+    );
+
+    let tag = struct_tag("Counter", vec![]);
+    let expected = annotated_struct(&tag, vec![("value", MoveValue::U64(3))]);
+    assert_eq!(
+        mutable_reference_outputs,
+        vec![(0, expected.clone(), TypeTag::Struct(tag.clone()))]
+    );
+    assert_eq!(return_values, vec![(expected, TypeTag::Struct(tag))]);
+}
@@ -0,0 +1,98 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::compiler::{as_module, compile_units};
+use move_binary_format::file_format_common::Opcodes;
+use move_core_types::{
+    account_address::AccountAddress,
+    identifier::Identifier,
+    language_storage::{ModuleId, StructTag, TypeTag},
+    value::{serialize_values, MoveValue},
+};
+use move_vm_runtime::{config::VMConfig, move_vm::MoveVM, statistics::ExecutionStatistics};
+use move_vm_test_utils::InMemoryStorage;
+use move_vm_types::gas::UnmeteredGasMeter;
+
+const TEST_ADDR: AccountAddress = AccountAddress::new([42; AccountAddress::LENGTH]);
+
+const CODE: &str = r#"
+    module 0x{ADDR}::M {
+        struct R has key { v: u64 }
+
+        fun depth(n: u64): u64 {
+            if (n == 0) 0 else 1 + depth(n - 1)
+        }
+
+        public fun run(account: &signer, n: u64) acquires R {
+            if (!exists<R>(@0x{ADDR})) move_to(account, R { v: 0 });
+            borrow_global_mut<R>(@0x{ADDR}).v = depth(n);
+            assert!(borrow_global<R>(@0x{ADDR}).v == n, 0);
+        }
+    }
+"#;
+
+fn run(vm_config: VMConfig) -> ExecutionStatistics {
+    let code = CODE.replace("{ADDR}", &format!("{}", TEST_ADDR));
+    let mut units = compile_units(&code).unwrap();
+    let mut blob = vec![];
+    as_module(units.pop().unwrap())
+        .serialize(&mut blob)
+        .unwrap();
+    let mut storage = InMemoryStorage::new();
+    let module_id = ModuleId::new(TEST_ADDR, Identifier::new("M").unwrap());
+    storage.publish_or_overwrite_module(module_id.clone(), blob);
+
+    let vm = MoveVM::new_with_config(vec![], vm_config).unwrap();
+    let mut sess = vm.new_session(&storage);
+    sess.execute_function_bypass_visibility(
+        &module_id,
+        &Identifier::new("run").unwrap(),
+        vec![],
+        serialize_values(&vec![MoveValue::Signer(TEST_ADDR), MoveValue::U64(3)]),
+        &mut UnmeteredGasMeter,
+    )
+    .unwrap();
+    let (_, _, statistics) = sess.finish_with_statistics().unwrap();
+    statistics
+}
+
+#[test]
+fn statistics_are_collected() {
+    let statistics = run(VMConfig {
+        collect_statistics: true,
+        ..Default::default()
+    });
+
+    let function = |name: &str| format!("0x{}::M::{}", TEST_ADDR, name);
+    assert_eq!(
+        statistics.function_calls.into_iter().collect::<Vec<_>>(),
+        vec![(function("depth"), 4), (function("run"), 1)]
+    );
+    assert_eq!(statistics.instructions[&Opcodes::CALL], 4);
+    assert_eq!(statistics.instructions[&Opcodes::RET], 5);
+    // `run` and the 4 frames of `depth`
+    assert_eq!(statistics.max_call_depth, 5);
+    assert!(statistics.max_operand_stack_size >= 4);
+
+    let r = TypeTag::Struct(StructTag {
+        address: TEST_ADDR,
+        module: Identifier::new("M").unwrap(),
+        name: Identifier::new("R").unwrap(),
+        type_params: vec![],
+    });
+    // `exists` and `borrow_global`
+    assert_eq!(
+        statistics.global_reads.into_iter().collect::<Vec<_>>(),
+        vec![(r.clone(), 2)]
+    );
+    // `move_to` and `borrow_global_mut`
+    assert_eq!(
+        statistics.global_writes.into_iter().collect::<Vec<_>>(),
+        vec![(r, 2)]
+    );
+}
+
+#[test]
+fn statistics_are_not_collected_by_default() {
+    assert_eq!(run(VMConfig::default()), ExecutionStatistics::default());
+}
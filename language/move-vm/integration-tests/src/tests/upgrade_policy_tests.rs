@@ -0,0 +1,164 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::compiler::{as_module, compile_units};
+use move_binary_format::upgrade_policy::{UpgradePolicy, UPGRADE_POLICY_METADATA_KEY};
+use move_core_types::{account_address::AccountAddress, metadata::Metadata, vm_status::StatusCode};
+use move_vm_runtime::move_vm::MoveVM;
+use move_vm_test_utils::InMemoryStorage;
+use move_vm_types::gas::UnmeteredGasMeter;
+
+const TEST_ADDR: AccountAddress = AccountAddress::new([42; AccountAddress::LENGTH]);
+
+const V1: &str = r#"
+    module 0x{ADDR}::M {
+        struct S has key { f: u64 }
+        public fun f(x: u64): u64 { x }
+    }
+"#;
+
+// Adds a function.
+const V2_ADDITIVE: &str = r#"
+    module 0x{ADDR}::M {
+        struct S has key { f: u64 }
+        public fun f(x: u64): u64 { x }
+        public fun g(): u64 { 0 }
+    }
+"#;
+
+// Adds an ability to a struct, and a function.
+const V2_COMPATIBLE: &str = r#"
+    module 0x{ADDR}::M {
+        struct S has key, store { f: u64 }
+        public fun f(x: u64): u64 { x }
+        public fun g(): u64 { 0 }
+    }
+"#;
+
+// Changes the layout of a struct.
+const V2_INCOMPATIBLE: &str = r#"
+    module 0x{ADDR}::M {
+        struct S has key { f: u64, g: bool }
+        public fun f(x: u64): u64 { x }
+    }
+"#;
+
+fn compile(code: &str, metadata: Vec<Metadata>) -> Vec<u8> {
+    let code = code.replace("{ADDR}", &format!("{}", TEST_ADDR));
+    let mut units = compile_units(&code).unwrap();
+    let mut module = as_module(units.pop().unwrap());
+    module.metadata = metadata;
+    let mut blob = vec![];
+    module.serialize(&mut blob).unwrap();
+    blob
+}
+
+fn with_policy(code: &str, policy: Option<UpgradePolicy>) -> Vec<u8> {
+    compile(code, policy.into_iter().map(|p| p.to_metadata()).collect())
+}
+
+// Publish `old` then `new`, returning the status of the second publishing.
+fn republish(old: Vec<u8>, new: Vec<u8>) -> Result<(), StatusCode> {
+    let vm = MoveVM::new(vec![]).unwrap();
+    let mut storage = InMemoryStorage::new();
+
+    let mut sess = vm.new_session(&storage);
+    sess.publish_module(old, TEST_ADDR, &mut UnmeteredGasMeter)
+        .unwrap();
+    let (changeset, _) = sess.finish().unwrap();
+    storage.apply(changeset).unwrap();
+
+    let mut sess = vm.new_session(&storage);
+    sess.publish_module(new, TEST_ADDR, &mut UnmeteredGasMeter)
+        .map_err(|err| err.major_status())
+}
+
+#[test]
+fn default_policy_is_compatible() {
+    assert_eq!(
+        republish(with_policy(V1, None), with_policy(V2_COMPATIBLE, None)),
+        Ok(())
+    );
+    assert_eq!(
+        republish(with_policy(V1, None), with_policy(V2_INCOMPATIBLE, None)),
+        Err(StatusCode::BACKWARD_INCOMPATIBLE_MODULE_UPDATE)
+    );
+}
+
+#[test]
+fn arbitrary_policy() {
+    let policy = Some(UpgradePolicy::Arbitrary);
+    assert_eq!(
+        republish(
+            with_policy(V1, policy),
+            with_policy(V2_INCOMPATIBLE, policy)
+        ),
+        Ok(())
+    );
+}
+
+#[test]
+fn additive_policy() {
+    let policy = Some(UpgradePolicy::Additive);
+    assert_eq!(
+        republish(with_policy(V1, policy), with_policy(V2_ADDITIVE, policy)),
+        Ok(())
+    );
+    assert_eq!(
+        republish(with_policy(V1, policy), with_policy(V2_COMPATIBLE, policy)),
+        Err(StatusCode::NON_ADDITIVE_MODULE_UPDATE)
+    );
+}
+
+#[test]
+fn immutable_policy() {
+    let policy = Some(UpgradePolicy::Immutable);
+    assert_eq!(
+        republish(with_policy(V1, policy), with_policy(V1, policy)),
+        Err(StatusCode::IMMUTABLE_MODULE_UPDATE)
+    );
+}
+
+#[test]
+fn policy_cannot_be_weakened() {
+    assert_eq!(
+        republish(
+            with_policy(V1, None),
+            with_policy(V2_COMPATIBLE, Some(UpgradePolicy::Arbitrary))
+        ),
+        Err(StatusCode::UPGRADE_POLICY_WEAKENED)
+    );
+    assert_eq!(
+        republish(
+            with_policy(V1, Some(UpgradePolicy::Additive)),
+            with_policy(V2_ADDITIVE, Some(UpgradePolicy::Compatible))
+        ),
+        Err(StatusCode::UPGRADE_POLICY_WEAKENED)
+    );
+    // the policy is checked against the one of the published module
+    assert_eq!(
+        republish(
+            with_policy(V1, None),
+            with_policy(V2_COMPATIBLE, Some(UpgradePolicy::Immutable))
+        ),
+        Ok(())
+    );
+}
+
+#[test]
+fn malformed_policy() {
+    let vm = MoveVM::new(vec![]).unwrap();
+    let storage = InMemoryStorage::new();
+    let mut sess = vm.new_session(&storage);
+    let blob = compile(
+        V1,
+        vec![Metadata {
+            key: UPGRADE_POLICY_METADATA_KEY.to_vec(),
+            value: vec![42],
+        }],
+    );
+    let err = sess
+        .publish_module(blob, TEST_ADDR, &mut UnmeteredGasMeter)
+        .unwrap_err();
+    assert_eq!(err.major_status(), StatusCode::MALFORMED_UPGRADE_POLICY);
+}
@@ -0,0 +1,91 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::compiler::{as_module, compile_units_with_stdlib};
+use move_core_types::{
+    account_address::AccountAddress,
+    identifier::Identifier,
+    language_storage::{ModuleId, TypeTag},
+    value::MoveValue,
+};
+use move_vm_runtime::move_vm::MoveVM;
+use move_vm_test_utils::{
+    gas_schedule::{zero_cost_schedule, Gas, GasStatus},
+    InMemoryStorage,
+};
+
+const TEST_ADDR: AccountAddress = AccountAddress::new([42; AccountAddress::LENGTH]);
+
+const CODE: &str = r#"
+    module 0x{ADDR}::M {
+        use std::vector;
+
+        fun nested(n: u64, x: u64): vector<u64> {
+            let v = vector::empty();
+            while (vector::length(&v) < n) vector::push_back(&mut v, x);
+            v
+        }
+
+        public fun sort_nested(n: u64) {
+            let v = vector::singleton(nested(n, 2));
+            vector::push_back(&mut v, nested(n, 1));
+            vector::sort(&mut v);
+        }
+
+        public fun search_nested(n: u64) {
+            let v = vector::singleton(nested(n, 1));
+            let (found, _) = vector::binary_search(&v, &nested(n, 1));
+            assert!(found, 0);
+        }
+    }
+"#;
+
+// Run `name` with `n` as argument, with all the costs set to zero but the cost of the size of
+// the values compared by sorting and searching natives, and return the gas used.
+fn gas_used(name: &str, n: u64) -> u64 {
+    let code = CODE.replace("{ADDR}", &format!("{}", TEST_ADDR));
+    let mut storage = InMemoryStorage::new();
+    for unit in compile_units_with_stdlib(&code).unwrap() {
+        let module = as_module(unit);
+        let mut blob = vec![];
+        module.serialize(&mut blob).unwrap();
+        storage.publish_or_overwrite_module(module.self_id(), blob);
+    }
+
+    let mut gas_params = move_stdlib::natives::GasParameters::zeros();
+    // One unit of gas per unit of abstract memory.
+    gas_params.vector.sort.legacy_per_abstract_memory_unit = 1000.into();
+    gas_params
+        .vector
+        .binary_search
+        .legacy_per_abstract_memory_unit = 1000.into();
+    let vm = MoveVM::new(move_stdlib::natives::all_natives(
+        AccountAddress::from_hex_literal("0x1").unwrap(),
+        gas_params,
+    ))
+    .unwrap();
+
+    let cost_table = zero_cost_schedule();
+    let budget = Gas::new(1_000_000);
+    let mut gas_status = GasStatus::new(&cost_table, budget);
+    let mut sess = vm.new_session(&storage);
+    sess.execute_function_bypass_visibility(
+        &ModuleId::new(TEST_ADDR, Identifier::new("M").unwrap()),
+        &Identifier::new(name).unwrap(),
+        Vec::<TypeTag>::new(),
+        vec![MoveValue::U64(n).simple_serialize().unwrap()],
+        &mut gas_status,
+    )
+    .unwrap();
+    u64::from(budget) - u64::from(gas_status.remaining_gas())
+}
+
+#[test]
+fn comparisons_charged_by_size() {
+    // Both sorting two elements and searching among one element compare two vectors of `n`
+    // u64s once, which have an abstract memory size of `2 * 8 * n`.
+    assert_eq!(gas_used("sort_nested", 1), 16);
+    assert_eq!(gas_used("sort_nested", 1000), 16_000);
+    assert_eq!(gas_used("search_nested", 1), 16);
+    assert_eq!(gas_used("search_nested", 1000), 16_000);
+}
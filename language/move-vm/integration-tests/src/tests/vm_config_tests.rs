@@ -0,0 +1,188 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::compiler::{as_module, compile_units};
+use move_binary_format::errors::VMResult;
+use move_core_types::{
+    account_address::AccountAddress,
+    identifier::Identifier,
+    language_storage::{ModuleId, TypeTag},
+    value::MoveValue,
+    vm_status::StatusCode,
+};
+use move_vm_runtime::{config::VMConfig, move_vm::MoveVM};
+use move_vm_test_utils::InMemoryStorage;
+use move_vm_types::gas::UnmeteredGasMeter;
+
+const TEST_ADDR: AccountAddress = AccountAddress::new([42; AccountAddress::LENGTH]);
+
+fn run(
+    vm_config: VMConfig,
+    fun_name: &str,
+    ty_args: Vec<TypeTag>,
+    args: Vec<MoveValue>,
+) -> VMResult<()> {
+    let code = format!(
+        r#"
+        module 0x{}::M {{
+            fun recurse(n: u64): u64 {{
+                if (n == 0) 0 else 1 + recurse(n - 1)
+            }}
+
+            fun recurse_50() {{
+                recurse(50);
+            }}
+
+            fun nested<T>(v: vector<vector<T>>): vector<vector<T>> {{
+                v
+            }}
+
+            fun take(_v: vector<u64>) {{}}
+        }}
+    "#,
+        TEST_ADDR
+    );
+
+    let mut units = compile_units(&code).unwrap();
+    let m = as_module(units.pop().unwrap());
+    let mut blob = vec![];
+    m.serialize(&mut blob).unwrap();
+
+    let mut storage = InMemoryStorage::new();
+    let module_id = ModuleId::new(TEST_ADDR, Identifier::new("M").unwrap());
+    storage.publish_or_overwrite_module(module_id.clone(), blob);
+
+    let vm = MoveVM::new_with_config(vec![], vm_config).unwrap();
+    let mut sess = vm.new_session(&storage);
+
+    let args: Vec<_> = args
+        .into_iter()
+        .map(|val| val.simple_serialize().unwrap())
+        .collect();
+
+    sess.execute_function_bypass_visibility(
+        &module_id,
+        &Identifier::new(fun_name).unwrap(),
+        ty_args,
+        args,
+        &mut UnmeteredGasMeter,
+    )?;
+    Ok(())
+}
+
+fn expect_status(
+    vm_config: VMConfig,
+    fun_name: &str,
+    ty_args: Vec<TypeTag>,
+    args: Vec<MoveValue>,
+    status: StatusCode,
+) {
+    assert_eq!(
+        run(vm_config, fun_name, ty_args, args)
+            .unwrap_err()
+            .major_status(),
+        status
+    );
+}
+
+#[test]
+fn default_config() {
+    run(VMConfig::default(), "recurse_50", vec![], vec![]).unwrap();
+    run(
+        VMConfig::default(),
+        "nested",
+        vec![TypeTag::U64],
+        vec![MoveValue::Vector(vec![])],
+    )
+    .unwrap();
+    run(
+        VMConfig::default(),
+        "take",
+        vec![],
+        vec![MoveValue::Vector(vec![MoveValue::U64(1); 100])],
+    )
+    .unwrap();
+}
+
+#[test]
+fn max_call_stack_size() {
+    let vm_config = VMConfig {
+        max_call_stack_size: 10,
+        ..VMConfig::default()
+    };
+    expect_status(
+        vm_config,
+        "recurse_50",
+        vec![],
+        vec![],
+        StatusCode::CALL_STACK_OVERFLOW,
+    );
+}
+
+#[test]
+fn max_value_stack_size() {
+    let vm_config = VMConfig {
+        max_value_stack_size: 10,
+        ..VMConfig::default()
+    };
+    expect_status(
+        vm_config,
+        "recurse_50",
+        vec![],
+        vec![],
+        StatusCode::EXECUTION_STACK_OVERFLOW,
+    );
+}
+
+#[test]
+fn max_type_depth() {
+    let vm_config = VMConfig {
+        max_type_depth: 1,
+        ..VMConfig::default()
+    };
+    expect_status(
+        vm_config,
+        "nested",
+        vec![TypeTag::U64],
+        vec![MoveValue::Vector(vec![])],
+        StatusCode::VM_MAX_TYPE_DEPTH_REACHED,
+    );
+}
+
+#[test]
+fn max_value_nest_depth() {
+    let vm_config = VMConfig {
+        max_value_nest_depth: 1,
+        ..VMConfig::default()
+    };
+    // Failing to compute the layout of an argument is reported as a bad parameter type.
+    expect_status(
+        vm_config,
+        "nested",
+        vec![TypeTag::U64],
+        vec![MoveValue::Vector(vec![])],
+        StatusCode::INVALID_PARAM_TYPE_FOR_DESERIALIZATION,
+    );
+}
+
+#[test]
+fn max_vector_len_on_deserialization() {
+    let vm_config = VMConfig {
+        max_vector_len_on_deserialization: Some(100),
+        ..VMConfig::default()
+    };
+    run(
+        vm_config.clone(),
+        "take",
+        vec![],
+        vec![MoveValue::Vector(vec![MoveValue::U64(1); 100])],
+    )
+    .unwrap();
+    expect_status(
+        vm_config,
+        "take",
+        vec![],
+        vec![MoveValue::Vector(vec![MoveValue::U64(1); 101])],
+        StatusCode::FAILED_TO_DESERIALIZE_ARGUMENT,
+    );
+}
@@ -0,0 +1,100 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use move_bytecode_verifier::VerifierConfig;
+use move_core_types::{
+    identifier::Identifier,
+    language_storage::{ModuleId, CORE_CODE_ADDRESS},
+};
+use move_vm_types::loaded_data::runtime_types::TYPE_DEPTH_MAX;
+
+pub const DEFAULT_MAX_VALUE_STACK_SIZE: usize = 1024;
+pub const DEFAULT_MAX_CALL_STACK_SIZE: usize = 1024;
+pub const DEFAULT_MAX_VALUE_NEST_DEPTH: usize = 128;
+pub const DEFAULT_MAX_CACHED_INSTANTIATIONS: usize = 64;
+
+/// Dynamic configuration of the Move VM.
+///
+/// The defaults reproduce the limits the VM has always enforced, so an embedder only needs to
+/// override the limits that matter for its threat model.
+#[derive(Debug, Clone)]
+pub struct VMConfig {
+    /// Configuration of the bytecode verifier run on every loaded module and script.
+    pub verifier: VerifierConfig,
+    /// Maximum number of values on the operand stack, shared by all frames.
+    pub max_value_stack_size: usize,
+    /// Maximum number of frames on the call stack.
+    pub max_call_stack_size: usize,
+    /// Maximum depth of a type produced by instantiating generic types and functions.
+    pub max_type_depth: usize,
+    /// Maximum nesting depth of a value whose layout is computed by the VM, e.g. for
+    /// (de)serialization of arguments, resources and events.
+    pub max_value_nest_depth: usize,
+    /// Maximum length of any vector deserialized from a transaction argument. `None` means the
+    /// length is only bounded by the size of the argument.
+    pub max_vector_len_on_deserialization: Option<usize>,
+    /// Maximum total size in bytes of the modules kept in the loader cache. The cache is trimmed,
+    /// least recently used modules first, when it is flushed with
+    /// `MoveVM::flush_loader_cache_if_invalidated`. `None` means the cache is unbounded.
+    pub module_cache_capacity: Option<usize>,
+    /// Maximum number of instantiations of a function whose resolved instruction operands are
+    /// cached. Once a function has that many cached instantiations, the operands of any other
+    /// instantiation are resolved again for every call.
+    pub max_cached_instantiations: usize,
+    /// The structs which entry functions accept as arguments, besides primitive types and
+    /// vectors.
+    pub struct_arg_constructors: Vec<StructArgConstructor>,
+    /// Whether sessions collect `ExecutionStatistics` of the code they execute.
+    pub collect_statistics: bool,
+}
+
+impl Default for VMConfig {
+    fn default() -> Self {
+        Self {
+            verifier: VerifierConfig::default(),
+            max_value_stack_size: DEFAULT_MAX_VALUE_STACK_SIZE,
+            max_call_stack_size: DEFAULT_MAX_CALL_STACK_SIZE,
+            max_type_depth: TYPE_DEPTH_MAX,
+            max_value_nest_depth: DEFAULT_MAX_VALUE_NEST_DEPTH,
+            max_vector_len_on_deserialization: None,
+            module_cache_capacity: None,
+            max_cached_instantiations: DEFAULT_MAX_CACHED_INSTANTIATIONS,
+            struct_arg_constructors: StructArgConstructor::std_constructors(),
+            collect_statistics: false,
+        }
+    }
+}
+
+/// A struct which entry functions accept as an argument.
+///
+/// Such an argument is serialized as the arguments of `constructor`, a function of the module
+/// declaring the struct with the same type parameters as the struct. The constructor is called
+/// to build the value, and aborts if the serialized arguments are not valid, e.g. if a string is
+/// not UTF-8.
+#[derive(Debug, Clone)]
+pub struct StructArgConstructor {
+    pub module: ModuleId,
+    pub struct_name: Identifier,
+    pub constructor: Identifier,
+}
+
+impl StructArgConstructor {
+    pub fn new(module: ModuleId, struct_name: &str, constructor: &str) -> Self {
+        Self {
+            module,
+            struct_name: Identifier::new(struct_name).unwrap(),
+            constructor: Identifier::new(constructor).unwrap(),
+        }
+    }
+
+    /// The constructors of `std::string::String` and `std::option::Option`, with the standard
+    /// library published at `0x1`.
+    pub fn std_constructors() -> Vec<Self> {
+        let std_module =
+            |name: &str| ModuleId::new(CORE_CODE_ADDRESS, Identifier::new(name).unwrap());
+        vec![
+            Self::new(std_module("string"), "String", "utf8"),
+            Self::new(std_module("option"), "Option", "from_vec"),
+        ]
+    }
+}
@@ -170,6 +170,7 @@ impl<'r, 'l, S: MoveResolver> DataStore for TransactionDataCache<'r, 'l, S> {
         &mut self,
         addr: AccountAddress,
         ty: &Type,
+        ty_layout: &MoveTypeLayout,
     ) -> PartialVMResult<(&mut GlobalValue, Option<Option<NumBytes>>)> {
         let account_cache = Self::get_mut_or_insert_with(&mut self.account_map, &addr, || {
             (addr, AccountDataCache::new())
@@ -185,13 +186,11 @@ impl<'r, 'l, S: MoveResolver> DataStore for TransactionDataCache<'r, 'l, S> {
                     return Err(PartialVMError::new(StatusCode::INTERNAL_TYPE_ERROR))
                 }
             };
-            // TODO(Gas): Shall we charge for this?
-            let ty_layout = self.loader.type_to_type_layout(ty)?;
 
             let gv = match self.remote.get_resource(&addr, &ty_tag) {
                 Ok(Some(blob)) => {
                     load_res = Some(Some(NumBytes::new(blob.len() as u64)));
-                    let val = match Value::simple_deserialize(&blob, &ty_layout) {
+                    let val = match Value::simple_deserialize(&blob, ty_layout) {
                         Some(val) => val,
                         None => {
                             let msg =
@@ -218,7 +217,9 @@ impl<'r, 'l, S: MoveResolver> DataStore for TransactionDataCache<'r, 'l, S> {
                 }
             };
 
-            account_cache.data_map.insert(ty.clone(), (ty_layout, gv));
+            account_cache
+                .data_map
+                .insert(ty.clone(), (ty_layout.clone(), gv));
         }
 
         Ok((
@@ -0,0 +1,221 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Hooks for attaching an external debugger to the interpreter.
+//!
+//! A `Debugger` installed with `set_debugger` is called before every instruction executed by any
+//! VM in the process, with an `ExecutionState` giving access to the current call stack. The
+//! debugger may block in `on_instruction` to suspend execution, e.g. while waiting for a client
+//! to resume it.
+//!
+//! Like the rest of the tracing support, this is only available in debug builds or with the
+//! `debugging` feature enabled.
+
+use crate::{
+    interpreter::Interpreter,
+    loader::{Function, Loader},
+};
+use move_binary_format::{
+    errors::{PartialVMError, PartialVMResult},
+    file_format::{Bytecode, FunctionDefinitionIndex},
+};
+use move_core_types::{
+    language_storage::{ModuleId, TypeTag},
+    value::MoveValue,
+    vm_status::StatusCode,
+};
+use move_vm_types::{
+    loaded_data::runtime_types::Type,
+    values::{Locals, Reference},
+};
+use once_cell::sync::Lazy;
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Mutex,
+};
+
+/// A debugger driven by the interpreter.
+pub trait Debugger: Send {
+    /// Called before `state.instruction()` is executed.
+    fn on_instruction(&mut self, state: &ExecutionState);
+}
+
+static DEBUGGER_INSTALLED: AtomicBool = AtomicBool::new(false);
+
+static DEBUGGER: Lazy<Mutex<Option<Box<dyn Debugger>>>> = Lazy::new(|| Mutex::new(None));
+
+/// Install `debugger`, replacing the previously installed one if any.
+///
+/// This must not be called while the installed debugger is suspending execution.
+pub fn set_debugger(debugger: Box<dyn Debugger>) {
+    *DEBUGGER.lock().unwrap() = Some(debugger);
+    DEBUGGER_INSTALLED.store(true, Ordering::SeqCst);
+}
+
+/// Remove the installed debugger, if any.
+pub fn clear_debugger() {
+    DEBUGGER_INSTALLED.store(false, Ordering::SeqCst);
+    *DEBUGGER.lock().unwrap() = None;
+}
+
+pub(crate) fn on_instruction(state: &ExecutionState) {
+    if !DEBUGGER_INSTALLED.load(Ordering::Relaxed) {
+        return;
+    }
+    if let Some(debugger) = DEBUGGER.lock().unwrap().as_mut() {
+        debugger.on_instruction(state);
+    }
+}
+
+/// The state of the interpreter about to execute an instruction.
+pub struct ExecutionState<'a> {
+    function: &'a Function,
+    ty_args: &'a [Type],
+    locals: &'a Locals,
+    pc: u16,
+    instr: &'a Bytecode,
+    loader: &'a Loader,
+    interp: &'a Interpreter,
+}
+
+/// A function on the call stack.
+#[derive(Clone, Debug)]
+pub struct FrameSnapshot {
+    /// The module defining the function, `None` for a script.
+    pub module_id: Option<ModuleId>,
+    pub function_name: String,
+    pub function_index: FunctionDefinitionIndex,
+    /// The instruction about to be executed in the innermost frame, the call being executed in
+    /// the other frames.
+    pub pc: u16,
+    pub type_arguments: Vec<TypeTag>,
+    /// Parameters followed by the other locals, in declaration order.
+    pub locals: Vec<LocalSnapshot>,
+}
+
+/// The content of a local.
+#[derive(Clone, Debug)]
+pub struct LocalSnapshot {
+    pub type_name: String,
+    /// `None` if the local holds no value, e.g. because it was moved or not assigned yet. For a
+    /// reference, the value it points to.
+    pub value: Option<MoveValue>,
+}
+
+impl<'a> ExecutionState<'a> {
+    pub(crate) fn new(
+        function: &'a Function,
+        ty_args: &'a [Type],
+        locals: &'a Locals,
+        pc: u16,
+        instr: &'a Bytecode,
+        loader: &'a Loader,
+        interp: &'a Interpreter,
+    ) -> Self {
+        Self {
+            function,
+            ty_args,
+            locals,
+            pc,
+            instr,
+            loader,
+            interp,
+        }
+    }
+
+    /// The module of the executing function, `None` for a script.
+    pub fn module_id(&self) -> Option<&ModuleId> {
+        self.function.module_id()
+    }
+
+    pub fn function_name(&self) -> &str {
+        self.function.name()
+    }
+
+    pub fn function_index(&self) -> FunctionDefinitionIndex {
+        self.function.index()
+    }
+
+    pub fn pc(&self) -> u16 {
+        self.pc
+    }
+
+    pub fn instruction(&self) -> &Bytecode {
+        self.instr
+    }
+
+    /// Number of frames on the call stack, including the executing one.
+    pub fn call_depth(&self) -> usize {
+        self.interp.debug_frames().count() + 1
+    }
+
+    /// Snapshot of the call stack, innermost frame first.
+    pub fn call_stack(&self) -> Vec<FrameSnapshot> {
+        let mut frames =
+            vec![self.frame_snapshot(self.function, self.ty_args, self.locals, self.pc)];
+        for (function, ty_args, locals, pc) in self.interp.debug_frames().rev() {
+            // the pc of a caller frame has already been advanced past the call
+            frames.push(self.frame_snapshot(function, ty_args, locals, pc.saturating_sub(1)));
+        }
+        frames
+    }
+
+    fn frame_snapshot(
+        &self,
+        function: &Function,
+        ty_args: &[Type],
+        locals: &Locals,
+        pc: u16,
+    ) -> FrameSnapshot {
+        let local_types = self
+            .loader
+            .local_types(function, ty_args)
+            .unwrap_or_default();
+        FrameSnapshot {
+            module_id: function.module_id().cloned(),
+            function_name: function.name().to_string(),
+            function_index: function.index(),
+            pc,
+            type_arguments: ty_args
+                .iter()
+                .filter_map(|ty| self.loader.type_to_type_tag(ty).ok())
+                .collect(),
+            locals: local_types
+                .iter()
+                .enumerate()
+                .map(|(idx, ty)| LocalSnapshot {
+                    type_name: self.type_name(ty),
+                    value: self.local_value(locals, idx, ty).ok(),
+                })
+                .collect(),
+        }
+    }
+
+    fn type_name(&self, ty: &Type) -> String {
+        match ty {
+            Type::Reference(inner) => format!("&{}", self.type_name(inner)),
+            Type::MutableReference(inner) => format!("&mut {}", self.type_name(inner)),
+            ty => match self.loader.type_to_type_tag(ty) {
+                Ok(tag) => tag.to_string(),
+                Err(_) => "?".to_string(),
+            },
+        }
+    }
+
+    fn local_value(&self, locals: &Locals, idx: usize, ty: &Type) -> PartialVMResult<MoveValue> {
+        let value = locals.copy_loc(idx)?;
+        let (value, ty) = match ty {
+            Type::Reference(inner) | Type::MutableReference(inner) => {
+                (value.value_as::<Reference>()?.read_ref()?, &**inner)
+            }
+            ty => (value, ty),
+        };
+        let layout = self.loader.type_to_type_layout(ty)?;
+        let annotated_layout = self.loader.type_to_fully_annotated_layout(ty)?;
+        let bytes = value
+            .simple_serialize(&layout)
+            .ok_or_else(|| PartialVMError::new(StatusCode::VALUE_SERIALIZATION_ERROR))?;
+        MoveValue::simple_deserialize(&bytes, &annotated_layout)
+            .map_err(|_| PartialVMError::new(StatusCode::VALUE_DESERIALIZATION_ERROR))
+    }
+}
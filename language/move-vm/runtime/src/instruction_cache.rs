@@ -0,0 +1,222 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Pre-decoded operands of the instructions of a function.
+//!
+//! Some instructions refer to types or functions which must be resolved against the loader and
+//! instantiated with the type arguments of the running frame: global storage operations, vector
+//! operations and calls. Those operands are resolved the first time an instruction executes for a
+//! given instantiation of its function, and cached so that later executions only read them. The
+//! layouts of the resources accessed by global storage operations are cached the same way.
+
+use crate::loader::Function;
+use move_binary_format::{
+    errors::{PartialVMError, PartialVMResult},
+    file_format::{
+        Bytecode, FunctionHandleIndex, FunctionInstantiationIndex, SignatureIndex,
+        StructDefInstantiationIndex, StructDefinitionIndex,
+    },
+};
+use move_core_types::{value::MoveTypeLayout, vm_status::StatusCode};
+use move_vm_types::loaded_data::runtime_types::Type;
+use once_cell::sync::OnceCell;
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Weak},
+};
+
+// The operands which are resolved at runtime. Instructions sharing an operand share its slot.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+enum Operand {
+    Struct(StructDefinitionIndex),
+    StructInstantiation(StructDefInstantiationIndex),
+    Signature(SignatureIndex),
+    FunctionHandle(FunctionHandleIndex),
+    FunctionInstantiation(FunctionInstantiationIndex),
+}
+
+impl Operand {
+    fn of(instruction: &Bytecode) -> Option<Self> {
+        use Bytecode::*;
+
+        Some(match instruction {
+            MutBorrowGlobal(idx) | ImmBorrowGlobal(idx) | Exists(idx) | MoveFrom(idx)
+            | MoveTo(idx) => Operand::Struct(*idx),
+            MutBorrowGlobalGeneric(idx)
+            | ImmBorrowGlobalGeneric(idx)
+            | ExistsGeneric(idx)
+            | MoveFromGeneric(idx)
+            | MoveToGeneric(idx) => Operand::StructInstantiation(*idx),
+            VecPack(idx, _)
+            | VecLen(idx)
+            | VecImmBorrow(idx)
+            | VecMutBorrow(idx)
+            | VecPushBack(idx)
+            | VecPopBack(idx)
+            | VecUnpack(idx, _)
+            | VecSwap(idx) => Operand::Signature(*idx),
+            Call(idx) => Operand::FunctionHandle(*idx),
+            CallGeneric(idx) => Operand::FunctionInstantiation(*idx),
+            _ => return None,
+        })
+    }
+}
+
+/// The slots of the operands of the instructions of a function, computed once when the function
+/// is loaded.
+pub(crate) struct OperandSlots {
+    // slot of the operand of the instruction at each code offset, if it has one
+    slots: Vec<Option<u32>>,
+    count: usize,
+}
+
+impl OperandSlots {
+    pub(crate) fn new(code: &[Bytecode]) -> Self {
+        let mut operands = BTreeMap::new();
+        let slots = code
+            .iter()
+            .map(|instruction| {
+                Operand::of(instruction).map(|operand| {
+                    let next = operands.len() as u32;
+                    *operands.entry(operand).or_insert(next)
+                })
+            })
+            .collect();
+        Self {
+            slots,
+            count: operands.len(),
+        }
+    }
+
+    fn slot(&self, pc: u16) -> PartialVMResult<usize> {
+        match self.slots.get(pc as usize) {
+            Some(Some(slot)) => Ok(*slot as usize),
+            _ => Err(
+                PartialVMError::new(StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR)
+                    .with_message(format!("No operand slot for instruction at offset {}", pc)),
+            ),
+        }
+    }
+}
+
+/// A function to call, with the cache of the instantiation being called.
+pub(crate) struct Callee {
+    pub(crate) function: Arc<Function>,
+    pub(crate) cache: Arc<InstructionCache>,
+}
+
+// A resolved operand. Functions are only weakly referenced, as a function may call itself and
+// a cache must not keep alive the function owning it.
+enum ResolvedOperand {
+    Type {
+        ty: Type,
+        layout: OnceCell<MoveTypeLayout>,
+    },
+    Callee {
+        function: Weak<Function>,
+        cache: Weak<InstructionCache>,
+    },
+}
+
+/// The operands resolved for an instantiation of a function.
+pub(crate) struct InstructionCache {
+    ty_args: Vec<Type>,
+    operands: Vec<OnceCell<ResolvedOperand>>,
+}
+
+impl InstructionCache {
+    pub(crate) fn new(slots: &OperandSlots, ty_args: Vec<Type>) -> Self {
+        Self {
+            ty_args,
+            operands: (0..slots.count).map(|_| OnceCell::new()).collect(),
+        }
+    }
+
+    /// The type arguments of the instantiation.
+    pub(crate) fn ty_args(&self) -> &[Type] {
+        &self.ty_args
+    }
+
+    /// The type operand of the instruction at `pc` in `function`, resolved with `resolve` the
+    /// first time it is needed.
+    pub(crate) fn ty(
+        &self,
+        function: &Function,
+        pc: u16,
+        resolve: impl FnOnce() -> PartialVMResult<Type>,
+    ) -> PartialVMResult<&Type> {
+        self.ty_operand(function, pc, resolve).map(|(ty, _)| ty)
+    }
+
+    /// The type operand of the instruction at `pc` in `function` and its layout, computed with
+    /// `layout` the first time it is needed.
+    pub(crate) fn ty_with_layout(
+        &self,
+        function: &Function,
+        pc: u16,
+        resolve: impl FnOnce() -> PartialVMResult<Type>,
+        layout: impl FnOnce(&Type) -> PartialVMResult<MoveTypeLayout>,
+    ) -> PartialVMResult<(&Type, &MoveTypeLayout)> {
+        let (ty, ty_layout) = self.ty_operand(function, pc, resolve)?;
+        Ok((ty, ty_layout.get_or_try_init(|| layout(ty))?))
+    }
+
+    fn ty_operand(
+        &self,
+        function: &Function,
+        pc: u16,
+        resolve: impl FnOnce() -> PartialVMResult<Type>,
+    ) -> PartialVMResult<(&Type, &OnceCell<MoveTypeLayout>)> {
+        let slot = function.operand_slots().slot(pc)?;
+        let operand = self.operands[slot].get_or_try_init(|| {
+            resolve().map(|ty| ResolvedOperand::Type {
+                ty,
+                layout: OnceCell::new(),
+            })
+        })?;
+        match operand {
+            ResolvedOperand::Type { ty, layout } => Ok((ty, layout)),
+            ResolvedOperand::Callee { .. } => Err(PartialVMError::new(
+                StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR,
+            )
+            .with_message(format!("Operand at offset {} is not a type", pc))),
+        }
+    }
+
+    /// The function called by the instruction at `pc` in `function`, resolved with `resolve`
+    /// the first time it is needed.
+    pub(crate) fn callee(
+        &self,
+        function: &Function,
+        pc: u16,
+        resolve: impl Fn() -> PartialVMResult<Callee>,
+    ) -> PartialVMResult<Callee> {
+        let slot = function.operand_slots().slot(pc)?;
+        let mut resolved = None;
+        let operand = self.operands[slot].get_or_try_init(|| {
+            let callee = resolve()?;
+            let operand = ResolvedOperand::Callee {
+                function: Arc::downgrade(&callee.function),
+                cache: Arc::downgrade(&callee.cache),
+            };
+            resolved = Some(callee);
+            Ok::<_, PartialVMError>(operand)
+        })?;
+        if let Some(callee) = resolved {
+            return Ok(callee);
+        }
+        match operand {
+            ResolvedOperand::Callee { function, cache } => {
+                match (function.upgrade(), cache.upgrade()) {
+                    (Some(function), Some(cache)) => Ok(Callee { function, cache }),
+                    // the callee is not alive anymore, resolve it again without caching it
+                    _ => resolve(),
+                }
+            }
+            ResolvedOperand::Type { .. } => Err(PartialVMError::new(
+                StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR,
+            )
+            .with_message(format!("Operand at offset {} is not a function", pc))),
+        }
+    }
+}
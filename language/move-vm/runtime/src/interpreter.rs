@@ -3,8 +3,10 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
+    instruction_cache::{Callee, InstructionCache},
     loader::{Function, Loader, Resolver},
     native_functions::NativeContext,
+    statistics::ExecutionStatistics,
     trace,
 };
 use fail::fail_point;
@@ -15,7 +17,8 @@ use move_binary_format::{
 use move_core_types::{
     account_address::AccountAddress,
     gas_algebra::{NumArgs, NumBytes},
-    language_storage::TypeTag,
+    language_storage::{ModuleId, TypeTag},
+    value::MoveTypeLayout,
     vm_status::{StatusCode, StatusType},
 };
 use move_vm_types::{
@@ -67,6 +70,8 @@ pub(crate) struct Interpreter {
     operand_stack: Stack,
     /// The stack of active functions.
     call_stack: CallStack,
+    /// Statistics of the execution, if they are collected.
+    statistics: Option<ExecutionStatistics>,
 }
 
 struct TypeWithLoader<'a, 'b> {
@@ -83,6 +88,8 @@ impl<'a, 'b> TypeView for TypeWithLoader<'a, 'b> {
 impl Interpreter {
     /// Entrypoint into the interpreter. All external calls need to be routed through this
     /// function.
+    ///
+    /// If `statistics` is set, the execution is recorded in it.
     pub(crate) fn entrypoint(
         function: Arc<Function>,
         ty_args: Vec<Type>,
@@ -91,14 +98,19 @@ impl Interpreter {
         gas_meter: &mut impl GasMeter,
         extensions: &mut NativeContextExtensions,
         loader: &Loader,
+        statistics: &mut Option<ExecutionStatistics>,
     ) -> VMResult<Vec<Value>> {
-        Interpreter {
-            operand_stack: Stack::new(),
-            call_stack: CallStack::new(),
-        }
-        .execute_main(
+        let vm_config = loader.vm_config();
+        let mut interpreter = Interpreter {
+            operand_stack: Stack::new(vm_config.max_value_stack_size),
+            call_stack: CallStack::new(vm_config.max_call_stack_size),
+            statistics: statistics.take(),
+        };
+        let result = interpreter.execute_main(
             loader, data_store, gas_meter, extensions, function, ty_args, args,
-        )
+        );
+        *statistics = interpreter.statistics;
+        result
     }
 
     /// Main loop for the execution of a function.
@@ -108,7 +120,7 @@ impl Interpreter {
     /// on call. When that happens the frame is changes to a new one (call) or to the one
     /// at the top of the stack (return). If the call stack is empty execution is completed.
     fn execute_main(
-        mut self,
+        &mut self,
         loader: &Loader,
         data_store: &mut impl DataStore,
         gas_meter: &mut impl GasMeter,
@@ -124,13 +136,17 @@ impl Interpreter {
                 .map_err(|e| self.set_location(e))?;
         }
 
-        let mut current_frame = Frame::new(function, ty_args, locals);
+        if let Some(statistics) = &mut self.statistics {
+            statistics.record_call(&function, 1);
+        }
+        let max_instantiations = loader.vm_config().max_cached_instantiations;
+        let cache = function.instruction_cache(ty_args, max_instantiations);
+        let mut current_frame = Frame::new(function, cache, locals);
         loop {
             let resolver = current_frame.resolver(loader);
-            let exit_code =
-                current_frame //self
-                    .execute_code(&resolver, &mut self, data_store, gas_meter)
-                    .map_err(|err| self.maybe_core_dump(err, &current_frame))?;
+            let exit_code = current_frame //self
+                .execute_code(&resolver, self, data_store, gas_meter)
+                .map_err(|err| self.maybe_core_dump(err, &current_frame))?;
             match exit_code {
                 ExitCode::Return => {
                     if let Some(frame) = self.call_stack.pop() {
@@ -139,11 +155,24 @@ impl Interpreter {
                         current_frame.pc += 1; // advance past the Call instruction in the caller
                     } else {
                         // end of execution. `self` should no longer be used afterward
-                        return Ok(self.operand_stack.0);
+                        if let Some(statistics) = &mut self.statistics {
+                            statistics.record_operand_stack_size(self.operand_stack.values.len());
+                        }
+                        return Ok(std::mem::take(&mut self.operand_stack.values));
                     }
                 }
                 ExitCode::Call(fh_idx) => {
-                    let func = resolver.function_from_handle(fh_idx);
+                    let Callee {
+                        function: func,
+                        cache,
+                    } = current_frame
+                        .callee(|| {
+                            let function = resolver.function_from_handle(fh_idx);
+                            let cache = function.instruction_cache(vec![], max_instantiations);
+                            Ok(Callee { function, cache })
+                        })
+                        .map_err(|e| set_err_info!(current_frame, e))?;
+                    self.record_call(&func);
 
                     // Charge gas
                     let module_id = func
@@ -169,6 +198,7 @@ impl Interpreter {
                             data_store,
                             gas_meter,
                             extensions,
+                            &current_frame,
                             func,
                             vec![],
                         )?;
@@ -176,7 +206,7 @@ impl Interpreter {
                         continue;
                     }
                     let frame = self
-                        .make_call_frame(func, vec![])
+                        .make_call_frame(func, cache)
                         .map_err(|err| self.maybe_core_dump(err, &current_frame))?;
                     self.call_stack.push(current_frame).map_err(|frame| {
                         let err = PartialVMError::new(StatusCode::CALL_STACK_OVERFLOW);
@@ -188,10 +218,20 @@ impl Interpreter {
                 }
                 ExitCode::CallGeneric(idx) => {
                     // TODO(Gas): We should charge gas as we do type substitution...
-                    let ty_args = resolver
-                        .instantiate_generic_function(idx, current_frame.ty_args())
+                    let Callee {
+                        function: func,
+                        cache,
+                    } = current_frame
+                        .callee(|| {
+                            let ty_args = resolver
+                                .instantiate_generic_function(idx, current_frame.ty_args())?;
+                            let function = resolver.function_from_instantiation(idx);
+                            let cache = function.instruction_cache(ty_args, max_instantiations);
+                            Ok(Callee { function, cache })
+                        })
                         .map_err(|e| set_err_info!(current_frame, e))?;
-                    let func = resolver.function_from_instantiation(idx);
+                    self.record_call(&func);
+                    let ty_args = cache.ty_args();
 
                     // Charge gas
                     let module_id = func
@@ -214,13 +254,19 @@ impl Interpreter {
 
                     if func.is_native() {
                         self.call_native(
-                            &resolver, data_store, gas_meter, extensions, func, ty_args,
+                            &resolver,
+                            data_store,
+                            gas_meter,
+                            extensions,
+                            &current_frame,
+                            func,
+                            ty_args.to_vec(),
                         )?;
                         current_frame.pc += 1; // advance past the Call instruction in the caller
                         continue;
                     }
                     let frame = self
-                        .make_call_frame(func, ty_args)
+                        .make_call_frame(func, cache)
                         .map_err(|err| self.maybe_core_dump(err, &current_frame))?;
                     self.call_stack.push(current_frame).map_err(|frame| {
                         let err = PartialVMError::new(StatusCode::CALL_STACK_OVERFLOW);
@@ -238,7 +284,11 @@ impl Interpreter {
     ///
     /// Native functions do not push a frame at the moment and as such errors from a native
     /// function are incorrectly attributed to the caller.
-    fn make_call_frame(&mut self, func: Arc<Function>, ty_args: Vec<Type>) -> VMResult<Frame> {
+    fn make_call_frame(
+        &mut self,
+        func: Arc<Function>,
+        cache: Arc<InstructionCache>,
+    ) -> VMResult<Frame> {
         let mut locals = Locals::new(func.local_count());
         let arg_count = func.arg_count();
         for i in 0..arg_count {
@@ -249,7 +299,7 @@ impl Interpreter {
                 )
                 .map_err(|e| self.set_location(e))?;
         }
-        Ok(Frame::new(func, ty_args, locals))
+        Ok(Frame::new(func, cache, locals))
     }
 
     /// Call a native functions.
@@ -259,6 +309,7 @@ impl Interpreter {
         data_store: &mut dyn DataStore,
         gas_meter: &mut impl GasMeter,
         extensions: &mut NativeContextExtensions,
+        caller: &Frame,
         function: Arc<Function>,
         ty_args: Vec<Type>,
     ) -> VMResult<()> {
@@ -268,10 +319,14 @@ impl Interpreter {
             data_store,
             gas_meter,
             extensions,
+            caller.function.module_id(),
             function.clone(),
             ty_args,
         )
         .map_err(|e| match function.module_id() {
+            // An abort with a message is raised on behalf of the caller, as if it had executed
+            // the `Abort` instruction itself, so that the abort code is interpreted in its module.
+            Some(_) if e.abort_message().is_some() => set_err_info!(caller, e),
             Some(id) => e
                 .at_code_offset(function.index(), 0)
                 .finish(Location::Module(id.clone())),
@@ -289,6 +344,7 @@ impl Interpreter {
         data_store: &mut dyn DataStore,
         gas_meter: &mut impl GasMeter,
         extensions: &mut NativeContextExtensions,
+        caller_module: Option<&ModuleId>,
         function: Arc<Function>,
         ty_args: Vec<Type>,
     ) -> PartialVMResult<()> {
@@ -298,15 +354,21 @@ impl Interpreter {
         for _ in 0..expected_args {
             args.push_front(self.operand_stack.pop()?);
         }
-        let mut native_context = NativeContext::new(self, data_store, resolver, extensions);
+        let mut native_context =
+            NativeContext::new(self, data_store, resolver, extensions, caller_module);
         let native_function = function.get_native()?;
 
         let result = native_function(&mut native_context, ty_args, args)?;
         gas_meter.charge_native_function(result.cost)?;
 
-        let return_values = result
-            .result
-            .map_err(|code| PartialVMError::new(StatusCode::ABORTED).with_sub_status(code))?;
+        let abort_message = result.abort_message;
+        let return_values = result.result.map_err(|code| {
+            let err = PartialVMError::new(StatusCode::ABORTED).with_sub_status(code);
+            match abort_message {
+                Some(message) => err.with_abort_message(message),
+                None => err,
+            }
+        })?;
         // Paranoid check to protect us against incorrect native function implementations. A native function that
         // returns a different number of values than its declared types will trigger this check
         if return_values.len() != return_type_count {
@@ -361,14 +423,41 @@ impl Interpreter {
         self.binop(|lhs, rhs| Ok(Value::bool(f(lhs, rhs)?)))
     }
 
+    /// Records a call from the current frame, if statistics are collected.
+    fn record_call(&mut self, function: &Function) {
+        if let Some(statistics) = &mut self.statistics {
+            // the callee runs above the frames on the call stack and the current frame
+            statistics.record_call(function, self.call_stack.frames.len() + 2);
+        }
+    }
+
+    /// Records an access to global storage, if statistics are collected.
+    fn record_global_access(
+        &mut self,
+        is_write: bool,
+        loader: &Loader,
+        ty: &Type,
+    ) -> PartialVMResult<()> {
+        if let Some(statistics) = &mut self.statistics {
+            let ty = loader.type_to_type_tag(ty)?;
+            if is_write {
+                statistics.record_global_write(ty);
+            } else {
+                statistics.record_global_read(ty);
+            }
+        }
+        Ok(())
+    }
+
     /// Loads a resource from the data store and return the number of bytes read from the storage.
     fn load_resource<'b>(
         gas_meter: &mut impl GasMeter,
         data_store: &'b mut impl DataStore,
         addr: AccountAddress,
         ty: &Type,
+        ty_layout: &MoveTypeLayout,
     ) -> PartialVMResult<&'b mut GlobalValue> {
-        match data_store.load_resource(addr, ty) {
+        match data_store.load_resource(addr, ty, ty_layout) {
             Ok((gv, load_res)) => {
                 if let Some(loaded) = load_res {
                     gas_meter.charge_load_resource(loaded)?;
@@ -395,8 +484,10 @@ impl Interpreter {
         data_store: &mut impl DataStore,
         addr: AccountAddress,
         ty: &Type,
+        ty_layout: &MoveTypeLayout,
     ) -> PartialVMResult<()> {
-        let res = Self::load_resource(gas_meter, data_store, addr, ty)?.borrow_global();
+        self.record_global_access(is_mut, loader, ty)?;
+        let res = Self::load_resource(gas_meter, data_store, addr, ty, ty_layout)?.borrow_global();
         gas_meter.charge_borrow_global(
             is_mut,
             is_generic,
@@ -416,8 +507,10 @@ impl Interpreter {
         data_store: &mut impl DataStore,
         addr: AccountAddress,
         ty: &Type,
+        ty_layout: &MoveTypeLayout,
     ) -> PartialVMResult<()> {
-        let gv = Self::load_resource(gas_meter, data_store, addr, ty)?;
+        self.record_global_access(false, loader, ty)?;
+        let gv = Self::load_resource(gas_meter, data_store, addr, ty, ty_layout)?;
         let exists = gv.exists()?;
         gas_meter.charge_exists(is_generic, TypeWithLoader { ty, loader }, exists)?;
         self.operand_stack.push(Value::bool(exists))?;
@@ -433,22 +526,25 @@ impl Interpreter {
         data_store: &mut impl DataStore,
         addr: AccountAddress,
         ty: &Type,
+        ty_layout: &MoveTypeLayout,
     ) -> PartialVMResult<()> {
-        let resource = match Self::load_resource(gas_meter, data_store, addr, ty)?.move_from() {
-            Ok(resource) => {
-                gas_meter.charge_move_from(
-                    is_generic,
-                    TypeWithLoader { ty, loader },
-                    Some(&resource),
-                )?;
-                resource
-            }
-            Err(err) => {
-                let val: Option<&Value> = None;
-                gas_meter.charge_move_from(is_generic, TypeWithLoader { ty, loader }, val)?;
-                return Err(err);
-            }
-        };
+        self.record_global_access(true, loader, ty)?;
+        let resource =
+            match Self::load_resource(gas_meter, data_store, addr, ty, ty_layout)?.move_from() {
+                Ok(resource) => {
+                    gas_meter.charge_move_from(
+                        is_generic,
+                        TypeWithLoader { ty, loader },
+                        Some(&resource),
+                    )?;
+                    resource
+                }
+                Err(err) => {
+                    let val: Option<&Value> = None;
+                    gas_meter.charge_move_from(is_generic, TypeWithLoader { ty, loader }, val)?;
+                    return Err(err);
+                }
+            };
         self.operand_stack.push(resource)?;
         Ok(())
     }
@@ -462,9 +558,11 @@ impl Interpreter {
         data_store: &mut impl DataStore,
         addr: AccountAddress,
         ty: &Type,
+        ty_layout: &MoveTypeLayout,
         resource: Value,
     ) -> PartialVMResult<()> {
-        let gv = Self::load_resource(gas_meter, data_store, addr, ty)?;
+        self.record_global_access(true, loader, ty)?;
+        let gv = Self::load_resource(gas_meter, data_store, addr, ty, ty_layout)?;
         // NOTE(Gas): To maintain backward compatibility, we need to charge gas after attempting
         //            the move_to operation.
         match gv.move_to(resource) {
@@ -580,6 +678,18 @@ impl Interpreter {
         Ok(())
     }
 
+    /// The frames of the call stack below the one currently executing, outermost first, as
+    /// `(function, type arguments, locals, pc)`.
+    #[cfg(any(debug_assertions, feature = "debugging"))]
+    pub(crate) fn debug_frames(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = (&Function, &[Type], &Locals, u16)> {
+        self.call_stack
+            .frames
+            .iter()
+            .map(|frame| (&*frame.function, frame.ty_args(), &frame.locals, frame.pc))
+    }
+
     #[allow(dead_code)]
     pub(crate) fn debug_print_stack_trace<B: Write>(
         &self,
@@ -587,11 +697,11 @@ impl Interpreter {
         loader: &Loader,
     ) -> PartialVMResult<()> {
         debug_writeln!(buf, "Call Stack:")?;
-        for (i, frame) in self.call_stack.0.iter().enumerate() {
+        for (i, frame) in self.call_stack.frames.iter().enumerate() {
             self.debug_print_frame(buf, loader, i, frame)?;
         }
         debug_writeln!(buf, "Operand Stack:")?;
-        for (idx, val) in self.operand_stack.0.iter().enumerate() {
+        for (idx, val) in self.operand_stack.values.iter().enumerate() {
             // TODO: Currently we do not know the types of the values on the operand stack.
             // Revisit.
             debug_write!(buf, "    [{}] ", idx)?;
@@ -609,7 +719,7 @@ impl Interpreter {
     /// of an execution.
     fn internal_state_str(&self, current_frame: &Frame) -> String {
         let mut internal_state = "Call stack:\n".to_string();
-        for (i, frame) in self.call_stack.0.iter().enumerate() {
+        for (i, frame) in self.call_stack.frames.iter().enumerate() {
             internal_state.push_str(
                 format!(
                     " frame #{}: {} [pc = {}]\n",
@@ -623,7 +733,7 @@ impl Interpreter {
         internal_state.push_str(
             format!(
                 "*frame #{}: {} [pc = {}]:\n",
-                self.call_stack.0.len(),
+                self.call_stack.frames.len(),
                 current_frame.function.pretty_string(),
                 current_frame.pc,
             )
@@ -641,7 +751,7 @@ impl Interpreter {
         }
         internal_state.push_str(format!("Locals:\n{}\n", current_frame.locals).as_str());
         internal_state.push_str("Operand Stack:\n");
-        for value in &self.operand_stack.0 {
+        for value in &self.operand_stack.values {
             internal_state.push_str(format!("{}\n", value).as_str());
         }
         internal_state
@@ -662,7 +772,7 @@ impl Interpreter {
         // is the last one)
         let stack_trace = self
             .call_stack
-            .0
+            .frames
             .iter()
             .rev()
             .take(count)
@@ -678,24 +788,26 @@ impl Interpreter {
     }
 }
 
-// TODO Determine stack size limits based on gas limit
-const OPERAND_STACK_SIZE_LIMIT: usize = 1024;
-const CALL_STACK_SIZE_LIMIT: usize = 1024;
-
 /// The operand stack.
-struct Stack(Vec<Value>);
+struct Stack {
+    values: Vec<Value>,
+    max_size: usize,
+}
 
 impl Stack {
-    /// Create a new empty operand stack.
-    fn new() -> Self {
-        Stack(vec![])
+    /// Create a new empty operand stack holding at most `max_size` values.
+    fn new(max_size: usize) -> Self {
+        Stack {
+            values: vec![],
+            max_size,
+        }
     }
 
     /// Push a `Value` on the stack if the max stack size has not been reached. Abort execution
     /// otherwise.
     fn push(&mut self, value: Value) -> PartialVMResult<()> {
-        if self.0.len() < OPERAND_STACK_SIZE_LIMIT {
-            self.0.push(value);
+        if self.values.len() < self.max_size {
+            self.values.push(value);
             Ok(())
         } else {
             Err(PartialVMError::new(StatusCode::EXECUTION_STACK_OVERFLOW))
@@ -704,7 +816,7 @@ impl Stack {
 
     /// Pop a `Value` off the stack or abort execution if the stack is empty.
     fn pop(&mut self) -> PartialVMResult<Value> {
-        self.0
+        self.values
             .pop()
             .ok_or_else(|| PartialVMError::new(StatusCode::EMPTY_VALUE_STACK))
     }
@@ -721,37 +833,43 @@ impl Stack {
     /// Pop n values off the stack.
     fn popn(&mut self, n: u16) -> PartialVMResult<Vec<Value>> {
         let remaining_stack_size = self
-            .0
+            .values
             .len()
             .checked_sub(n as usize)
             .ok_or_else(|| PartialVMError::new(StatusCode::EMPTY_VALUE_STACK))?;
-        let args = self.0.split_off(remaining_stack_size);
+        let args = self.values.split_off(remaining_stack_size);
         Ok(args)
     }
 
     fn last_n(&self, n: usize) -> PartialVMResult<impl ExactSizeIterator<Item = &Value>> {
-        if self.0.len() < n {
+        if self.values.len() < n {
             return Err(PartialVMError::new(StatusCode::EMPTY_VALUE_STACK)
                 .with_message("Failed to get last n arguments on the argument stack".to_string()));
         }
-        Ok(self.0[(self.0.len() - n)..].iter())
+        Ok(self.values[(self.values.len() - n)..].iter())
     }
 }
 
 /// A call stack.
 // #[derive(Debug)]
-struct CallStack(Vec<Frame>);
+struct CallStack {
+    frames: Vec<Frame>,
+    max_size: usize,
+}
 
 impl CallStack {
-    /// Create a new empty call stack.
-    fn new() -> Self {
-        CallStack(vec![])
+    /// Create a new empty call stack holding at most `max_size` frames.
+    fn new(max_size: usize) -> Self {
+        CallStack {
+            frames: vec![],
+            max_size,
+        }
     }
 
     /// Push a `Frame` on the call stack.
     fn push(&mut self, frame: Frame) -> ::std::result::Result<(), Frame> {
-        if self.0.len() < CALL_STACK_SIZE_LIMIT {
-            self.0.push(frame);
+        if self.frames.len() < self.max_size {
+            self.frames.push(frame);
             Ok(())
         } else {
             Err(frame)
@@ -760,11 +878,11 @@ impl CallStack {
 
     /// Pop a `Frame` off the call stack.
     fn pop(&mut self) -> Option<Frame> {
-        self.0.pop()
+        self.frames.pop()
     }
 
     fn current_location(&self) -> Location {
-        let location_opt = self.0.last().map(|frame| frame.location());
+        let location_opt = self.frames.last().map(|frame| frame.location());
         location_opt.unwrap_or(Location::Undefined)
     }
 }
@@ -776,7 +894,7 @@ struct Frame {
     pc: u16,
     locals: Locals,
     function: Arc<Function>,
-    ty_args: Vec<Type>,
+    cache: Arc<InstructionCache>,
 }
 
 /// An `ExitCode` from `execute_code_unit`.
@@ -791,12 +909,12 @@ impl Frame {
     /// Create a new `Frame` given a `Function` and the function `Locals`.
     ///
     /// The locals must be loaded before calling this.
-    fn new(function: Arc<Function>, ty_args: Vec<Type>, locals: Locals) -> Self {
+    fn new(function: Arc<Function>, cache: Arc<InstructionCache>, locals: Locals) -> Self {
         Frame {
             pc: 0,
             locals,
             function,
-            ty_args,
+            cache,
         }
     }
 
@@ -838,6 +956,7 @@ impl Frame {
             for instruction in &code[self.pc as usize..] {
                 trace!(
                     &self.function,
+                    self.ty_args(),
                     &self.locals,
                     self.pc,
                     instruction,
@@ -845,6 +964,11 @@ impl Frame {
                     interpreter
                 );
 
+                if let Some(statistics) = &mut interpreter.statistics {
+                    statistics
+                        .record_instruction(instruction, interpreter.operand_stack.values.len());
+                }
+
                 fail_point!("move_vm::interpreter_loop", |_| {
                     Err(
                         PartialVMError::new(StatusCode::VERIFIER_INVARIANT_VIOLATION).with_message(
@@ -1156,7 +1280,9 @@ impl Frame {
                     Bytecode::MutBorrowGlobal(sd_idx) | Bytecode::ImmBorrowGlobal(sd_idx) => {
                         let is_mut = matches!(instruction, Bytecode::MutBorrowGlobal(_));
                         let addr = interpreter.operand_stack.pop_as::<AccountAddress>()?;
-                        let ty = resolver.get_struct_type(*sd_idx);
+                        let (ty, ty_layout) = self.operand_resource(resolver.loader(), |_| {
+                            Ok(resolver.get_struct_type(*sd_idx))
+                        })?;
                         interpreter.borrow_global(
                             is_mut,
                             false,
@@ -1164,14 +1290,18 @@ impl Frame {
                             gas_meter,
                             data_store,
                             addr,
-                            &ty,
+                            ty,
+                            ty_layout,
                         )?;
                     }
                     Bytecode::MutBorrowGlobalGeneric(si_idx)
                     | Bytecode::ImmBorrowGlobalGeneric(si_idx) => {
                         let is_mut = matches!(instruction, Bytecode::MutBorrowGlobalGeneric(_));
                         let addr = interpreter.operand_stack.pop_as::<AccountAddress>()?;
-                        let ty = resolver.instantiate_generic_type(*si_idx, self.ty_args())?;
+                        let (ty, ty_layout) = self
+                            .operand_resource(resolver.loader(), |ty_args| {
+                                resolver.instantiate_generic_type(*si_idx, ty_args)
+                            })?;
                         interpreter.borrow_global(
                             is_mut,
                             true,
@@ -1179,55 +1309,70 @@ impl Frame {
                             gas_meter,
                             data_store,
                             addr,
-                            &ty,
+                            ty,
+                            ty_layout,
                         )?;
                     }
                     Bytecode::Exists(sd_idx) => {
                         let addr = interpreter.operand_stack.pop_as::<AccountAddress>()?;
-                        let ty = resolver.get_struct_type(*sd_idx);
+                        let (ty, ty_layout) = self.operand_resource(resolver.loader(), |_| {
+                            Ok(resolver.get_struct_type(*sd_idx))
+                        })?;
                         interpreter.exists(
                             false,
                             resolver.loader(),
                             gas_meter,
                             data_store,
                             addr,
-                            &ty,
+                            ty,
+                            ty_layout,
                         )?;
                     }
                     Bytecode::ExistsGeneric(si_idx) => {
                         let addr = interpreter.operand_stack.pop_as::<AccountAddress>()?;
-                        let ty = resolver.instantiate_generic_type(*si_idx, self.ty_args())?;
+                        let (ty, ty_layout) = self
+                            .operand_resource(resolver.loader(), |ty_args| {
+                                resolver.instantiate_generic_type(*si_idx, ty_args)
+                            })?;
                         interpreter.exists(
                             true,
                             resolver.loader(),
                             gas_meter,
                             data_store,
                             addr,
-                            &ty,
+                            ty,
+                            ty_layout,
                         )?;
                     }
                     Bytecode::MoveFrom(sd_idx) => {
                         let addr = interpreter.operand_stack.pop_as::<AccountAddress>()?;
-                        let ty = resolver.get_struct_type(*sd_idx);
+                        let (ty, ty_layout) = self.operand_resource(resolver.loader(), |_| {
+                            Ok(resolver.get_struct_type(*sd_idx))
+                        })?;
                         interpreter.move_from(
                             false,
                             resolver.loader(),
                             gas_meter,
                             data_store,
                             addr,
-                            &ty,
+                            ty,
+                            ty_layout,
                         )?;
                     }
                     Bytecode::MoveFromGeneric(si_idx) => {
                         let addr = interpreter.operand_stack.pop_as::<AccountAddress>()?;
-                        let ty = resolver.instantiate_generic_type(*si_idx, self.ty_args())?;
+                        let (ty, ty_layout) = self
+                            .operand_resource(resolver.loader(), |ty_args| {
+                                resolver.instantiate_generic_type(*si_idx, ty_args)
+                            })?;
                         interpreter.move_from(
                             true,
                             resolver.loader(),
                             gas_meter,
                             data_store,
                             addr,
-                            &ty,
+                            ty,
+                            ty_layout,
                         )?;
                     }
                     Bytecode::MoveTo(sd_idx) => {
@@ -1238,7 +1383,9 @@ impl Frame {
                             .value_as::<Reference>()?
                             .read_ref()?
                             .value_as::<AccountAddress>()?;
-                        let ty = resolver.get_struct_type(*sd_idx);
+                        let (ty, ty_layout) = self.operand_resource(resolver.loader(), |_| {
+                            Ok(resolver.get_struct_type(*sd_idx))
+                        })?;
                         // REVIEW: Can we simplify Interpreter::move_to?
                         interpreter.move_to(
                             false,
@@ -1246,7 +1393,8 @@ impl Frame {
                             gas_meter,
                             data_store,
                             addr,
-                            &ty,
+                            ty,
+                            ty_layout,
                             resource,
                         )?;
                     }
@@ -1258,14 +1406,18 @@ impl Frame {
                             .value_as::<Reference>()?
                             .read_ref()?
                             .value_as::<AccountAddress>()?;
-                        let ty = resolver.instantiate_generic_type(*si_idx, self.ty_args())?;
+                        let (ty, ty_layout) = self
+                            .operand_resource(resolver.loader(), |ty_args| {
+                                resolver.instantiate_generic_type(*si_idx, ty_args)
+                            })?;
                         interpreter.move_to(
                             true,
                             resolver.loader(),
                             gas_meter,
                             data_store,
                             addr,
-                            &ty,
+                            ty,
+                            ty_layout,
                             resource,
                         )?;
                     }
@@ -1283,18 +1435,20 @@ impl Frame {
                         gas_meter.charge_simple_instr(S::Nop)?;
                     }
                     Bytecode::VecPack(si, num) => {
-                        let ty = resolver.instantiate_single_type(*si, self.ty_args())?;
+                        let ty = self
+                            .operand_ty(|ty_args| resolver.instantiate_single_type(*si, ty_args))?;
                         gas_meter.charge_vec_pack(
-                            make_ty!(&ty),
+                            make_ty!(ty),
                             interpreter.operand_stack.last_n(*num as usize)?,
                         )?;
                         let elements = interpreter.operand_stack.popn(*num as u16)?;
-                        let value = Vector::pack(&ty, elements)?;
+                        let value = Vector::pack(ty, elements)?;
                         interpreter.operand_stack.push(value)?;
                     }
                     Bytecode::VecLen(si) => {
                         let vec_ref = interpreter.operand_stack.pop_as::<VectorRef>()?;
-                        let ty = &resolver.instantiate_single_type(*si, self.ty_args())?;
+                        let ty = self
+                            .operand_ty(|ty_args| resolver.instantiate_single_type(*si, ty_args))?;
                         gas_meter.charge_vec_len(TypeWithLoader {
                             ty,
                             loader: resolver.loader(),
@@ -1305,15 +1459,17 @@ impl Frame {
                     Bytecode::VecImmBorrow(si) => {
                         let idx = interpreter.operand_stack.pop_as::<u64>()? as usize;
                         let vec_ref = interpreter.operand_stack.pop_as::<VectorRef>()?;
-                        let ty = resolver.instantiate_single_type(*si, self.ty_args())?;
-                        let res = vec_ref.borrow_elem(idx, &ty);
-                        gas_meter.charge_vec_borrow(false, make_ty!(&ty), res.is_ok())?;
+                        let ty = self
+                            .operand_ty(|ty_args| resolver.instantiate_single_type(*si, ty_args))?;
+                        let res = vec_ref.borrow_elem(idx, ty);
+                        gas_meter.charge_vec_borrow(false, make_ty!(ty), res.is_ok())?;
                         interpreter.operand_stack.push(res?)?;
                     }
                     Bytecode::VecMutBorrow(si) => {
                         let idx = interpreter.operand_stack.pop_as::<u64>()? as usize;
                         let vec_ref = interpreter.operand_stack.pop_as::<VectorRef>()?;
-                        let ty = &resolver.instantiate_single_type(*si, self.ty_args())?;
+                        let ty = self
+                            .operand_ty(|ty_args| resolver.instantiate_single_type(*si, ty_args))?;
                         let res = vec_ref.borrow_elem(idx, ty);
                         gas_meter.charge_vec_borrow(true, make_ty!(ty), res.is_ok())?;
                         interpreter.operand_stack.push(res?)?;
@@ -1321,20 +1477,23 @@ impl Frame {
                     Bytecode::VecPushBack(si) => {
                         let elem = interpreter.operand_stack.pop()?;
                         let vec_ref = interpreter.operand_stack.pop_as::<VectorRef>()?;
-                        let ty = &resolver.instantiate_single_type(*si, self.ty_args())?;
+                        let ty = self
+                            .operand_ty(|ty_args| resolver.instantiate_single_type(*si, ty_args))?;
                         gas_meter.charge_vec_push_back(make_ty!(ty), &elem)?;
                         vec_ref.push_back(elem, ty)?;
                     }
                     Bytecode::VecPopBack(si) => {
                         let vec_ref = interpreter.operand_stack.pop_as::<VectorRef>()?;
-                        let ty = &resolver.instantiate_single_type(*si, self.ty_args())?;
+                        let ty = self
+                            .operand_ty(|ty_args| resolver.instantiate_single_type(*si, ty_args))?;
                         let res = vec_ref.pop(ty);
                         gas_meter.charge_vec_pop_back(make_ty!(ty), res.as_ref().ok())?;
                         interpreter.operand_stack.push(res?)?;
                     }
                     Bytecode::VecUnpack(si, num) => {
                         let vec_val = interpreter.operand_stack.pop_as::<Vector>()?;
-                        let ty = &resolver.instantiate_single_type(*si, self.ty_args())?;
+                        let ty = self
+                            .operand_ty(|ty_args| resolver.instantiate_single_type(*si, ty_args))?;
                         gas_meter.charge_vec_unpack(make_ty!(ty), NumArgs::new(*num))?;
                         let elements = vec_val.unpack(ty, *num)?;
                         for value in elements {
@@ -1345,7 +1504,8 @@ impl Frame {
                         let idx2 = interpreter.operand_stack.pop_as::<u64>()? as usize;
                         let idx1 = interpreter.operand_stack.pop_as::<u64>()? as usize;
                         let vec_ref = interpreter.operand_stack.pop_as::<VectorRef>()?;
-                        let ty = &resolver.instantiate_single_type(*si, self.ty_args())?;
+                        let ty = self
+                            .operand_ty(|ty_args| resolver.instantiate_single_type(*si, ty_args))?;
                         gas_meter.charge_vec_swap(make_ty!(ty))?;
                         vec_ref.swap(idx1, idx2, ty)?;
                     }
@@ -1370,7 +1530,39 @@ impl Frame {
     }
 
     fn ty_args(&self) -> &[Type] {
-        &self.ty_args
+        self.cache.ty_args()
+    }
+
+    // The function called by the current instruction, resolved with `resolve` the first time the
+    // instruction executes for the type arguments of the frame.
+    fn callee(&self, resolve: impl Fn() -> PartialVMResult<Callee>) -> PartialVMResult<Callee> {
+        self.cache.callee(&self.function, self.pc, resolve)
+    }
+
+    // The type operand of the current instruction, instantiated with `resolve` the first time the
+    // instruction executes for the type arguments of the frame.
+    fn operand_ty(
+        &self,
+        resolve: impl FnOnce(&[Type]) -> PartialVMResult<Type>,
+    ) -> PartialVMResult<&Type> {
+        self.cache
+            .ty(&self.function, self.pc, || resolve(self.ty_args()))
+    }
+
+    // The struct type operand of the current global storage instruction and its layout, both
+    // computed the first time the instruction executes for the type arguments of the frame.
+    fn operand_resource(
+        &self,
+        loader: &Loader,
+        resolve: impl FnOnce(&[Type]) -> PartialVMResult<Type>,
+    ) -> PartialVMResult<(&Type, &MoveTypeLayout)> {
+        self.cache.ty_with_layout(
+            &self.function,
+            self.pc,
+            || resolve(self.ty_args()),
+            // TODO(Gas): Shall we charge for this?
+            |ty| loader.type_to_type_layout(ty),
+        )
     }
 
     fn resolver<'a>(&self, loader: &'a Loader) -> Resolver<'a> {
@@ -10,21 +10,27 @@
 //! other blockchains can use it as well. The VM isn't there yet, but hopefully will be there
 //! soon.
 
+pub mod config;
 pub mod data_cache;
+mod instruction_cache;
 mod interpreter;
 mod loader;
 pub mod logging;
+pub mod module_cache;
 pub mod move_vm;
 pub mod native_extensions;
 pub mod native_functions;
 mod runtime;
 pub mod session;
+pub mod statistics;
 #[macro_use]
 mod tracing;
 
 // Only include debugging functionality in debug builds
 #[cfg(any(debug_assertions, feature = "debugging"))]
 mod debug;
+#[cfg(any(debug_assertions, feature = "debugging"))]
+pub mod debugger;
 
 #[cfg(test)]
 mod unit_tests;
@@ -3,7 +3,10 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
+    config::VMConfig,
+    instruction_cache::{InstructionCache, OperandSlots},
     logging::expect_no_verification_errors,
+    module_cache::{CacheCounters, CacheMetrics, ModuleHash, SharedModuleCache},
     native_functions::{NativeFunction, NativeFunctions, UnboxedNativeFunction},
     session::LoadedFunctionInstantiation,
 };
@@ -20,7 +23,7 @@ use move_binary_format::{
     },
     IndexKind,
 };
-use move_bytecode_verifier::{self, cyclic_dependencies, dependencies, VerifierConfig};
+use move_bytecode_verifier::{self, cyclic_dependencies, dependencies};
 use move_core_types::{
     identifier::{IdentStr, Identifier},
     language_storage::{ModuleId, StructTag, TypeTag},
@@ -38,7 +41,10 @@ use std::{
     collections::{BTreeMap, BTreeSet, HashMap},
     fmt::Debug,
     hash::Hash,
-    sync::Arc,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
 };
 use tracing::error;
 
@@ -128,6 +134,8 @@ pub struct ModuleCache {
     modules: BinaryCache<ModuleId, Module>,
     structs: Vec<Arc<StructType>>,
     functions: Vec<Arc<Function>>,
+    // total size in bytes of the serialized modules in the cache
+    size: usize,
 }
 
 impl ModuleCache {
@@ -136,6 +144,7 @@ impl ModuleCache {
             modules: BinaryCache::new(),
             structs: vec![],
             functions: vec![],
+            size: 0,
         }
     }
 
@@ -168,7 +177,8 @@ impl ModuleCache {
         &mut self,
         natives: &NativeFunctions,
         id: ModuleId,
-        module: CompiledModule,
+        module: Arc<CompiledModule>,
+        size: usize,
     ) -> VMResult<Arc<Module>> {
         if let Some(cached) = self.module_at(&id) {
             return Ok(cached);
@@ -177,13 +187,16 @@ impl ModuleCache {
         // we need this operation to be transactional, if an error occurs we must
         // leave a clean state
         self.add_module(natives, &module)?;
-        match Module::new(module, self) {
-            Ok(module) => Ok(Arc::clone(self.modules.insert(id, module))),
-            Err((err, module)) => {
+        let strut_def_count = module.struct_defs().len();
+        let function_count = module.function_defs().len();
+        match Module::new(module, size, self) {
+            Ok(module) => {
+                self.size += size;
+                Ok(Arc::clone(self.modules.insert(id, module)))
+            }
+            Err(err) => {
                 // remove all structs and functions that have been pushed
-                let strut_def_count = module.struct_defs().len();
                 self.structs.truncate(self.structs.len() - strut_def_count);
-                let function_count = module.function_defs().len();
                 self.functions
                     .truncate(self.functions.len() - function_count);
                 Err(err.finish(Location::Undefined))
@@ -483,11 +496,27 @@ pub(crate) struct Loader {
     // other transactions.
     module_cache_hits: RwLock<BTreeSet<ModuleId>>,
 
-    verifier_config: VerifierConfig,
+    // Modules which must be evicted from the cache on the next flush, together with every
+    // module depending on them. Unlike `invalidated`, this leaves the rest of the cache intact.
+    invalidated_modules: RwLock<BTreeSet<ModuleId>>,
+
+    // Verified modules, possibly shared with other loaders.
+    shared_cache: Option<SharedModuleCache>,
+
+    // Logical clock used to record when a module was last used.
+    clock: AtomicU64,
+
+    metrics: CacheCounters,
+
+    vm_config: VMConfig,
 }
 
 impl Loader {
-    pub(crate) fn new(natives: NativeFunctions, verifier_config: VerifierConfig) -> Self {
+    pub(crate) fn new(
+        natives: NativeFunctions,
+        vm_config: VMConfig,
+        shared_cache: Option<SharedModuleCache>,
+    ) -> Self {
         Self {
             scripts: RwLock::new(ScriptCache::new()),
             module_cache: RwLock::new(ModuleCache::new()),
@@ -495,10 +524,18 @@ impl Loader {
             natives,
             invalidated: RwLock::new(false),
             module_cache_hits: RwLock::new(BTreeSet::new()),
-            verifier_config,
+            invalidated_modules: RwLock::new(BTreeSet::new()),
+            shared_cache,
+            clock: AtomicU64::new(0),
+            metrics: CacheCounters::default(),
+            vm_config,
         }
     }
 
+    pub(crate) fn vm_config(&self) -> &VMConfig {
+        &self.vm_config
+    }
+
     /// Gets and clears module cache hits. A cache hit may also be caused indirectly by
     /// loading a function or a type. This not only returns the direct hit, but also
     /// indirect ones, that is all dependencies.
@@ -515,28 +552,47 @@ impl Loader {
         if !visited.insert(id.clone()) {
             return;
         }
-        let deps = self
-            .module_cache
-            .read()
-            .modules
-            .get(id)
-            .unwrap()
-            .module
-            .immediate_dependencies();
+        // the module may have been evicted since it was hit, in which case its dependencies
+        // are not known anymore
+        let deps = match self.module_cache.read().modules.get(id) {
+            Some(module) => module.module.immediate_dependencies(),
+            None => return,
+        };
         for dep in deps {
             self.transitive_dep_closure(&dep, visited)
         }
     }
 
-    /// Flush this cache if it is marked as invalidated.
+    /// Flush this cache if it is marked as invalidated. If only some modules were invalidated,
+    /// or if the cache exceeds its configured capacity, only the invalidated and least recently
+    /// used modules are evicted.
     pub(crate) fn flush_if_invalidated(&self) {
         let mut invalidated = self.invalidated.write();
-        if *invalidated {
-            *self.scripts.write() = ScriptCache::new();
-            *self.module_cache.write() = ModuleCache::new();
-            *self.type_cache.write() = TypeCache::new();
-            *invalidated = false;
+        let mut invalidated_modules = self.invalidated_modules.write();
+        if !*invalidated {
+            let over_capacity = match self.vm_config.module_cache_capacity {
+                Some(capacity) => self.module_cache.read().size > capacity,
+                None => false,
+            };
+            if invalidated_modules.is_empty() && !over_capacity {
+                return;
+            }
+            // relinking the remaining modules should never fail, if it does we start over with
+            // an empty cache
+            if self.evict_modules(std::mem::take(&mut *invalidated_modules)) {
+                return;
+            }
         }
+
+        *self.scripts.write() = ScriptCache::new();
+        let flushed = std::mem::replace(&mut *self.module_cache.write(), ModuleCache::new());
+        CacheCounters::add(
+            &self.metrics.module_evictions,
+            flushed.modules.binaries.len() as u64,
+        );
+        *self.type_cache.write() = TypeCache::new();
+        invalidated_modules.clear();
+        *invalidated = false;
     }
 
     /// Mark this cache as invalidated.
@@ -544,6 +600,140 @@ impl Loader {
         *self.invalidated.write() = true;
     }
 
+    /// Mark a single module as invalidated, e.g. because it has been republished. The module and
+    /// all the modules depending on it are evicted on the next flush.
+    pub(crate) fn mark_module_as_invalid(&self, module_id: ModuleId) {
+        self.invalidated_modules.write().insert(module_id);
+    }
+
+    pub(crate) fn cache_metrics(&self) -> CacheMetrics {
+        let module_cache = self.module_cache.read();
+        self.metrics.snapshot(
+            module_cache.modules.binaries.len() as u64,
+            module_cache.size as u64,
+        )
+    }
+
+    // Evict the given modules, their dependents and, if the cache is over capacity, the least
+    // recently used modules. Structs and functions are addressed by their position in the
+    // global tables, so the cache is rebuilt by relinking the remaining modules (which does not
+    // verify them again). Returns false if relinking failed.
+    //
+    // Must only be called when no session derived from this loader is alive.
+    fn evict_modules(&self, mut evicted: BTreeSet<ModuleId>) -> bool {
+        let mut scripts = self.scripts.write();
+        let mut module_cache = self.module_cache.write();
+        let mut type_cache = self.type_cache.write();
+
+        // modules are cached in insertion order, dependencies first
+        let modules = &module_cache.modules.binaries;
+        Self::add_dependents(modules, &mut evicted);
+
+        if let Some(capacity) = self.vm_config.module_cache_capacity {
+            // a module is considered used whenever one of its dependents is used
+            let mut last_used: HashMap<&ModuleId, u64> = modules
+                .iter()
+                .map(|module| (&module.id, module.last_used.load(Ordering::Relaxed)))
+                .collect();
+            for module in modules.iter().rev() {
+                let used = last_used[&module.id];
+                for dep in module.module.immediate_dependencies() {
+                    if let Some(dep_used) = last_used.get_mut(&dep) {
+                        *dep_used = (*dep_used).max(used);
+                    }
+                }
+            }
+            let mut candidates: Vec<_> = last_used
+                .into_iter()
+                .map(|(id, used)| (used, id.clone()))
+                .collect();
+            candidates.sort();
+
+            let retained_size = |evicted: &BTreeSet<ModuleId>| -> usize {
+                modules
+                    .iter()
+                    .filter(|module| !evicted.contains(&module.id))
+                    .map(|module| module.size)
+                    .sum()
+            };
+            let mut size = retained_size(&evicted);
+            for (_, id) in candidates {
+                if size <= capacity {
+                    break;
+                }
+                if evicted.insert(id) {
+                    Self::add_dependents(modules, &mut evicted);
+                    size = retained_size(&evicted);
+                }
+            }
+        }
+
+        let old_cache = std::mem::replace(&mut *module_cache, ModuleCache::new());
+        let mut evicted_count = 0;
+        for module in old_cache.modules.binaries {
+            if evicted.contains(&module.id) {
+                evicted_count += 1;
+                continue;
+            }
+            match module_cache.insert(
+                &self.natives,
+                module.id.clone(),
+                module.arc_module(),
+                module.size,
+            ) {
+                Ok(relinked) => relinked
+                    .last_used
+                    .store(module.last_used.load(Ordering::Relaxed), Ordering::Relaxed),
+                Err(err) => {
+                    error!("[VM] failed to relink module {:?}: {:?}", module.id, err);
+                    return false;
+                }
+            }
+        }
+        CacheCounters::add(&self.metrics.module_evictions, evicted_count);
+
+        // keep the scripts whose dependencies are all still cached
+        let old_scripts = std::mem::replace(&mut *scripts, ScriptCache::new());
+        for (hash, idx) in old_scripts.scripts.id_map {
+            let script = &old_scripts.scripts.binaries[idx].script;
+            if script
+                .immediate_dependencies()
+                .iter()
+                .all(|id| module_cache.has_module(id))
+            {
+                if let Ok(script) = Script::new(script.clone(), &hash, &module_cache) {
+                    scripts.insert(hash, script);
+                }
+            }
+        }
+
+        // cached type information is keyed by struct index
+        *type_cache = TypeCache::new();
+        true
+    }
+
+    // Add to `evicted` all modules depending, directly or transitively, on an evicted module.
+    fn add_dependents(modules: &[Arc<Module>], evicted: &mut BTreeSet<ModuleId>) {
+        // dependencies are always cached before their dependents, so a single pass suffices
+        for module in modules {
+            if !evicted.contains(&module.id)
+                && module
+                    .module
+                    .immediate_dependencies()
+                    .iter()
+                    .any(|dep| evicted.contains(dep))
+            {
+                evicted.insert(module.id.clone());
+            }
+        }
+    }
+
+    // Record that the module has just been used.
+    fn touch(&self, module: &Module) {
+        let now = self.clock.fetch_add(1, Ordering::Relaxed) + 1;
+        module.last_used.store(now, Ordering::Relaxed);
+    }
+
     /// Copies metadata out of a modules bytecode if available.
     pub(crate) fn get_metadata(&self, module: ModuleId, key: &[u8]) -> Option<Metadata> {
         let cache = self.module_cache.read();
@@ -579,8 +769,12 @@ impl Loader {
 
         let mut scripts = self.scripts.write();
         let (main, parameters, return_) = match scripts.get(&hash_value) {
-            Some(cached) => cached,
+            Some(cached) => {
+                CacheCounters::inc(&self.metrics.script_hits);
+                cached
+            }
             None => {
+                CacheCounters::inc(&self.metrics.script_misses);
                 let ver_script = self.deserialize_and_verify_script(script_blob, data_store)?;
                 let script = Script::new(ver_script, &hash_value, &self.module_cache.read())?;
                 scripts.insert(hash_value, script)
@@ -646,7 +840,7 @@ impl Loader {
     // Script verification steps.
     // See `verify_module()` for module verification steps.
     fn verify_script(&self, script: &CompiledScript) -> VMResult<()> {
-        move_bytecode_verifier::verify_script_with_config(&self.verifier_config, script)
+        move_bytecode_verifier::verify_script_with_config(&self.vm_config.verifier, script)
     }
 
     fn verify_script_dependencies(
@@ -771,7 +965,7 @@ impl Loader {
         // module will NOT show up in `module_cache`. In the module republishing case, it means
         // that the old module is still in the `module_cache`, unless a new Loader is created,
         // which means that a new MoveVM instance needs to be created.
-        move_bytecode_verifier::verify_module_with_config(&self.verifier_config, module)?;
+        move_bytecode_verifier::verify_module_with_config(&self.vm_config.verifier, module)?;
         self.check_natives(module)?;
 
         let mut visited = BTreeSet::new();
@@ -949,6 +1143,8 @@ impl Loader {
         // if the module is already in the code cache, load the cached version
         if let Some(cached) = self.module_cache.read().module_at(id) {
             self.module_cache_hits.write().insert(id.clone());
+            CacheCounters::inc(&self.metrics.module_hits);
+            self.touch(&cached);
             return Ok(cached);
         }
 
@@ -971,13 +1167,14 @@ impl Loader {
         Ok(module_ref)
     }
 
-    // Load, deserialize, and check the module with the bytecode verifier, without linking
+    // Load, deserialize, and check the module with the bytecode verifier, without linking.
+    // Returns the module together with its size in bytes.
     fn load_and_verify_module(
         &self,
         id: &ModuleId,
         data_store: &impl DataStore,
         allow_loading_failure: bool,
-    ) -> VMResult<CompiledModule> {
+    ) -> VMResult<(Arc<CompiledModule>, usize)> {
         // bytes fetching, allow loading to fail if the flag is set
         let bytes = match data_store.load_module(id) {
             Ok(bytes) => bytes,
@@ -987,6 +1184,24 @@ impl Loader {
                 return Err(expect_no_verification_errors(err));
             }
         };
+        CacheCounters::inc(&self.metrics.module_misses);
+        let size = bytes.len();
+
+        // a module verified by a loader sharing the cache does not need to be verified again
+        let hash_value: Option<ModuleHash> = self.shared_cache.as_ref().map(|_| {
+            let mut sha3_256 = Sha3_256::new();
+            sha3_256.update(&bytes);
+            sha3_256.finalize().into()
+        });
+        if let (Some(shared_cache), Some(hash_value)) = (&self.shared_cache, &hash_value) {
+            if let Some(module) = shared_cache.get(hash_value) {
+                CacheCounters::inc(&self.metrics.shared_module_hits);
+                self.check_natives(&module)
+                    .map_err(expect_no_verification_errors)?;
+                return Ok((module, size));
+            }
+            CacheCounters::inc(&self.metrics.shared_module_misses);
+        }
 
         // for bytes obtained from the data store, they should always deserialize and verify.
         // It is an invariant violation if they don't.
@@ -1000,11 +1215,15 @@ impl Loader {
             .map_err(expect_no_verification_errors)?;
 
         // bytecode verifier checks that can be performed with the module itself
-        move_bytecode_verifier::verify_module_with_config(&self.verifier_config, &module)
+        move_bytecode_verifier::verify_module_with_config(&self.vm_config.verifier, &module)
             .map_err(expect_no_verification_errors)?;
+        let module = Arc::new(module);
+        if let (Some(shared_cache), Some(hash_value)) = (&self.shared_cache, hash_value) {
+            shared_cache.insert(hash_value, Arc::clone(&module), size);
+        }
         self.check_natives(&module)
             .map_err(expect_no_verification_errors)?;
-        Ok(module)
+        Ok((module, size))
     }
 
     // Everything in `load_and_verify_module` and also recursively load and verify all the
@@ -1025,7 +1244,8 @@ impl Loader {
         }
 
         // module self-check
-        let module = self.load_and_verify_module(id, data_store, allow_module_loading_failure)?;
+        let (module, size) =
+            self.load_and_verify_module(id, data_store, allow_module_loading_failure)?;
         visited.insert(id.clone());
         friends_discovered.extend(module.immediate_friends());
 
@@ -1042,8 +1262,9 @@ impl Loader {
 
         // if linking goes well, insert the module to the code cache
         let mut locked_cache = self.module_cache.write();
-        let module_ref = locked_cache.insert(&self.natives, id.clone(), module)?;
+        let module_ref = locked_cache.insert(&self.natives, id.clone(), module, size)?;
         drop(locked_cache); // explicit unlock
+        self.touch(&module_ref);
 
         Ok(module_ref)
     }
@@ -1081,7 +1302,10 @@ impl Loader {
                             allow_dependency_loading_failure,
                         )?
                     }
-                    Some(cached) => cached,
+                    Some(cached) => {
+                        self.touch(&cached);
+                        cached
+                    }
                 };
                 cached_deps.push(loaded);
             }
@@ -1237,6 +1461,38 @@ impl Loader {
         self.module_cache.read().structs.get(idx.0).map(Arc::clone)
     }
 
+    // The types of the parameters and locals of `function`, instantiated with `ty_args`.
+    #[cfg(any(debug_assertions, feature = "debugging"))]
+    pub(crate) fn local_types(
+        &self,
+        function: &Function,
+        ty_args: &[Type],
+    ) -> PartialVMResult<Vec<Type>> {
+        let make_types = |view: BinaryIndexedView| {
+            let module_cache = self.module_cache.read();
+            function
+                .locals
+                .0
+                .iter()
+                .map(|tok| {
+                    module_cache
+                        .make_type(view, tok)?
+                        .subst_with_max_depth(ty_args, self.vm_config.max_type_depth)
+                })
+                .collect()
+        };
+        match &function.scope {
+            Scope::Module(module_id) => {
+                let module = self.get_module(module_id);
+                make_types(BinaryIndexedView::Module(module.module()))
+            }
+            Scope::Script(hash) => {
+                let script = self.get_script(hash);
+                make_types(BinaryIndexedView::Script(&script.script))
+            }
+        }
+    }
+
     pub(crate) fn abilities(&self, ty: &Type) -> PartialVMResult<AbilitySet> {
         match ty {
             Type::Bool | Type::U8 | Type::U64 | Type::U128 | Type::Address => {
@@ -1351,7 +1607,7 @@ impl<'a> Resolver<'a> {
         };
         let mut instantiation = vec![];
         for ty in &func_inst.instantiation {
-            instantiation.push(ty.subst(type_params)?);
+            instantiation.push(self.subst(ty, type_params)?);
         }
         Ok(instantiation)
     }
@@ -1391,7 +1647,7 @@ impl<'a> Resolver<'a> {
             struct_inst
                 .instantiation
                 .iter()
-                .map(|ty| ty.subst(ty_args))
+                .map(|ty| self.subst(ty, ty_args))
                 .collect::<PartialVMResult<_>>()?,
         ))
     }
@@ -1409,7 +1665,11 @@ impl<'a> Resolver<'a> {
         ty_args: &[Type],
     ) -> PartialVMResult<Type> {
         let ty = self.single_type_at(idx);
-        ty.subst(ty_args)
+        self.subst(ty, ty_args)
+    }
+
+    fn subst(&self, ty: &Type, ty_args: &[Type]) -> PartialVMResult<Type> {
+        ty.subst_with_max_depth(ty_args, self.loader.vm_config.max_type_depth)
     }
 
     //
@@ -1467,10 +1727,13 @@ impl<'a> Resolver<'a> {
 // so that any data needed for execution is immediately available
 #[derive(Debug)]
 pub(crate) struct Module {
-    #[allow(dead_code)]
     id: ModuleId,
     // primitive pools
     module: Arc<CompiledModule>,
+    // size in bytes of the serialized module
+    size: usize,
+    // value of the loader clock when the module was last used, drives LRU eviction
+    last_used: AtomicU64,
 
     //
     // types as indexes into the Loader type list
@@ -1515,10 +1778,7 @@ pub(crate) struct Module {
 }
 
 impl Module {
-    fn new(
-        module: CompiledModule,
-        cache: &ModuleCache,
-    ) -> Result<Self, (PartialVMError, CompiledModule)> {
+    fn new(module: Arc<CompiledModule>, size: usize, cache: &ModuleCache) -> PartialVMResult<Self> {
         let id = module.self_id();
 
         let mut struct_refs = vec![];
@@ -1680,23 +1940,23 @@ impl Module {
             Ok(())
         };
 
-        match create() {
-            Ok(_) => Ok(Self {
-                id,
-                module: Arc::new(module),
-                struct_refs,
-                structs,
-                struct_instantiations,
-                function_refs,
-                function_instantiations,
-                field_handles,
-                field_instantiations,
-                function_map,
-                struct_map,
-                single_signature_token_map,
-            }),
-            Err(err) => Err((err, module)),
-        }
+        create()?;
+        Ok(Self {
+            id,
+            module,
+            size,
+            last_used: AtomicU64::new(0),
+            struct_refs,
+            structs,
+            struct_instantiations,
+            function_refs,
+            function_instantiations,
+            field_handles,
+            field_instantiations,
+            function_map,
+            struct_map,
+            single_signature_token_map,
+        })
     }
 
     fn struct_at(&self, idx: StructDefinitionIndex) -> CachedStructIndex {
@@ -1859,6 +2119,7 @@ impl Script {
         // TODO: main does not have a name. Revisit.
         let name = Identifier::new("main").unwrap();
         let (native, def_is_native) = (None, false); // Script entries cannot be native
+        let operand_slots = OperandSlots::new(&code);
         let main: Arc<Function> = Arc::new(Function {
             file_format_version: script.version(),
             index: FunctionDefinitionIndex(0),
@@ -1871,6 +2132,8 @@ impl Script {
             def_is_native,
             scope,
             name,
+            operand_slots,
+            instantiations: RwLock::new(HashMap::new()),
         });
 
         let mut single_signature_token_map = BTreeMap::new();
@@ -1963,6 +2226,9 @@ pub(crate) struct Function {
     def_is_native: bool,
     scope: Scope,
     name: Identifier,
+    operand_slots: OperandSlots,
+    // operands resolved for each instantiation of the function
+    instantiations: RwLock<HashMap<Vec<Type>, Arc<InstructionCache>>>,
 }
 
 impl Function {
@@ -2006,6 +2272,7 @@ impl Function {
         };
         let return_ = module.signature_at(handle.return_).clone();
         let type_parameters = handle.type_parameters.clone();
+        let operand_slots = OperandSlots::new(&code);
         Self {
             file_format_version: module.version(),
             index,
@@ -2018,6 +2285,8 @@ impl Function {
             def_is_native,
             scope,
             name,
+            operand_slots,
+            instantiations: RwLock::new(HashMap::new()),
         }
     }
 
@@ -2074,6 +2343,33 @@ impl Function {
         &self.type_parameters
     }
 
+    pub(crate) fn operand_slots(&self) -> &OperandSlots {
+        &self.operand_slots
+    }
+
+    /// The cache of the operands of the instantiation of this function with `ty_args`.
+    ///
+    /// At most `max_instantiations` caches are kept by the function. Past that limit, a new cache
+    /// is returned for every call, which only lives as long as the frames using it.
+    pub(crate) fn instruction_cache(
+        &self,
+        ty_args: Vec<Type>,
+        max_instantiations: usize,
+    ) -> Arc<InstructionCache> {
+        if let Some(cache) = self.instantiations.read().get(&ty_args) {
+            return Arc::clone(cache);
+        }
+        let mut instantiations = self.instantiations.write();
+        if let Some(cache) = instantiations.get(&ty_args) {
+            return Arc::clone(cache);
+        }
+        let cache = Arc::new(InstructionCache::new(&self.operand_slots, ty_args.clone()));
+        if instantiations.len() < max_instantiations {
+            instantiations.insert(ty_args, Arc::clone(&cache));
+        }
+        cache
+    }
+
     #[allow(dead_code)]
     pub(crate) fn parameters(&self) -> &Signature {
         &self.parameters
@@ -2195,8 +2491,6 @@ impl TypeCache {
     }
 }
 
-const VALUE_DEPTH_MAX: usize = 128;
-
 impl Loader {
     fn struct_gidx_to_type_tag(
         &self,
@@ -2275,7 +2569,7 @@ impl Loader {
         let field_tys = struct_type
             .fields
             .iter()
-            .map(|ty| ty.subst(ty_args))
+            .map(|ty| ty.subst_with_max_depth(ty_args, self.vm_config.max_type_depth))
             .collect::<PartialVMResult<Vec<_>>>()?;
         let field_layouts = field_tys
             .iter()
@@ -2296,7 +2590,7 @@ impl Loader {
     }
 
     fn type_to_type_layout_impl(&self, ty: &Type, depth: usize) -> PartialVMResult<MoveTypeLayout> {
-        if depth > VALUE_DEPTH_MAX {
+        if depth > self.vm_config.max_value_nest_depth {
             return Err(PartialVMError::new(StatusCode::VM_MAX_VALUE_DEPTH_REACHED));
         }
         Ok(match ty {
@@ -2353,7 +2647,7 @@ impl Loader {
             .iter()
             .zip(&struct_type.fields)
             .map(|(n, ty)| {
-                let ty = ty.subst(ty_args)?;
+                let ty = ty.subst_with_max_depth(ty_args, self.vm_config.max_type_depth)?;
                 let l = self.type_to_fully_annotated_layout_impl(&ty, depth + 1)?;
                 Ok(MoveFieldLayout::new(n.clone(), l))
             })
@@ -2377,7 +2671,7 @@ impl Loader {
         ty: &Type,
         depth: usize,
     ) -> PartialVMResult<MoveTypeLayout> {
-        if depth > VALUE_DEPTH_MAX {
+        if depth > self.vm_config.max_value_nest_depth {
             return Err(PartialVMError::new(StatusCode::VM_MAX_VALUE_DEPTH_REACHED));
         }
         Ok(match ty {
@@ -0,0 +1,197 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Caching infrastructure shared between the loader and its embedders.
+//!
+//! The loader keeps its own cache of linked modules which is private to a `MoveVM`. Deserializing
+//! and verifying a module is by far the most expensive part of loading it though, and the result
+//! of that step only depends on the module bytes. A `SharedModuleCache` holds verified modules
+//! keyed by the hash of their bytes so it can be handed to several `MoveVM` instances.
+
+use move_binary_format::CompiledModule;
+use parking_lot::Mutex;
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+
+pub(crate) type ModuleHash = [u8; 32];
+
+struct VerifiedModule {
+    module: Arc<CompiledModule>,
+    size: usize,
+    last_used: u64,
+}
+
+struct SharedModuleCacheInner {
+    modules: HashMap<ModuleHash, VerifiedModule>,
+    capacity: Option<usize>,
+    size: usize,
+    tick: u64,
+}
+
+/// A cache of deserialized and verified modules which can be shared across `MoveVM` instances.
+///
+/// Modules are keyed by the hash of their serialized form, so a republished module never hits
+/// a stale entry. When the total size of the cached bytes exceeds the capacity, the least
+/// recently used modules are evicted.
+///
+/// All VMs sharing a cache must use the same `VerifierConfig`, as a module found in the cache is
+/// not verified again.
+#[derive(Clone)]
+pub struct SharedModuleCache {
+    inner: Arc<Mutex<SharedModuleCacheInner>>,
+}
+
+impl SharedModuleCache {
+    /// Create a cache holding at most `capacity` bytes of modules, or an unbounded one if
+    /// `capacity` is `None`.
+    pub fn new(capacity: Option<usize>) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(SharedModuleCacheInner {
+                modules: HashMap::new(),
+                capacity,
+                size: 0,
+                tick: 0,
+            })),
+        }
+    }
+
+    /// Number of modules in the cache.
+    pub fn len(&self) -> usize {
+        self.inner.lock().modules.len()
+    }
+
+    /// Whether the cache holds no module.
+    pub fn is_empty(&self) -> bool {
+        self.inner.lock().modules.is_empty()
+    }
+
+    /// Total size in bytes of the serialized modules in the cache.
+    pub fn size(&self) -> usize {
+        self.inner.lock().size
+    }
+
+    /// Remove all modules from the cache.
+    pub fn clear(&self) {
+        let mut inner = self.inner.lock();
+        inner.modules.clear();
+        inner.size = 0;
+    }
+
+    pub(crate) fn get(&self, hash: &ModuleHash) -> Option<Arc<CompiledModule>> {
+        let mut inner = self.inner.lock();
+        inner.tick += 1;
+        let tick = inner.tick;
+        inner.modules.get_mut(hash).map(|entry| {
+            entry.last_used = tick;
+            Arc::clone(&entry.module)
+        })
+    }
+
+    pub(crate) fn insert(&self, hash: ModuleHash, module: Arc<CompiledModule>, size: usize) {
+        let mut inner = self.inner.lock();
+        if inner.modules.contains_key(&hash) {
+            return;
+        }
+        inner.tick += 1;
+        let last_used = inner.tick;
+        inner.modules.insert(
+            hash,
+            VerifiedModule {
+                module,
+                size,
+                last_used,
+            },
+        );
+        inner.size += size;
+        inner.evict();
+    }
+}
+
+impl SharedModuleCacheInner {
+    // Evict least recently used modules until the cache fits in its capacity. Evicting from this
+    // cache is always safe, the loader keeps its own reference to the modules it linked.
+    fn evict(&mut self) {
+        let capacity = match self.capacity {
+            Some(capacity) if self.size > capacity => capacity,
+            _ => return,
+        };
+        let mut entries: Vec<_> = self
+            .modules
+            .iter()
+            .map(|(hash, entry)| (entry.last_used, *hash))
+            .collect();
+        entries.sort_unstable();
+        for (_, hash) in entries {
+            if self.size <= capacity {
+                break;
+            }
+            if let Some(entry) = self.modules.remove(&hash) {
+                self.size -= entry.size;
+            }
+        }
+    }
+}
+
+/// A snapshot of the loader cache counters, see `MoveVM::cache_metrics`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CacheMetrics {
+    /// Module loads served from the loader cache.
+    pub module_hits: u64,
+    /// Module loads which had to fetch the module from storage.
+    pub module_misses: u64,
+    /// Modules removed from the loader cache, either because they were invalidated or to stay
+    /// within the configured capacity.
+    pub module_evictions: u64,
+    /// Script loads served from the loader cache.
+    pub script_hits: u64,
+    /// Script loads which had to deserialize and verify the script.
+    pub script_misses: u64,
+    /// Module fetches from storage which found the verified module in the shared cache.
+    pub shared_module_hits: u64,
+    /// Module fetches from storage which had to verify the module.
+    pub shared_module_misses: u64,
+    /// Number of modules currently in the loader cache.
+    pub cached_modules: u64,
+    /// Total size in bytes of the modules currently in the loader cache.
+    pub cached_module_bytes: u64,
+}
+
+#[derive(Default)]
+pub(crate) struct CacheCounters {
+    pub(crate) module_hits: AtomicU64,
+    pub(crate) module_misses: AtomicU64,
+    pub(crate) module_evictions: AtomicU64,
+    pub(crate) script_hits: AtomicU64,
+    pub(crate) script_misses: AtomicU64,
+    pub(crate) shared_module_hits: AtomicU64,
+    pub(crate) shared_module_misses: AtomicU64,
+}
+
+impl CacheCounters {
+    pub(crate) fn inc(counter: &AtomicU64) {
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn add(counter: &AtomicU64, n: u64) {
+        counter.fetch_add(n, Ordering::Relaxed);
+    }
+
+    pub(crate) fn snapshot(&self, cached_modules: u64, cached_module_bytes: u64) -> CacheMetrics {
+        CacheMetrics {
+            module_hits: self.module_hits.load(Ordering::Relaxed),
+            module_misses: self.module_misses.load(Ordering::Relaxed),
+            module_evictions: self.module_evictions.load(Ordering::Relaxed),
+            script_hits: self.script_hits.load(Ordering::Relaxed),
+            script_misses: self.script_misses.load(Ordering::Relaxed),
+            shared_module_hits: self.shared_module_hits.load(Ordering::Relaxed),
+            shared_module_misses: self.shared_module_misses.load(Ordering::Relaxed),
+            cached_modules,
+            cached_module_bytes,
+        }
+    }
+}
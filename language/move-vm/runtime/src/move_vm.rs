@@ -5,8 +5,13 @@
 use std::{collections::BTreeSet, sync::Arc};
 
 use crate::{
-    data_cache::TransactionDataCache, native_extensions::NativeContextExtensions,
-    native_functions::NativeFunction, runtime::VMRuntime, session::Session,
+    config::VMConfig,
+    data_cache::TransactionDataCache,
+    module_cache::{CacheMetrics, SharedModuleCache},
+    native_extensions::NativeContextExtensions,
+    native_functions::NativeFunction,
+    runtime::VMRuntime,
+    session::Session,
 };
 use move_binary_format::{
     errors::{Location, VMResult},
@@ -26,15 +31,44 @@ impl MoveVM {
     pub fn new(
         natives: impl IntoIterator<Item = (AccountAddress, Identifier, Identifier, NativeFunction)>,
     ) -> VMResult<Self> {
-        Self::new_with_verifier_config(natives, VerifierConfig::default())
+        Self::new_with_config(natives, VMConfig::default())
     }
 
     pub fn new_with_verifier_config(
         natives: impl IntoIterator<Item = (AccountAddress, Identifier, Identifier, NativeFunction)>,
         verifier_config: VerifierConfig,
+    ) -> VMResult<Self> {
+        Self::new_with_config(
+            natives,
+            VMConfig {
+                verifier: verifier_config,
+                ..VMConfig::default()
+            },
+        )
+    }
+
+    /// Create a new VM enforcing the limits in `vm_config`, which covers the verifier
+    /// configuration as well as runtime limits such as stack sizes and nesting depths.
+    pub fn new_with_config(
+        natives: impl IntoIterator<Item = (AccountAddress, Identifier, Identifier, NativeFunction)>,
+        vm_config: VMConfig,
     ) -> VMResult<Self> {
         Ok(Self {
-            runtime: VMRuntime::new(natives, verifier_config)
+            runtime: VMRuntime::new(natives, vm_config, None)
+                .map_err(|err| err.finish(Location::Undefined))?,
+        })
+    }
+
+    /// Create a new VM, as in `new_with_config`, which looks up verified modules in
+    /// `shared_cache` before verifying them. The same cache can be given to several VMs, as
+    /// long as they all use the same verifier configuration.
+    pub fn new_with_shared_cache(
+        natives: impl IntoIterator<Item = (AccountAddress, Identifier, Identifier, NativeFunction)>,
+        vm_config: VMConfig,
+        shared_cache: SharedModuleCache,
+    ) -> VMResult<Self> {
+        Ok(Self {
+            runtime: VMRuntime::new(natives, vm_config, Some(shared_cache))
                 .map_err(|err| err.finish(Location::Undefined))?,
         })
     }
@@ -90,14 +124,29 @@ impl MoveVM {
         self.runtime.loader().mark_as_invalid()
     }
 
-    /// If the loader cache has been invalidated (either by the above call or by internal logic)
-    /// flush it so it is valid again. Notice that should only be called if there are no
-    /// outstanding sessions created from this VM.
+    /// Like `mark_loader_cache_as_invalid`, but only for the given module. The module, and all
+    /// cached modules depending on it, are evicted on the next flush while the rest of the cache
+    /// is kept. The VM does this itself when a module is republished.
+    pub fn mark_module_as_invalid(&self, module_id: &ModuleId) {
+        self.runtime
+            .loader()
+            .mark_module_as_invalid(module_id.clone())
+    }
+
+    /// If the loader cache has been invalidated (either by the above calls or by internal logic)
+    /// flush it so it is valid again. This also evicts the least recently used modules if the
+    /// cache exceeds `VMConfig::module_cache_capacity`. Notice that should only be called if there
+    /// are no outstanding sessions created from this VM.
     /// TODO: new loader architecture
     pub fn flush_loader_cache_if_invalidated(&self) {
         self.runtime.loader().flush_if_invalidated()
     }
 
+    /// Returns the hit, miss and eviction counters of the loader cache since this VM was created.
+    pub fn cache_metrics(&self) -> CacheMetrics {
+        self.runtime.loader().cache_metrics()
+    }
+
     /// Gets and clears module cache hits. This is hack which allows the adapter to see module
     /// reads if executing multiple transactions in a VM. Without this, the adapter only sees
     /// the first load of a module.
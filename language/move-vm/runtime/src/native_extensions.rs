@@ -44,6 +44,14 @@ impl<'a> NativeContextExtensions<'a> {
             .unwrap()
     }
 
+    /// Like `get_mut`, but returns `None` instead of panicking if there is no extension of type
+    /// `T`, for natives which must not assume that the embedder registered it.
+    pub fn try_get_mut<T: TidAble<'a>>(&mut self) -> Option<&mut T> {
+        self.map
+            .get_mut(&T::id())
+            .and_then(|ext| ext.as_mut().downcast_mut::<T>())
+    }
+
     pub fn remove<T: TidAble<'a>>(&mut self) -> T {
         // can't use expect below because it requires `T: Debug`.
         match self
@@ -5,11 +5,14 @@
 use crate::{
     interpreter::Interpreter, loader::Resolver, native_extensions::NativeContextExtensions,
 };
-use move_binary_format::errors::{ExecutionState, PartialVMError, PartialVMResult};
+use move_binary_format::{
+    errors::{ExecutionState, PartialVMError, PartialVMResult},
+    file_format::AbilitySet,
+};
 use move_core_types::{
     account_address::AccountAddress,
     identifier::Identifier,
-    language_storage::TypeTag,
+    language_storage::{ModuleId, TypeTag},
     value::MoveTypeLayout,
     vm_status::{StatusCode, StatusType},
 };
@@ -94,6 +97,7 @@ pub struct NativeContext<'a, 'b> {
     data_store: &'a mut dyn DataStore,
     resolver: &'a Resolver<'a>,
     extensions: &'a mut NativeContextExtensions<'b>,
+    caller_module: Option<&'a ModuleId>,
 }
 
 impl<'a, 'b> NativeContext<'a, 'b> {
@@ -102,12 +106,14 @@ impl<'a, 'b> NativeContext<'a, 'b> {
         data_store: &'a mut dyn DataStore,
         resolver: &'a Resolver<'a>,
         extensions: &'a mut NativeContextExtensions<'b>,
+        caller_module: Option<&'a ModuleId>,
     ) -> Self {
         Self {
             interpreter,
             data_store,
             resolver,
             extensions,
+            caller_module,
         }
     }
 }
@@ -140,6 +146,10 @@ impl<'a, 'b> NativeContext<'a, 'b> {
         self.resolver.loader().type_to_type_tag(ty)
     }
 
+    pub fn type_abilities(&self, ty: &Type) -> PartialVMResult<AbilitySet> {
+        self.resolver.loader().abilities(ty)
+    }
+
     pub fn type_to_type_layout(&self, ty: &Type) -> PartialVMResult<Option<MoveTypeLayout>> {
         match self.resolver.type_to_type_layout(ty) {
             Ok(ty_layout) => Ok(Some(ty_layout)),
@@ -167,6 +177,12 @@ impl<'a, 'b> NativeContext<'a, 'b> {
         self.extensions
     }
 
+    /// The module of the function calling the native function, or `None` if it is called by a
+    /// script.
+    pub fn caller_module(&self) -> Option<&ModuleId> {
+        self.caller_module
+    }
+
     /// Get count stack frames, including the one of the called native function. This
     /// allows a native function to reflect about its caller.
     pub fn stack_frames(&self, count: usize) -> ExecutionState {
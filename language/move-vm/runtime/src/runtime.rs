@@ -3,36 +3,49 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
+    config::{StructArgConstructor, VMConfig},
     data_cache::TransactionDataCache,
     interpreter::Interpreter,
     loader::{Function, Loader},
+    module_cache::SharedModuleCache,
     native_extensions::NativeContextExtensions,
     native_functions::{NativeFunction, NativeFunctions},
-    session::{LoadedFunctionInstantiation, SerializedReturnValues, Session},
+    session::{
+        AnnotatedReturnValues, LoadedFunctionInstantiation, SerializedReturnValues, Session,
+    },
+    statistics::ExecutionStatistics,
 };
 use move_binary_format::{
     access::ModuleAccess,
     compatibility::{Compatibility, CompatibilityConfig},
-    errors::{verification_error, Location, PartialVMError, PartialVMResult, VMResult},
+    errors::{verification_error, Location, PartialVMError, PartialVMResult, VMError, VMResult},
     file_format::LocalIndex,
-    normalized, CompiledModule, IndexKind,
+    normalized,
+    upgrade_policy::UpgradePolicy,
+    CompiledModule, IndexKind,
 };
-use move_bytecode_verifier::{script_signature, VerifierConfig};
+use move_bytecode_verifier::script_signature;
 use move_core_types::{
     account_address::AccountAddress,
+    gas_algebra::{NumBytes, NumInstructions},
     identifier::{IdentStr, Identifier},
     language_storage::{ModuleId, TypeTag},
     resolver::MoveResolver,
-    value::MoveTypeLayout,
+    value::{MoveTypeLayout, MoveValue},
     vm_status::StatusCode,
 };
 use move_vm_types::{
     data_store::DataStore,
     gas::GasMeter,
     loaded_data::runtime_types::Type,
-    values::{Locals, Reference, VMValueCast, Value},
+    values::{Locals, Reference, VMValueCast, Value, Vector},
+    views::{ValueView, ValueVisitor},
+};
+use std::{
+    borrow::{Borrow, Cow},
+    collections::BTreeSet,
+    sync::Arc,
 };
-use std::{borrow::Borrow, collections::BTreeSet, sync::Arc};
 use tracing::warn;
 
 /// An instantiation of the MoveVM.
@@ -43,10 +56,11 @@ pub(crate) struct VMRuntime {
 impl VMRuntime {
     pub(crate) fn new(
         natives: impl IntoIterator<Item = (AccountAddress, Identifier, Identifier, NativeFunction)>,
-        verifier_config: VerifierConfig,
+        vm_config: VMConfig,
+        shared_cache: Option<SharedModuleCache>,
     ) -> PartialVMResult<Self> {
         Ok(VMRuntime {
-            loader: Loader::new(NativeFunctions::new(natives)?, verifier_config),
+            loader: Loader::new(NativeFunctions::new(natives)?, vm_config, shared_cache),
         })
     }
 
@@ -63,6 +77,11 @@ impl VMRuntime {
             runtime: self,
             data_cache: TransactionDataCache::new(remote, &self.loader),
             native_extensions,
+            statistics: self
+                .loader
+                .vm_config()
+                .collect_statistics
+                .then(ExecutionStatistics::default),
         }
     }
 
@@ -71,9 +90,16 @@ impl VMRuntime {
         modules: Vec<Vec<u8>>,
         sender: AccountAddress,
         data_store: &mut impl DataStore,
-        _gas_meter: &mut impl GasMeter,
+        gas_meter: &mut impl GasMeter,
         compat_config: CompatibilityConfig,
     ) -> VMResult<()> {
+        // charge for the size of the bundle before doing any work on it
+        for blob in &modules {
+            gas_meter
+                .charge_publish_module_bytes(NumBytes::new(blob.len() as u64))
+                .map_err(|err| err.finish(Location::Undefined))?;
+        }
+
         // deserialize the modules. Perform bounds check. After this indexes can be
         // used with the `[]` operator
         let compiled_modules = match modules
@@ -105,35 +131,66 @@ impl VMRuntime {
         // Collect ids for modules that are published together
         let mut bundle_unverified = BTreeSet::new();
 
-        // For now, we assume that all modules can be republished, as long as the new module is
-        // backward compatible with the old module.
-        //
-        // TODO: in the future, we may want to add restrictions on module republishing, possibly by
-        // changing the bytecode format to include an `is_upgradable` flag in the CompiledModule.
+        // Modules can be republished as allowed by the upgrade policy of the published module.
+        // The policy of the new module cannot be less restrictive than the published one.
         for module in &compiled_modules {
             let module_id = module.self_id();
+            let policy = UpgradePolicy::from_module(module)
+                .map_err(|err| err.finish(Location::Undefined))?;
 
-            if data_store.exists_module(&module_id)? && compat_config.need_check_compat() {
+            if data_store.exists_module(&module_id)? {
                 let old_module_ref = self.loader.load_module(&module_id, data_store)?;
                 let old_module = old_module_ref.module();
-                let old_m = normalized::Module::new(old_module);
-                let new_m = normalized::Module::new(module);
-                let compat =
-                    Compatibility::check(compat_config.check_friend_linking, &old_m, &new_m);
-
-                if compat_config.check_struct_and_function_linking
-                    && !compat.struct_and_function_linking
-                {
-                    return Err(PartialVMError::new(
-                        StatusCode::BACKWARD_INCOMPATIBLE_MODULE_UPDATE,
-                    )
-                    .finish(Location::Undefined));
+                let old_policy = UpgradePolicy::from_module(old_module)
+                    .map_err(|err| err.finish(Location::Undefined))?;
+                if policy < old_policy {
+                    return Err(PartialVMError::new(StatusCode::UPGRADE_POLICY_WEAKENED)
+                        .with_message(format!(
+                            "Cannot change the upgrade policy of {} from {} to {}",
+                            module_id, old_policy, policy
+                        ))
+                        .finish(Location::Undefined));
                 }
-                if compat_config.check_struct_layout && !compat.struct_layout {
-                    return Err(PartialVMError::new(
-                        StatusCode::BACKWARD_INCOMPATIBLE_MODULE_UPDATE,
-                    )
-                    .finish(Location::Undefined));
+                match old_policy {
+                    UpgradePolicy::Immutable => {
+                        return Err(PartialVMError::new(StatusCode::IMMUTABLE_MODULE_UPDATE)
+                            .finish(Location::Undefined));
+                    }
+                    UpgradePolicy::Additive => {
+                        let old_m = normalized::Module::new(old_module);
+                        let new_m = normalized::Module::new(module);
+                        if !UpgradePolicy::is_additive_update(&old_m, &new_m) {
+                            return Err(PartialVMError::new(
+                                StatusCode::NON_ADDITIVE_MODULE_UPDATE,
+                            )
+                            .finish(Location::Undefined));
+                        }
+                    }
+                    UpgradePolicy::Compatible if compat_config.need_check_compat() => {
+                        let old_m = normalized::Module::new(old_module);
+                        let new_m = normalized::Module::new(module);
+                        let incompatibilities = Compatibility::incompatibilities(
+                            compat_config.check_friend_linking,
+                            &old_m,
+                            &new_m,
+                        )
+                        .into_iter()
+                        .filter(|incompatibility| incompatibility.is_checked_by(&compat_config))
+                        .map(|incompatibility| incompatibility.to_string())
+                        .collect::<Vec<_>>();
+                        if !incompatibilities.is_empty() {
+                            return Err(PartialVMError::new(
+                                StatusCode::BACKWARD_INCOMPATIBLE_MODULE_UPDATE,
+                            )
+                            .with_message(format!(
+                                "Incompatible update of module {}: {}",
+                                module_id,
+                                incompatibilities.join("; ")
+                            ))
+                            .finish(Location::Undefined));
+                        }
+                    }
+                    UpgradePolicy::Compatible | UpgradePolicy::Arbitrary => (),
                 }
             }
             if !bundle_unverified.insert(module_id) {
@@ -142,6 +199,35 @@ impl VMRuntime {
             }
         }
 
+        // Charge for the modules outside of the bundle which are loaded to link it, and for the
+        // verification of the bundle.
+        let external_modules: BTreeSet<_> = compiled_modules
+            .iter()
+            .flat_map(|module| {
+                module
+                    .immediate_dependencies()
+                    .into_iter()
+                    .chain(module.immediate_friends())
+            })
+            .filter(|module_id| !bundle_unverified.contains(module_id))
+            .collect();
+        for module_id in &external_modules {
+            gas_meter
+                .charge_publish_dependency(module_id)
+                .map_err(|err| err.finish(Location::Undefined))?;
+        }
+        for module in &compiled_modules {
+            let num_instructions = module
+                .function_defs()
+                .iter()
+                .filter_map(|func| func.code.as_ref())
+                .map(|code| code.code.len() as u64)
+                .sum();
+            gas_meter
+                .charge_verify_module(&module.self_id(), NumInstructions::new(num_instructions))
+                .map_err(|err| err.finish(Location::Undefined))?;
+        }
+
         // Perform bytecode and loading verification. Modules must be sorted in topological order.
         self.loader
             .verify_module_bundle_for_publication(&compiled_modules, data_store)?;
@@ -203,9 +289,9 @@ impl VMRuntime {
         for (module, blob) in compiled_modules.into_iter().zip(modules.into_iter()) {
             let is_republishing = data_store.exists_module(&module.self_id())?;
             if is_republishing {
-                // This is an upgrade, so invalidate the loader cache, which still contains the
-                // old module.
-                self.loader.mark_as_invalid();
+                // This is an upgrade, so invalidate the old module in the loader cache, together
+                // with the modules depending on it.
+                self.loader.mark_module_as_invalid(module.self_id());
             }
             data_store.publish_module(&module.self_id(), blob, is_republishing)?;
         }
@@ -224,7 +310,10 @@ impl VMRuntime {
         };
 
         match Value::simple_deserialize(arg.borrow(), &layout) {
-            Some(val) => Ok(val),
+            Some(val) => {
+                self.check_vector_lengths(&val)?;
+                Ok(val)
+            }
             None => {
                 warn!("[VM] failed to deserialize argument");
                 Err(PartialVMError::new(
@@ -234,6 +323,71 @@ impl VMRuntime {
         }
     }
 
+    // Reject arguments containing vectors longer than the configured limit.
+    fn check_vector_lengths(&self, val: &Value) -> PartialVMResult<()> {
+        struct MaxVectorLen(usize);
+
+        impl ValueVisitor for MaxVectorLen {
+            fn visit_u8(&mut self, _depth: usize, _val: u8) {}
+            fn visit_u64(&mut self, _depth: usize, _val: u64) {}
+            fn visit_u128(&mut self, _depth: usize, _val: u128) {}
+            fn visit_bool(&mut self, _depth: usize, _val: bool) {}
+            fn visit_address(&mut self, _depth: usize, _val: AccountAddress) {}
+
+            fn visit_struct(&mut self, _depth: usize, _len: usize) -> bool {
+                true
+            }
+
+            fn visit_vec(&mut self, _depth: usize, len: usize) -> bool {
+                self.0 = self.0.max(len);
+                true
+            }
+
+            fn visit_ref(&mut self, _depth: usize, _is_global: bool) -> bool {
+                false
+            }
+
+            fn visit_vec_u8(&mut self, depth: usize, vals: &[u8]) {
+                self.visit_vec(depth, vals.len());
+            }
+
+            fn visit_vec_u64(&mut self, depth: usize, vals: &[u64]) {
+                self.visit_vec(depth, vals.len());
+            }
+
+            fn visit_vec_u128(&mut self, depth: usize, vals: &[u128]) {
+                self.visit_vec(depth, vals.len());
+            }
+
+            fn visit_vec_bool(&mut self, depth: usize, vals: &[bool]) {
+                self.visit_vec(depth, vals.len());
+            }
+
+            fn visit_vec_address(&mut self, depth: usize, vals: &[AccountAddress]) {
+                self.visit_vec(depth, vals.len());
+            }
+        }
+
+        let max_len = match self.loader.vm_config().max_vector_len_on_deserialization {
+            Some(max_len) => max_len,
+            None => return Ok(()),
+        };
+        let mut visitor = MaxVectorLen(0);
+        val.visit(&mut visitor);
+        if visitor.0 > max_len {
+            warn!("[VM] argument exceeds the maximum vector length");
+            return Err(
+                PartialVMError::new(StatusCode::FAILED_TO_DESERIALIZE_ARGUMENT).with_message(
+                    format!(
+                        "vector of length {} exceeds the maximum length {}",
+                        visitor.0, max_len
+                    ),
+                ),
+            );
+        }
+        Ok(())
+    }
+
     fn deserialize_args(
         &self,
         arg_tys: Vec<Type>,
@@ -270,12 +424,322 @@ impl VMRuntime {
         Ok((dummy_locals, deserialized_args))
     }
 
+    // Check that the arguments of an entry function or a script only have the types a transaction
+    // can pass: primitive types, vectors and the structs with a constructor in the configuration,
+    // with signers only as top-level arguments. Arguments containing structs are rebuilt by
+    // calling the constructors on their serialized fields.
+    fn construct_args<'a>(
+        &self,
+        arg_tys: &[Type],
+        serialized_args: &'a [impl Borrow<[u8]>],
+        data_store: &mut impl DataStore,
+        gas_meter: &mut impl GasMeter,
+        extensions: &mut NativeContextExtensions,
+        statistics: &mut Option<ExecutionStatistics>,
+    ) -> VMResult<Vec<Cow<'a, [u8]>>> {
+        let mut args = Vec::with_capacity(serialized_args.len());
+        for (idx, arg) in serialized_args.iter().enumerate() {
+            let arg = arg.borrow();
+            let ty = match arg_tys.get(idx) {
+                Some(ty) => ty,
+                // the number of arguments is checked when they are deserialized
+                None => {
+                    args.push(Cow::Borrowed(arg));
+                    continue;
+                }
+            };
+            let ty = match ty {
+                Type::Reference(inner) | Type::MutableReference(inner) => &**inner,
+                _ => ty,
+            };
+            let has_structs = self
+                .check_arg_type(ty, true)
+                .map_err(|e| e.finish(Location::Undefined))?;
+            if !has_structs {
+                args.push(Cow::Borrowed(arg));
+                continue;
+            }
+            let mut reader = ArgReader(arg);
+            let value = self.construct_arg(
+                ty,
+                &mut reader,
+                data_store,
+                gas_meter,
+                extensions,
+                statistics,
+            )?;
+            let bytes = reader
+                .finish()
+                .and_then(|()| {
+                    value
+                        .simple_serialize(&self.loader.type_to_type_layout(ty)?)
+                        .ok_or_else(|| {
+                            PartialVMError::new(StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR)
+                                .with_message(
+                                    "failed to serialize constructed argument".to_string(),
+                                )
+                        })
+                })
+                .map_err(|e| e.finish(Location::Undefined))?;
+            args.push(Cow::Owned(bytes));
+        }
+        Ok(args)
+    }
+
+    // Check that `ty` can be the type of a transaction argument, and return whether it contains a
+    // struct.
+    fn check_arg_type(&self, ty: &Type, top_level: bool) -> PartialVMResult<bool> {
+        match ty {
+            Type::Bool | Type::U8 | Type::U64 | Type::U128 | Type::Address => Ok(false),
+            Type::Signer if top_level => Ok(false),
+            Type::Vector(inner) => self.check_arg_type(inner, false),
+            Type::Struct(_) | Type::StructInstantiation(_, _) => {
+                self.struct_arg_constructor(ty)?;
+                if let Type::StructInstantiation(_, ty_args) = ty {
+                    for ty_arg in ty_args {
+                        self.check_arg_type(ty_arg, false)?;
+                    }
+                }
+                Ok(true)
+            }
+            Type::Signer | Type::Reference(_) | Type::MutableReference(_) | Type::TyParam(_) => {
+                Err(
+                    PartialVMError::new(StatusCode::INVALID_MAIN_FUNCTION_SIGNATURE).with_message(
+                        format!("{:?} cannot be the type of a transaction argument", ty),
+                    ),
+                )
+            }
+        }
+    }
+
+    // The constructor of the struct type `ty`, if it can be a transaction argument.
+    fn struct_arg_constructor(&self, ty: &Type) -> PartialVMResult<&StructArgConstructor> {
+        let idx = match ty {
+            Type::Struct(idx) | Type::StructInstantiation(idx, _) => *idx,
+            _ => {
+                return Err(
+                    PartialVMError::new(StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR)
+                        .with_message(format!("{:?} is not a struct type", ty)),
+                )
+            }
+        };
+        let struct_type = self.loader.get_struct_type(idx).ok_or_else(|| {
+            PartialVMError::new(StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR)
+                .with_message("missing struct type".to_string())
+        })?;
+        self.loader
+            .vm_config()
+            .struct_arg_constructors
+            .iter()
+            .find(|ctor| ctor.module == struct_type.module && ctor.struct_name == struct_type.name)
+            .ok_or_else(|| {
+                PartialVMError::new(StatusCode::INVALID_MAIN_FUNCTION_SIGNATURE).with_message(
+                    format!(
+                        "struct {}::{} cannot be the type of a transaction argument",
+                        struct_type.module, struct_type.name
+                    ),
+                )
+            })
+    }
+
+    // Build a value of type `ty` from the serialized argument in `reader`, calling the
+    // constructors of the structs it contains.
+    fn construct_arg(
+        &self,
+        ty: &Type,
+        reader: &mut ArgReader,
+        data_store: &mut impl DataStore,
+        gas_meter: &mut impl GasMeter,
+        extensions: &mut NativeContextExtensions,
+        statistics: &mut Option<ExecutionStatistics>,
+    ) -> VMResult<Value> {
+        let value = match ty {
+            Type::Bool => match reader.read_byte() {
+                Ok(0) => Value::bool(false),
+                Ok(1) => Value::bool(true),
+                _ => return Err(failed_to_deserialize_argument()),
+            },
+            Type::U8 => Value::u8(
+                reader
+                    .read_byte()
+                    .map_err(|e| e.finish(Location::Undefined))?,
+            ),
+            Type::U64 => Value::u64(u64::from_le_bytes(
+                reader
+                    .read_array()
+                    .map_err(|e| e.finish(Location::Undefined))?,
+            )),
+            Type::U128 => Value::u128(u128::from_le_bytes(
+                reader
+                    .read_array()
+                    .map_err(|e| e.finish(Location::Undefined))?,
+            )),
+            Type::Address => Value::address(AccountAddress::new(
+                reader
+                    .read_array()
+                    .map_err(|e| e.finish(Location::Undefined))?,
+            )),
+            Type::Vector(elem_ty) => {
+                let len = reader
+                    .read_len()
+                    .map_err(|e| e.finish(Location::Undefined))?;
+                let mut elems = Vec::with_capacity(len);
+                for _ in 0..len {
+                    elems.push(self.construct_arg(
+                        elem_ty, reader, data_store, gas_meter, extensions, statistics,
+                    )?);
+                }
+                Vector::pack(elem_ty, elems).map_err(|e| e.finish(Location::Undefined))?
+            }
+            Type::Struct(_) | Type::StructInstantiation(_, _) => self
+                .construct_struct_arg(ty, reader, data_store, gas_meter, extensions, statistics)?,
+            Type::Signer | Type::Reference(_) | Type::MutableReference(_) | Type::TyParam(_) => {
+                return Err(
+                    PartialVMError::new(StatusCode::INVALID_MAIN_FUNCTION_SIGNATURE)
+                        .with_message(format!(
+                            "{:?} cannot be the type of a transaction argument",
+                            ty
+                        ))
+                        .finish(Location::Undefined),
+                )
+            }
+        };
+        Ok(value)
+    }
+
+    // Build a struct argument by calling its constructor on the arguments serialized in `reader`.
+    fn construct_struct_arg(
+        &self,
+        ty: &Type,
+        reader: &mut ArgReader,
+        data_store: &mut impl DataStore,
+        gas_meter: &mut impl GasMeter,
+        extensions: &mut NativeContextExtensions,
+        statistics: &mut Option<ExecutionStatistics>,
+    ) -> VMResult<Value> {
+        let ctor = self
+            .struct_arg_constructor(ty)
+            .map_err(|e| e.finish(Location::Undefined))?;
+        let ty_args = match ty {
+            Type::StructInstantiation(_, ty_args) => ty_args
+                .iter()
+                .map(|ty| self.loader.type_to_type_tag(ty))
+                .collect::<PartialVMResult<Vec<_>>>()
+                .map_err(|e| e.finish(Location::Undefined))?,
+            _ => vec![],
+        };
+        let (
+            _,
+            func,
+            LoadedFunctionInstantiation {
+                type_arguments,
+                parameters,
+                return_,
+            },
+        ) = self
+            .loader
+            .load_function(&ctor.module, &ctor.constructor, &ty_args, data_store)?;
+        let invalid_constructor = || {
+            PartialVMError::new(StatusCode::INVALID_MAIN_FUNCTION_SIGNATURE)
+                .with_message(format!(
+                    "{}::{} is not a constructor of {}",
+                    ctor.module, ctor.constructor, ctor.struct_name
+                ))
+                .finish(Location::Undefined)
+        };
+        // The constructor's signature may nest the type arguments deeper than the struct itself,
+        // so its instantiation is bounded like that of the entry function.
+        let max_type_depth = self.loader.vm_config().max_type_depth;
+        let subst = |ty: &Type| {
+            ty.subst_with_max_depth(&type_arguments, max_type_depth)
+                .map_err(|err| {
+                    let status = if err.major_status() == StatusCode::VM_MAX_TYPE_DEPTH_REACHED {
+                        StatusCode::TOO_MANY_TYPE_NODES
+                    } else {
+                        StatusCode::INVALID_MAIN_FUNCTION_SIGNATURE
+                    };
+                    PartialVMError::new(status)
+                        .with_message(format!(
+                            "cannot instantiate constructor {}::{} of {}",
+                            ctor.module, ctor.constructor, ctor.struct_name
+                        ))
+                        .finish(Location::Undefined)
+                })
+        };
+        match return_.as_slice() {
+            [return_ty] if subst(return_ty)? == *ty => (),
+            _ => return Err(invalid_constructor()),
+        }
+
+        let mut args = Vec::with_capacity(parameters.len());
+        for param_ty in &parameters {
+            let param_ty = subst(param_ty)?;
+            args.push(self.construct_arg(
+                &param_ty, reader, data_store, gas_meter, extensions, statistics,
+            )?);
+        }
+        let mut return_values = Interpreter::entrypoint(
+            func,
+            type_arguments,
+            args,
+            data_store,
+            gas_meter,
+            extensions,
+            &self.loader,
+            statistics,
+        )
+        .map_err(|err| {
+            PartialVMError::new(StatusCode::FAILED_TO_DESERIALIZE_ARGUMENT)
+                .with_message(format!(
+                    "constructor {}::{} failed with {:?}",
+                    ctor.module,
+                    ctor.constructor,
+                    err.into_vm_status()
+                ))
+                .finish(Location::Undefined)
+        })?;
+        match return_values.pop() {
+            Some(value) if return_values.is_empty() => Ok(value),
+            _ => Err(invalid_constructor()),
+        }
+    }
+
     fn serialize_return_value(
         &self,
         ty: &Type,
         value: Value,
     ) -> PartialVMResult<(Vec<u8>, MoveTypeLayout)> {
-        let (ty, value) = match ty {
+        let (ty, value) = Self::deref_return_value(ty, value)?;
+        let layout = self.return_value_layout(ty)?;
+        let bytes = value.simple_serialize(&layout).ok_or_else(|| {
+            PartialVMError::new(StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR)
+                .with_message("failed to serialize return values".to_string())
+        })?;
+        Ok((bytes, layout))
+    }
+
+    fn annotate_return_value(
+        &self,
+        ty: &Type,
+        value: Value,
+    ) -> PartialVMResult<(MoveValue, TypeTag)> {
+        let (ty, value) = Self::deref_return_value(ty, value)?;
+        let layout = self.return_value_layout(ty)?;
+        let annotated_layout = self.loader.type_to_fully_annotated_layout(ty)?;
+        let type_tag = self.loader.type_to_type_tag(ty)?;
+        let annotated_value = value
+            .simple_serialize(&layout)
+            .and_then(|bytes| MoveValue::simple_deserialize(&bytes, &annotated_layout).ok())
+            .ok_or_else(|| {
+                PartialVMError::new(StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR)
+                    .with_message("failed to annotate return values".to_string())
+            })?;
+        Ok((annotated_value, type_tag))
+    }
+
+    // Return values of reference types are returned as the value they point to.
+    fn deref_return_value(ty: &Type, value: Value) -> PartialVMResult<(&Type, Value)> {
+        match ty {
             Type::Reference(inner) | Type::MutableReference(inner) => {
                 let ref_value: Reference = value.cast().map_err(|_err| {
                     PartialVMError::new(StatusCode::INTERNAL_TYPE_ERROR).with_message(
@@ -283,28 +747,25 @@ impl VMRuntime {
                     )
                 })?;
                 let inner_value = ref_value.read_ref()?;
-                (&**inner, inner_value)
+                Ok((&**inner, inner_value))
             }
-            _ => (ty, value),
-        };
+            _ => Ok((ty, value)),
+        }
+    }
 
-        let layout = self.loader.type_to_type_layout(ty).map_err(|_err| {
+    fn return_value_layout(&self, ty: &Type) -> PartialVMResult<MoveTypeLayout> {
+        self.loader.type_to_type_layout(ty).map_err(|_err| {
             PartialVMError::new(StatusCode::VERIFICATION_ERROR).with_message(
                 "entry point functions cannot have non-serializable return types".to_string(),
             )
-        })?;
-        let bytes = value.simple_serialize(&layout).ok_or_else(|| {
-            PartialVMError::new(StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR)
-                .with_message("failed to serialize return values".to_string())
-        })?;
-        Ok((bytes, layout))
+        })
     }
 
-    fn serialize_return_values(
+    fn encode_return_values<R: ReturnValues>(
         &self,
         return_types: &[Type],
         return_values: Vec<Value>,
-    ) -> PartialVMResult<Vec<(Vec<u8>, MoveTypeLayout)>> {
+    ) -> PartialVMResult<Vec<R::Value>> {
         if return_types.len() != return_values.len() {
             return Err(
                 PartialVMError::new(StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR).with_message(
@@ -320,11 +781,11 @@ impl VMRuntime {
         return_types
             .iter()
             .zip(return_values)
-            .map(|(ty, value)| self.serialize_return_value(ty, value))
+            .map(|(ty, value)| R::encode(self, ty, value))
             .collect()
     }
 
-    fn execute_function_impl(
+    fn execute_function_impl<R: ReturnValues>(
         &self,
         func: Arc<Function>,
         ty_args: Vec<Type>,
@@ -334,10 +795,13 @@ impl VMRuntime {
         data_store: &mut impl DataStore,
         gas_meter: &mut impl GasMeter,
         extensions: &mut NativeContextExtensions,
-    ) -> VMResult<SerializedReturnValues> {
+        statistics: &mut Option<ExecutionStatistics>,
+        check_args: bool,
+    ) -> VMResult<R> {
+        let max_type_depth = self.loader.vm_config().max_type_depth;
         let arg_types = param_types
             .into_iter()
-            .map(|ty| ty.subst(&ty_args))
+            .map(|ty| ty.subst_with_max_depth(&ty_args, max_type_depth))
             .collect::<PartialVMResult<Vec<_>>>()
             .map_err(|err| err.finish(Location::Undefined))?;
         let mut_ref_args = arg_types
@@ -348,12 +812,27 @@ impl VMRuntime {
                 _ => None,
             })
             .collect::<Vec<_>>();
+        let serialized_args = if check_args {
+            self.construct_args(
+                &arg_types,
+                &serialized_args,
+                data_store,
+                gas_meter,
+                extensions,
+                statistics,
+            )?
+        } else {
+            serialized_args
+                .iter()
+                .map(|arg| Cow::Borrowed(arg.borrow()))
+                .collect()
+        };
         let (mut dummy_locals, deserialized_args) = self
             .deserialize_args(arg_types, serialized_args)
             .map_err(|e| e.finish(Location::Undefined))?;
         let return_types = return_types
             .into_iter()
-            .map(|ty| ty.subst(&ty_args))
+            .map(|ty| ty.subst_with_max_depth(&ty_args, max_type_depth))
             .collect::<PartialVMResult<Vec<_>>>()
             .map_err(|err| err.finish(Location::Undefined))?;
 
@@ -365,18 +844,18 @@ impl VMRuntime {
             gas_meter,
             extensions,
             &self.loader,
+            statistics,
         )?;
 
-        let serialized_return_values = self
-            .serialize_return_values(&return_types, return_values)
+        let encoded_return_values = self
+            .encode_return_values::<R>(&return_types, return_values)
             .map_err(|e| e.finish(Location::Undefined))?;
-        let serialized_mut_ref_outputs = mut_ref_args
+        let encoded_mut_ref_outputs = mut_ref_args
             .into_iter()
             .map(|(idx, ty)| {
                 // serialize return values first in the case that a value points into this local
                 let local_val = dummy_locals.move_loc(idx)?;
-                let (bytes, layout) = self.serialize_return_value(&ty, local_val)?;
-                Ok((idx as LocalIndex, bytes, layout))
+                Ok((idx as LocalIndex, R::encode(self, &ty, local_val)?))
             })
             .collect::<PartialVMResult<_>>()
             .map_err(|e| e.finish(Location::Undefined))?;
@@ -384,13 +863,10 @@ impl VMRuntime {
         // locals should not be dropped until all return values are serialized
         std::mem::drop(dummy_locals);
 
-        Ok(SerializedReturnValues {
-            mutable_reference_outputs: serialized_mut_ref_outputs,
-            return_values: serialized_return_values,
-        })
+        Ok(R::new(encoded_mut_ref_outputs, encoded_return_values))
     }
 
-    pub(crate) fn execute_function(
+    pub(crate) fn execute_function<R: ReturnValues>(
         &self,
         module: &ModuleId,
         function_name: &IdentStr,
@@ -399,8 +875,9 @@ impl VMRuntime {
         data_store: &mut impl DataStore,
         gas_meter: &mut impl GasMeter,
         extensions: &mut NativeContextExtensions,
+        statistics: &mut Option<ExecutionStatistics>,
         bypass_declared_entry_check: bool,
-    ) -> VMResult<SerializedReturnValues> {
+    ) -> VMResult<R> {
         use move_binary_format::{binary_views::BinaryIndexedView, file_format::SignatureIndex};
         fn check_is_entry(
             _resolver: &BinaryIndexedView,
@@ -451,6 +928,8 @@ impl VMRuntime {
             data_store,
             gas_meter,
             extensions,
+            statistics,
+            !bypass_declared_entry_check,
         )
     }
 
@@ -463,6 +942,7 @@ impl VMRuntime {
         data_store: &mut impl DataStore,
         gas_meter: &mut impl GasMeter,
         extensions: &mut NativeContextExtensions,
+        statistics: &mut Option<ExecutionStatistics>,
     ) -> VMResult<SerializedReturnValues> {
         // load the script, perform verification
         let (
@@ -485,6 +965,9 @@ impl VMRuntime {
             data_store,
             gas_meter,
             extensions,
+            statistics,
+            // script arguments are only checked by the adapter
+            false,
         )
     }
 
@@ -492,3 +975,118 @@ impl VMRuntime {
         &self.loader
     }
 }
+
+/// The form in which the return values of an execution are handed back to the caller.
+pub(crate) trait ReturnValues: Sized {
+    type Value;
+
+    fn encode(runtime: &VMRuntime, ty: &Type, value: Value) -> PartialVMResult<Self::Value>;
+
+    fn new(
+        mutable_reference_outputs: Vec<(LocalIndex, Self::Value)>,
+        return_values: Vec<Self::Value>,
+    ) -> Self;
+}
+
+impl ReturnValues for SerializedReturnValues {
+    type Value = (Vec<u8>, MoveTypeLayout);
+
+    fn encode(runtime: &VMRuntime, ty: &Type, value: Value) -> PartialVMResult<Self::Value> {
+        runtime.serialize_return_value(ty, value)
+    }
+
+    fn new(
+        mutable_reference_outputs: Vec<(LocalIndex, Self::Value)>,
+        return_values: Vec<Self::Value>,
+    ) -> Self {
+        Self {
+            mutable_reference_outputs: mutable_reference_outputs
+                .into_iter()
+                .map(|(idx, (bytes, layout))| (idx, bytes, layout))
+                .collect(),
+            return_values,
+        }
+    }
+}
+
+impl ReturnValues for AnnotatedReturnValues {
+    type Value = (MoveValue, TypeTag);
+
+    fn encode(runtime: &VMRuntime, ty: &Type, value: Value) -> PartialVMResult<Self::Value> {
+        runtime.annotate_return_value(ty, value)
+    }
+
+    fn new(
+        mutable_reference_outputs: Vec<(LocalIndex, Self::Value)>,
+        return_values: Vec<Self::Value>,
+    ) -> Self {
+        Self {
+            mutable_reference_outputs: mutable_reference_outputs
+                .into_iter()
+                .map(|(idx, (value, type_tag))| (idx, value, type_tag))
+                .collect(),
+            return_values,
+        }
+    }
+}
+
+fn failed_to_deserialize_argument() -> VMError {
+    PartialVMError::new(StatusCode::FAILED_TO_DESERIALIZE_ARGUMENT).finish(Location::Undefined)
+}
+
+// Reads the BCS encoding of a transaction argument.
+struct ArgReader<'a>(&'a [u8]);
+
+impl<'a> ArgReader<'a> {
+    fn read_bytes(&mut self, len: usize) -> PartialVMResult<&'a [u8]> {
+        if self.0.len() < len {
+            return Err(PartialVMError::new(
+                StatusCode::FAILED_TO_DESERIALIZE_ARGUMENT,
+            ));
+        }
+        let (bytes, rest) = self.0.split_at(len);
+        self.0 = rest;
+        Ok(bytes)
+    }
+
+    fn read_byte(&mut self) -> PartialVMResult<u8> {
+        Ok(self.read_bytes(1)?[0])
+    }
+
+    fn read_array<const N: usize>(&mut self) -> PartialVMResult<[u8; N]> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.read_bytes(N)?);
+        Ok(array)
+    }
+
+    // The length of a vector, as a ULEB128 encoded `u32`. Every element takes at least a byte, so
+    // the length is also bounded by the remaining bytes.
+    fn read_len(&mut self) -> PartialVMResult<usize> {
+        let mut len: u64 = 0;
+        for shift in (0..32).step_by(7) {
+            let byte = self.read_byte()?;
+            len |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 {
+                if (byte == 0 && shift > 0) || len > u32::MAX as u64 || len as usize > self.0.len()
+                {
+                    break;
+                }
+                return Ok(len as usize);
+            }
+        }
+        Err(PartialVMError::new(
+            StatusCode::FAILED_TO_DESERIALIZE_ARGUMENT,
+        ))
+    }
+
+    fn finish(self) -> PartialVMResult<()> {
+        if self.0.is_empty() {
+            Ok(())
+        } else {
+            Err(
+                PartialVMError::new(StatusCode::FAILED_TO_DESERIALIZE_ARGUMENT)
+                    .with_message("trailing bytes in argument".to_string()),
+            )
+        }
+    }
+}
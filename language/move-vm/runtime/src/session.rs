@@ -4,7 +4,7 @@
 
 use crate::{
     data_cache::TransactionDataCache, native_extensions::NativeContextExtensions,
-    runtime::VMRuntime,
+    runtime::VMRuntime, statistics::ExecutionStatistics,
 };
 use move_binary_format::{
     compatibility::CompatibilityConfig,
@@ -17,7 +17,7 @@ use move_core_types::{
     identifier::IdentStr,
     language_storage::{ModuleId, TypeTag},
     resolver::MoveResolver,
-    value::MoveTypeLayout,
+    value::{MoveTypeLayout, MoveValue},
 };
 use move_vm_types::{
     data_store::DataStore,
@@ -30,6 +30,7 @@ pub struct Session<'r, 'l, S> {
     pub(crate) runtime: &'l VMRuntime,
     pub(crate) data_cache: TransactionDataCache<'r, 'l, S>,
     pub(crate) native_extensions: NativeContextExtensions<'r>,
+    pub(crate) statistics: Option<ExecutionStatistics>,
 }
 
 /// Serialized return values from function/script execution
@@ -43,16 +44,27 @@ pub struct SerializedReturnValues {
     pub return_values: Vec<(Vec<u8>, MoveTypeLayout)>,
 }
 
+/// Return values from function execution, decoded with their fully annotated layouts
+/// Structs carry their type, including type arguments, and field names
+#[derive(Debug)]
+pub struct AnnotatedReturnValues {
+    /// The value of any arguments that were mutably borrowed.
+    /// Non-mut borrowed values are not included
+    pub mutable_reference_outputs: Vec<(LocalIndex, MoveValue, TypeTag)>,
+    /// The return values from the function
+    pub return_values: Vec<(MoveValue, TypeTag)>,
+}
+
 impl<'r, 'l, S: MoveResolver> Session<'r, 'l, S> {
     /// Execute a Move function with the given arguments. This is mainly designed for an external
     /// environment to invoke system logic written in Move.
     ///
-    /// NOTE: There are NO checks on the `args` except that they can deserialize into the provided
-    /// types.
-    /// The ability to deserialize `args` into arbitrary types is *very* powerful, e.g. it can
-    /// used to manufacture `signer`'s or `Coin`'s from raw bytes. It is the responsibility of the
-    /// caller (e.g. adapter) to ensure that this power is used responsibly/securely for its
-    /// use-case.
+    /// The `args` may only be of primitive types, `signer` (as top level argument), vectors of
+    /// valid arguments, or structs allowed by `VMConfig::struct_arg_constructors`. A struct
+    /// argument is serialized as the arguments of its constructor, e.g. a `String` as its UTF-8
+    /// bytes and an `Option` as a vector of at most one element, and built by running that
+    /// constructor. Any other argument type is rejected with `INVALID_MAIN_FUNCTION_SIGNATURE`,
+    /// and an argument which cannot be constructed with `FAILED_TO_DESERIALIZE_ARGUMENT`.
     ///
     /// The caller MUST ensure
     ///   - All types and modules referred to by the type arguments exist.
@@ -86,11 +98,42 @@ impl<'r, 'l, S: MoveResolver> Session<'r, 'l, S> {
             &mut self.data_cache,
             gas_meter,
             &mut self.native_extensions,
+            &mut self.statistics,
             bypass_declared_entry_check,
         )
     }
 
-    /// Similar to execute_entry_function, but it bypasses visibility checks
+    /// Similar to execute_entry_function, but returns annotated values
+    pub fn execute_entry_function_annotated(
+        &mut self,
+        module: &ModuleId,
+        function_name: &IdentStr,
+        ty_args: Vec<TypeTag>,
+        args: Vec<impl Borrow<[u8]>>,
+        gas_meter: &mut impl GasMeter,
+    ) -> VMResult<AnnotatedReturnValues> {
+        let bypass_declared_entry_check = false;
+        self.runtime.execute_function(
+            module,
+            function_name,
+            ty_args,
+            args,
+            &mut self.data_cache,
+            gas_meter,
+            &mut self.native_extensions,
+            &mut self.statistics,
+            bypass_declared_entry_check,
+        )
+    }
+
+    /// Similar to execute_entry_function, but it bypasses visibility checks.
+    ///
+    /// NOTE: There are NO checks on the `args` except that they can deserialize into the provided
+    /// types.
+    /// The ability to deserialize `args` into arbitrary types is *very* powerful, e.g. it can
+    /// used to manufacture `signer`'s or `Coin`'s from raw bytes. It is the responsibility of the
+    /// caller (e.g. adapter) to ensure that this power is used responsibly/securely for its
+    /// use-case.
     pub fn execute_function_bypass_visibility(
         &mut self,
         module: &ModuleId,
@@ -108,6 +151,30 @@ impl<'r, 'l, S: MoveResolver> Session<'r, 'l, S> {
             &mut self.data_cache,
             gas_meter,
             &mut self.native_extensions,
+            &mut self.statistics,
+            bypass_declared_entry_check,
+        )
+    }
+
+    /// Similar to execute_function_bypass_visibility, but returns annotated values
+    pub fn execute_function_bypass_visibility_annotated(
+        &mut self,
+        module: &ModuleId,
+        function_name: &IdentStr,
+        ty_args: Vec<TypeTag>,
+        args: Vec<impl Borrow<[u8]>>,
+        gas_meter: &mut impl GasMeter,
+    ) -> VMResult<AnnotatedReturnValues> {
+        let bypass_declared_entry_check = true;
+        self.runtime.execute_function(
+            module,
+            function_name,
+            ty_args,
+            args,
+            &mut self.data_cache,
+            gas_meter,
+            &mut self.native_extensions,
+            &mut self.statistics,
             bypass_declared_entry_check,
         )
     }
@@ -142,6 +209,7 @@ impl<'r, 'l, S: MoveResolver> Session<'r, 'l, S> {
             &mut self.data_cache,
             gas_meter,
             &mut self.native_extensions,
+            &mut self.statistics,
         )
     }
 
@@ -151,6 +219,8 @@ impl<'r, 'l, S: MoveResolver> Session<'r, 'l, S> {
     ///   - The module fails to deserialize or verify.
     ///   - The sender address does not match that of the module.
     ///   - (Republishing-only) the module to be updated is not backward compatible with the old module.
+    ///   - (Republishing-only) the update is not allowed by the upgrade policy of the old module,
+    ///     or declares a less restrictive policy.
     ///   - (Republishing-only) the module to be updated introduces cyclic dependencies.
     ///
     /// The Move VM should not be able to produce other user errors.
@@ -180,8 +250,9 @@ impl<'r, 'l, S: MoveResolver> Session<'r, 'l, S> {
     /// In case an invariant violation occurs, the whole Session should be considered corrupted and
     /// one shall not proceed with effect generation.
     ///
-    /// This operation performs compatibility checks if a module is replaced. See also
-    /// `move_binary_format::compatibility`.
+    /// This operation performs compatibility checks if a module is replaced, as required by the
+    /// upgrade policy of the module. See also `move_binary_format::compatibility` and
+    /// `move_binary_format::upgrade_policy`.
     pub fn publish_module_bundle(
         &mut self,
         modules: Vec<Vec<u8>>,
@@ -197,7 +268,8 @@ impl<'r, 'l, S: MoveResolver> Session<'r, 'l, S> {
         )
     }
 
-    /// Same like `publish_module_bundle` but with a custom compatibility check.
+    /// Same like `publish_module_bundle` but with a custom compatibility check. The configuration
+    /// only applies to modules with the `Compatible` upgrade policy.
     pub fn publish_module_bundle_with_compat_config(
         &mut self,
         modules: Vec<Vec<u8>>,
@@ -259,6 +331,20 @@ impl<'r, 'l, S: MoveResolver> Session<'r, 'l, S> {
         Ok((change_set, events, native_extensions))
     }
 
+    /// Same like `finish`, but also returns the statistics of the code executed by the session.
+    /// The statistics are empty unless `VMConfig::collect_statistics` is set.
+    pub fn finish_with_statistics(self) -> VMResult<(ChangeSet, Vec<Event>, ExecutionStatistics)> {
+        let Session {
+            data_cache,
+            statistics,
+            ..
+        } = self;
+        let (change_set, events) = data_cache
+            .into_effects()
+            .map_err(|e| e.finish(Location::Undefined))?;
+        Ok((change_set, events, statistics.unwrap_or_default()))
+    }
+
     /// Load a script and all of its types into cache
     pub fn load_script(
         &self,
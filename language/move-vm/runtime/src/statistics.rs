@@ -0,0 +1,73 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Statistics of the code executed by a session.
+//!
+//! Statistics are only collected when `VMConfig::collect_statistics` is set, and are returned by
+//! `Session::finish_with_statistics`. Unlike gas, they do not depend on a gas schedule, which
+//! makes them suitable to track the behavior of a workload across versions of the VM.
+
+use crate::loader::Function;
+use move_binary_format::{
+    file_format::Bytecode,
+    file_format_common::{instruction_opcode, Opcodes},
+};
+use move_core_types::language_storage::TypeTag;
+use std::collections::BTreeMap;
+
+/// The statistics of all the functions and scripts executed by a session.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ExecutionStatistics {
+    /// Number of calls to each function, keyed by its fully qualified name, e.g.
+    /// `0x1::vector::append`. Calls into the session and calls to native functions are counted.
+    pub function_calls: BTreeMap<String, u64>,
+    /// Number of instructions executed, by opcode.
+    pub instructions: BTreeMap<Opcodes, u64>,
+    /// Maximum number of frames on the call stack. Native functions do not push a frame.
+    pub max_call_depth: u64,
+    /// Maximum number of values on the operand stack.
+    pub max_operand_stack_size: u64,
+    /// Number of instructions reading a resource without modifying it (`exists` and immutable
+    /// borrows), by resource type.
+    pub global_reads: BTreeMap<TypeTag, u64>,
+    /// Number of instructions which may modify a resource (`move_to`, `move_from` and mutable
+    /// borrows), by resource type.
+    pub global_writes: BTreeMap<TypeTag, u64>,
+}
+
+impl ExecutionStatistics {
+    /// Total number of instructions executed.
+    pub fn instruction_count(&self) -> u64 {
+        self.instructions.values().sum()
+    }
+
+    pub(crate) fn record_call(&mut self, function: &Function, call_depth: usize) {
+        *self
+            .function_calls
+            .entry(function.pretty_string())
+            .or_default() += 1;
+        if !function.is_native() {
+            self.max_call_depth = self.max_call_depth.max(call_depth as u64);
+        }
+    }
+
+    pub(crate) fn record_instruction(&mut self, instruction: &Bytecode, operand_stack_size: usize) {
+        *self
+            .instructions
+            .entry(instruction_opcode(instruction))
+            .or_default() += 1;
+        self.max_operand_stack_size = self.max_operand_stack_size.max(operand_stack_size as u64);
+    }
+
+    pub(crate) fn record_operand_stack_size(&mut self, operand_stack_size: usize) {
+        self.max_operand_stack_size = self.max_operand_stack_size.max(operand_stack_size as u64);
+    }
+
+    pub(crate) fn record_global_read(&mut self, ty: TypeTag) {
+        *self.global_reads.entry(ty).or_default() += 1;
+    }
+
+    pub(crate) fn record_global_write(&mut self, ty: TypeTag) {
+        *self.global_writes.entry(ty).or_default() += 1;
+    }
+}
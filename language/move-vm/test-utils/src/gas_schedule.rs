@@ -19,7 +19,7 @@ use move_binary_format::{
 use move_core_types::{
     gas_algebra::{
         AbstractMemorySize, GasQuantity, InternalGas, InternalGasPerAbstractMemoryUnit,
-        InternalGasUnit, NumArgs, NumBytes, ToUnit, ToUnitFractional,
+        InternalGasUnit, NumArgs, NumBytes, NumInstructions, ToUnit, ToUnitFractional,
     },
     language_storage::ModuleId,
     vm_status::StatusCode,
@@ -503,6 +503,23 @@ impl<'b> GasMeter for GasStatus<'b> {
     fn charge_vec_swap(&mut self, _ty: impl TypeView) -> PartialVMResult<()> {
         self.charge_instr(Opcodes::VEC_SWAP)
     }
+
+    // The cost table only covers bytecode instructions, so publishing is not charged.
+    fn charge_publish_module_bytes(&mut self, _size: NumBytes) -> PartialVMResult<()> {
+        Ok(())
+    }
+
+    fn charge_publish_dependency(&mut self, _module_id: &ModuleId) -> PartialVMResult<()> {
+        Ok(())
+    }
+
+    fn charge_verify_module(
+        &mut self,
+        _module_id: &ModuleId,
+        _num_instructions: NumInstructions,
+    ) -> PartialVMResult<()> {
+        Ok(())
+    }
 }
 
 pub fn new_from_instructions(mut instrs: Vec<(Bytecode, GasCost)>) -> CostTable {
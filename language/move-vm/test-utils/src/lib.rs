@@ -7,4 +7,6 @@
 mod storage;
 
 pub mod gas_schedule;
-pub use storage::{BlankStorage, DeltaStorage, InMemoryStorage};
+pub use storage::{
+    BlankStorage, DeltaStorage, InMemoryStorage, RecordedReads, RecordingStorage, ReplayStorage,
+};
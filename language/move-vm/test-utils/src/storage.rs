@@ -2,7 +2,7 @@
 // Copyright (c) The Move Contributors
 // SPDX-License-Identifier: Apache-2.0
 
-use anyhow::{bail, Result};
+use anyhow::{anyhow, bail, Result};
 use move_core_types::{
     account_address::AccountAddress,
     effects::{AccountChangeSet, ChangeSet, Op},
@@ -10,7 +10,9 @@ use move_core_types::{
     language_storage::{ModuleId, StructTag},
     resolver::{ModuleResolver, MoveResolver, ResourceResolver},
 };
+use serde::{Deserialize, Serialize};
 use std::{
+    cell::RefCell,
     collections::{btree_map, BTreeMap},
     fmt::Debug,
 };
@@ -120,6 +122,105 @@ impl<'a, 'b, S: MoveResolver> DeltaStorage<'a, 'b, S> {
     }
 }
 
+/// The data read from a storage, with `None` for the reads of data which did not exist.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RecordedReads {
+    pub modules: BTreeMap<ModuleId, Option<Vec<u8>>>,
+    pub resources: BTreeMap<(AccountAddress, StructTag), Option<Vec<u8>>>,
+}
+
+/// A storage adapter recording all the data read from an existing storage backend, so that
+/// a session can later be replayed against the recorded data only with `ReplayStorage`.
+#[derive(Debug)]
+pub struct RecordingStorage<'a, S> {
+    base: &'a S,
+    reads: RefCell<RecordedReads>,
+}
+
+impl<'a, S> RecordingStorage<'a, S> {
+    pub fn new(base: &'a S) -> Self {
+        Self {
+            base,
+            reads: RefCell::new(RecordedReads::default()),
+        }
+    }
+
+    pub fn into_reads(self) -> RecordedReads {
+        self.reads.into_inner()
+    }
+}
+
+impl<'a, S: ModuleResolver> ModuleResolver for RecordingStorage<'a, S> {
+    type Error = S::Error;
+
+    fn get_module(&self, module_id: &ModuleId) -> Result<Option<Vec<u8>>, Self::Error> {
+        let blob = self.base.get_module(module_id)?;
+        self.reads
+            .borrow_mut()
+            .modules
+            .insert(module_id.clone(), blob.clone());
+        Ok(blob)
+    }
+}
+
+impl<'a, S: ResourceResolver> ResourceResolver for RecordingStorage<'a, S> {
+    type Error = S::Error;
+
+    fn get_resource(
+        &self,
+        address: &AccountAddress,
+        tag: &StructTag,
+    ) -> Result<Option<Vec<u8>>, S::Error> {
+        let blob = self.base.get_resource(address, tag)?;
+        self.reads
+            .borrow_mut()
+            .resources
+            .insert((*address, tag.clone()), blob.clone());
+        Ok(blob)
+    }
+}
+
+/// A storage serving the data recorded by a `RecordingStorage`. Reading any data which was not
+/// recorded is an error.
+#[derive(Debug, Clone)]
+pub struct ReplayStorage {
+    reads: RecordedReads,
+}
+
+impl ReplayStorage {
+    pub fn new(reads: RecordedReads) -> Self {
+        Self { reads }
+    }
+}
+
+impl ModuleResolver for ReplayStorage {
+    type Error = anyhow::Error;
+
+    fn get_module(&self, module_id: &ModuleId) -> Result<Option<Vec<u8>>, Self::Error> {
+        self.reads
+            .modules
+            .get(module_id)
+            .cloned()
+            .ok_or_else(|| anyhow!("Module {} was not recorded", module_id))
+    }
+}
+
+impl ResourceResolver for ReplayStorage {
+    type Error = anyhow::Error;
+
+    fn get_resource(
+        &self,
+        address: &AccountAddress,
+        tag: &StructTag,
+    ) -> Result<Option<Vec<u8>>, Self::Error> {
+        self.reads
+            .resources
+            .get(&(*address, tag.clone()))
+            .cloned()
+            .ok_or_else(|| anyhow!("Resource {} at {} was not recorded", tag, address))
+    }
+}
+
 /// Simple in-memory storage for modules and resources under an account.
 #[derive(Debug, Clone)]
 struct InMemoryAccountStorage {
@@ -24,11 +24,13 @@ pub trait DataStore {
     // ---
 
     /// Try to load a resource from remote storage and create a corresponding GlobalValue
-    /// that is owned by the data store.
+    /// that is owned by the data store. `ty_layout` is the layout of `ty`, used to deserialize
+    /// the resource.
     fn load_resource(
         &mut self,
         addr: AccountAddress,
         ty: &Type,
+        ty_layout: &MoveTypeLayout,
     ) -> PartialVMResult<(&mut GlobalValue, Option<Option<NumBytes>>)>;
 
     /// Get the serialized format of a `CompiledModule` given a `ModuleId`.
@@ -4,7 +4,7 @@
 use crate::views::{TypeView, ValueView};
 use move_binary_format::errors::PartialVMResult;
 use move_core_types::{
-    gas_algebra::{InternalGas, NumArgs, NumBytes},
+    gas_algebra::{InternalGas, NumArgs, NumBytes, NumInstructions},
     language_storage::ModuleId,
 };
 
@@ -194,6 +194,26 @@ pub trait GasMeter {
     /// In the future, we may want to remove this and directly pass a reference to the GasMeter
     /// instance to the native functions to allow gas to be deducted during computation.
     fn charge_native_function(&mut self, amount: InternalGas) -> PartialVMResult<()>;
+
+    /// Charge for publishing a module of the given size in bytes. This is called for every module
+    /// of a bundle before it gets deserialized.
+    fn charge_publish_module_bytes(&mut self, size: NumBytes) -> PartialVMResult<()>;
+
+    /// Charge for a module that is not part of the published bundle, but is loaded because a
+    /// module of the bundle depends on it or declares it as a friend.
+    ///
+    /// Each such module is charged once per bundle, whether or not it is already cached, so that
+    /// the cost of publishing does not depend on the state of the loader.
+    fn charge_publish_dependency(&mut self, module_id: &ModuleId) -> PartialVMResult<()>;
+
+    /// Charge for verifying a module before it gets published. The work of the bytecode verifier
+    /// is proportional to `num_instructions`, the number of instructions in the function bodies of
+    /// the module.
+    fn charge_verify_module(
+        &mut self,
+        module_id: &ModuleId,
+        num_instructions: NumInstructions,
+    ) -> PartialVMResult<()>;
 }
 
 /// A dummy gas meter that does not meter anything.
@@ -366,4 +386,20 @@ impl GasMeter for UnmeteredGasMeter {
     fn charge_native_function(&mut self, _amount: InternalGas) -> PartialVMResult<()> {
         Ok(())
     }
+
+    fn charge_publish_module_bytes(&mut self, _size: NumBytes) -> PartialVMResult<()> {
+        Ok(())
+    }
+
+    fn charge_publish_dependency(&mut self, _module_id: &ModuleId) -> PartialVMResult<()> {
+        Ok(())
+    }
+
+    fn charge_verify_module(
+        &mut self,
+        _module_id: &ModuleId,
+        _num_instructions: NumInstructions,
+    ) -> PartialVMResult<()> {
+        Ok(())
+    }
 }
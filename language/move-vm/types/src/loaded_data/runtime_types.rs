@@ -50,15 +50,15 @@ pub enum Type {
 }
 
 impl Type {
-    fn clone_impl(&self, depth: usize) -> PartialVMResult<Type> {
-        self.apply_subst(|idx, _| Ok(Type::TyParam(idx)), depth)
+    fn clone_impl(&self, depth: usize, max_depth: usize) -> PartialVMResult<Type> {
+        self.apply_subst(|idx, _| Ok(Type::TyParam(idx)), depth, max_depth)
     }
 
-    fn apply_subst<F>(&self, subst: F, depth: usize) -> PartialVMResult<Type>
+    fn apply_subst<F>(&self, subst: F, depth: usize, max_depth: usize) -> PartialVMResult<Type>
     where
         F: Fn(usize, usize) -> PartialVMResult<Type> + Copy,
     {
-        if depth > TYPE_DEPTH_MAX {
+        if depth > max_depth {
             return Err(PartialVMError::new(StatusCode::VM_MAX_TYPE_DEPTH_REACHED));
         }
         let res = match self {
@@ -69,16 +69,20 @@ impl Type {
             Type::U128 => Type::U128,
             Type::Address => Type::Address,
             Type::Signer => Type::Signer,
-            Type::Vector(ty) => Type::Vector(Box::new(ty.apply_subst(subst, depth + 1)?)),
-            Type::Reference(ty) => Type::Reference(Box::new(ty.apply_subst(subst, depth + 1)?)),
+            Type::Vector(ty) => {
+                Type::Vector(Box::new(ty.apply_subst(subst, depth + 1, max_depth)?))
+            }
+            Type::Reference(ty) => {
+                Type::Reference(Box::new(ty.apply_subst(subst, depth + 1, max_depth)?))
+            }
             Type::MutableReference(ty) => {
-                Type::MutableReference(Box::new(ty.apply_subst(subst, depth + 1)?))
+                Type::MutableReference(Box::new(ty.apply_subst(subst, depth + 1, max_depth)?))
             }
             Type::Struct(def_idx) => Type::Struct(*def_idx),
             Type::StructInstantiation(def_idx, instantiation) => {
                 let mut inst = vec![];
                 for ty in instantiation {
-                    inst.push(ty.apply_subst(subst, depth + 1)?)
+                    inst.push(ty.apply_subst(subst, depth + 1, max_depth)?)
                 }
                 Type::StructInstantiation(*def_idx, inst)
             }
@@ -87,9 +91,19 @@ impl Type {
     }
 
     pub fn subst(&self, ty_args: &[Type]) -> PartialVMResult<Type> {
+        self.subst_with_max_depth(ty_args, TYPE_DEPTH_MAX)
+    }
+
+    /// Like `subst`, but fails with `VM_MAX_TYPE_DEPTH_REACHED` if the resulting type is deeper
+    /// than `max_depth` instead of the default `TYPE_DEPTH_MAX`.
+    pub fn subst_with_max_depth(
+        &self,
+        ty_args: &[Type],
+        max_depth: usize,
+    ) -> PartialVMResult<Type> {
         self.apply_subst(
             |idx, depth| match ty_args.get(idx) {
-                Some(ty) => ty.clone_impl(depth),
+                Some(ty) => ty.clone_impl(depth, max_depth),
                 None => Err(
                     PartialVMError::new(StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR)
                         .with_message(format!(
@@ -100,6 +114,7 @@ impl Type {
                 ),
             },
             1,
+            max_depth,
         )
     }
 
@@ -36,6 +36,8 @@ pub struct NativeResult {
     /// Result of execution. This is either the return values or the error to report.
     pub cost: InternalGas,
     pub result: Result<SmallVec<[Value; 1]>, u64>,
+    /// Human-readable message attached to an abort, only set when `result` is an error.
+    pub abort_message: Option<String>,
 }
 
 impl NativeResult {
@@ -44,6 +46,7 @@ impl NativeResult {
         NativeResult {
             cost,
             result: Ok(values),
+            abort_message: None,
         }
     }
 
@@ -55,6 +58,17 @@ impl NativeResult {
         NativeResult {
             cost,
             result: Err(abort_code),
+            abort_message: None,
+        }
+    }
+
+    /// Failed execution with a human-readable message attached to the abort code. The message is
+    /// reported alongside the abort code in the resulting `VMError`.
+    pub fn err_with_message(cost: InternalGas, abort_code: u64, message: String) -> Self {
+        NativeResult {
+            cost,
+            result: Err(abort_code),
+            abort_message: Some(message),
         }
     }
 
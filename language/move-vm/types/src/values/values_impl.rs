@@ -19,6 +19,7 @@ use move_core_types::{
 };
 use std::{
     cell::RefCell,
+    cmp::Ordering,
     fmt::{self, Debug, Display},
     iter,
     rc::Rc,
@@ -588,6 +589,86 @@ impl Value {
     }
 }
 
+/***************************************************************************************
+ *
+ * Ordering
+ *
+ *   The natural order of Move values stored in vectors, used by the vector natives that
+ *   sort and search. Integers, booleans and addresses are ordered as usual, while vectors
+ *   and structs are ordered lexicographically, element by element or field by field.
+ *   Errors are raised when types mismatch.
+ *
+ *   For the same reasons as for equality, Ord and PartialOrd must NOT be implemented.
+ *
+ **************************************************************************************/
+
+impl ValueImpl {
+    fn compare(&self, other: &Self) -> PartialVMResult<Ordering> {
+        use ValueImpl::*;
+
+        let res = match (self, other) {
+            (U8(l), U8(r)) => l.cmp(r),
+            (U64(l), U64(r)) => l.cmp(r),
+            (U128(l), U128(r)) => l.cmp(r),
+            (Bool(l), Bool(r)) => l.cmp(r),
+            (Address(l), Address(r)) => l.cmp(r),
+
+            (Container(l), Container(r)) => l.compare(r)?,
+
+            _ => {
+                return Err(PartialVMError::new(StatusCode::INTERNAL_TYPE_ERROR)
+                    .with_message(format!("cannot order values: {:?}, {:?}", self, other)))
+            }
+        };
+
+        Ok(res)
+    }
+}
+
+impl Container {
+    fn compare(&self, other: &Self) -> PartialVMResult<Ordering> {
+        use Container::*;
+
+        let res = match (self, other) {
+            (Vec(l), Vec(r)) | (Struct(l), Struct(r)) => {
+                let l = &*l.borrow();
+                let r = &*r.borrow();
+
+                for (v1, v2) in l.iter().zip(r.iter()) {
+                    let ord = v1.compare(v2)?;
+                    if ord != Ordering::Equal {
+                        return Ok(ord);
+                    }
+                }
+                l.len().cmp(&r.len())
+            }
+            (VecU8(l), VecU8(r)) => l.borrow().cmp(&*r.borrow()),
+            (VecU64(l), VecU64(r)) => l.borrow().cmp(&*r.borrow()),
+            (VecU128(l), VecU128(r)) => l.borrow().cmp(&*r.borrow()),
+            (VecBool(l), VecBool(r)) => l.borrow().cmp(&*r.borrow()),
+            (VecAddress(l), VecAddress(r)) => l.borrow().cmp(&*r.borrow()),
+
+            (Locals(_), _)
+            | (Vec(_), _)
+            | (Struct(_), _)
+            | (VecU8(_), _)
+            | (VecU64(_), _)
+            | (VecU128(_), _)
+            | (VecBool(_), _)
+            | (VecAddress(_), _) => {
+                return Err(
+                    PartialVMError::new(StatusCode::INTERNAL_TYPE_ERROR).with_message(format!(
+                        "cannot order container values: {:?}, {:?}",
+                        self, other
+                    )),
+                )
+            }
+        };
+
+        Ok(res)
+    }
+}
+
 /***************************************************************************************
  *
  * Read Ref
@@ -1018,6 +1099,12 @@ impl Value {
         ))))
     }
 
+    pub fn vector_struct(it: impl IntoIterator<Item = Struct>) -> Self {
+        Self(ValueImpl::Container(Container::Vec(Rc::new(RefCell::new(
+            it.into_iter().map(|s| Value::struct_(s).0).collect(),
+        )))))
+    }
+
     // REVIEW: This API can break
     pub fn vector_for_testing_only(it: impl IntoIterator<Item = Value>) -> Self {
         Self(ValueImpl::Container(Container::Vec(Rc::new(RefCell::new(
@@ -1702,6 +1789,270 @@ impl VectorRef {
         self.0.mark_dirty();
         Ok(())
     }
+
+    /// Moves the `length` elements of `from` starting at `removal_position` into `to`, so that
+    /// the first of them ends up at `insert_position`. The elements following the range in
+    /// `from`, and following `insert_position` in `to`, are shifted accordingly.
+    pub fn move_range(
+        from: &Self,
+        removal_position: usize,
+        length: usize,
+        to: &Self,
+        insert_position: usize,
+        type_param: &Type,
+    ) -> PartialVMResult<()> {
+        let from_c = from.0.container();
+        let to_c = to.0.container();
+        check_elem_layout(type_param, from_c)?;
+        check_elem_layout(type_param, to_c)?;
+
+        macro_rules! move_range {
+            ($from: expr, $to: expr) => {{
+                // The bytecode verifier rules out two mutable references to the same vector,
+                // which would otherwise be borrowed twice below.
+                if Rc::ptr_eq($from, $to) {
+                    return Err(
+                        PartialVMError::new(StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR)
+                            .with_message("cannot move a range within a vector".to_string()),
+                    );
+                }
+                let mut from_v = $from.borrow_mut();
+                let mut to_v = $to.borrow_mut();
+                let removal_end = match removal_position.checked_add(length) {
+                    Some(end) if end <= from_v.len() && insert_position <= to_v.len() => end,
+                    _ => {
+                        return Err(PartialVMError::new(StatusCode::VECTOR_OPERATION_ERROR)
+                            .with_sub_status(INDEX_OUT_OF_BOUNDS))
+                    }
+                };
+                let tail = to_v.split_off(insert_position);
+                to_v.extend(from_v.drain(removal_position..removal_end));
+                to_v.extend(tail);
+            }};
+        }
+
+        match (from_c, to_c) {
+            (Container::VecU8(f), Container::VecU8(t)) => move_range!(f, t),
+            (Container::VecU64(f), Container::VecU64(t)) => move_range!(f, t),
+            (Container::VecU128(f), Container::VecU128(t)) => move_range!(f, t),
+            (Container::VecBool(f), Container::VecBool(t)) => move_range!(f, t),
+            (Container::VecAddress(f), Container::VecAddress(t)) => move_range!(f, t),
+            (Container::Vec(f), Container::Vec(t)) => move_range!(f, t),
+            // Both layouts have been checked against the same type.
+            _ => unreachable!(),
+        }
+
+        from.0.mark_dirty();
+        to.0.mark_dirty();
+        Ok(())
+    }
+
+    /// Sorts the vector in the natural order of its elements, keeping equal elements in their
+    /// original order. Returns the comparisons made.
+    pub fn sort(&self, type_param: &Type) -> PartialVMResult<Comparisons> {
+        let c = self.0.container();
+        check_elem_layout(type_param, c)?;
+
+        let (permutation, comparisons) = c.sort_permutation()?;
+        c.permute(&permutation);
+
+        self.0.mark_dirty();
+        Ok(comparisons)
+    }
+
+    /// Sorts `keys` like `sort` does, and reorders the vector the same way, so that each
+    /// element keeps its key. Returns the comparisons made.
+    pub fn sort_by_key(
+        &self,
+        type_param: &Type,
+        keys: &Self,
+        key_type_param: &Type,
+    ) -> PartialVMResult<Comparisons> {
+        let c = self.0.container();
+        let keys_c = keys.0.container();
+        check_elem_layout(type_param, c)?;
+        check_elem_layout(key_type_param, keys_c)?;
+        if c.len() != keys_c.len() {
+            return Err(PartialVMError::new(StatusCode::VECTOR_OPERATION_ERROR)
+                .with_sub_status(INDEX_OUT_OF_BOUNDS));
+        }
+
+        let (permutation, comparisons) = keys_c.sort_permutation()?;
+        keys_c.permute(&permutation);
+        c.permute(&permutation);
+
+        self.0.mark_dirty();
+        keys.0.mark_dirty();
+        Ok(comparisons)
+    }
+
+    /// Searches the vector, which must be sorted in the natural order of its elements, for `e`.
+    /// Returns `Ok` with the index of a matching element, or `Err` with the index at which `e`
+    /// could be inserted while keeping the vector sorted, along with the comparisons made.
+    pub fn binary_search(
+        &self,
+        e: &Value,
+        type_param: &Type,
+    ) -> PartialVMResult<(Result<usize, usize>, Comparisons)> {
+        let c = self.0.container();
+        check_elem_layout(type_param, c)?;
+
+        let e_size = e.legacy_abstract_memory_size();
+        let mut comparisons = Comparisons::new();
+        let mut error = None;
+        macro_rules! binary_search {
+            ($v: expr, $cmp: expr, $size: expr) => {
+                $v.borrow().binary_search_by(|x| {
+                    comparisons.record($size(x) + e_size);
+                    match $cmp(x) {
+                        Ok(ord) => ord,
+                        Err(err) => {
+                            error.get_or_insert(err);
+                            Ordering::Equal
+                        }
+                    }
+                })
+            };
+        }
+
+        let res = match c {
+            Container::VecU8(r) => {
+                let e = e.0.as_value_ref::<u8>()?;
+                binary_search!(r, |x: &u8| Ok(x.cmp(e)), |_| LEGACY_CONST_SIZE)
+            }
+            Container::VecU64(r) => {
+                let e = e.0.as_value_ref::<u64>()?;
+                binary_search!(r, |x: &u64| Ok(x.cmp(e)), |_| LEGACY_CONST_SIZE)
+            }
+            Container::VecU128(r) => {
+                let e = e.0.as_value_ref::<u128>()?;
+                binary_search!(r, |x: &u128| Ok(x.cmp(e)), |_| LEGACY_CONST_SIZE)
+            }
+            Container::VecBool(r) => {
+                let e = e.0.as_value_ref::<bool>()?;
+                binary_search!(r, |x: &bool| Ok(x.cmp(e)), |_| LEGACY_CONST_SIZE)
+            }
+            Container::VecAddress(r) => {
+                let e = e.0.as_value_ref::<AccountAddress>()?;
+                binary_search!(r, |x: &AccountAddress| Ok(x.cmp(e)), |_| {
+                    LEGACY_ADDRESS_SIZE
+                })
+            }
+            Container::Vec(r) => {
+                binary_search!(r, |x: &ValueImpl| x.compare(&e.0), |x: &ValueImpl| x
+                    .legacy_abstract_memory_size())
+            }
+            Container::Locals(_) | Container::Struct(_) => unreachable!(),
+        };
+
+        if let Some(err) = error {
+            return Err(err);
+        }
+        Ok((res, comparisons))
+    }
+}
+
+/// The comparisons made by an operation on a vector, so that natives can charge gas both for
+/// their number and for the size of the compared values, like the `Eq` instruction does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Comparisons {
+    /// The number of comparisons.
+    pub count: u64,
+    /// The total legacy abstract memory size of the values compared, counted once per
+    /// comparison they take part in.
+    pub size: AbstractMemorySize,
+}
+
+impl Comparisons {
+    fn new() -> Self {
+        Self {
+            count: 0,
+            size: AbstractMemorySize::zero(),
+        }
+    }
+
+    fn record(&mut self, size: AbstractMemorySize) {
+        self.count += 1;
+        self.size += size;
+    }
+}
+
+impl Container {
+    /// Returns the indices of the elements of a vector container in the order that sorts them
+    /// stably, along with the comparisons made.
+    fn sort_permutation(&self) -> PartialVMResult<(Vec<usize>, Comparisons)> {
+        let mut comparisons = Comparisons::new();
+        let mut error = None;
+        macro_rules! sort_permutation {
+            ($v: expr, $cmp: expr, $size: expr) => {{
+                let v = $v.borrow();
+                let mut permutation: Vec<usize> = (0..v.len()).collect();
+                permutation.sort_by(|i, j| {
+                    comparisons.record($size(&v[*i]) + $size(&v[*j]));
+                    match $cmp(&v[*i], &v[*j]) {
+                        Ok(ord) => ord,
+                        Err(err) => {
+                            error.get_or_insert(err);
+                            Ordering::Equal
+                        }
+                    }
+                });
+                permutation
+            }};
+        }
+
+        let permutation = match self {
+            Container::VecU8(r) => {
+                sort_permutation!(r, |x: &u8, y| Ok(x.cmp(y)), |_| LEGACY_CONST_SIZE)
+            }
+            Container::VecU64(r) => {
+                sort_permutation!(r, |x: &u64, y| Ok(x.cmp(y)), |_| LEGACY_CONST_SIZE)
+            }
+            Container::VecU128(r) => {
+                sort_permutation!(r, |x: &u128, y| Ok(x.cmp(y)), |_| LEGACY_CONST_SIZE)
+            }
+            Container::VecBool(r) => {
+                sort_permutation!(r, |x: &bool, y| Ok(x.cmp(y)), |_| LEGACY_CONST_SIZE)
+            }
+            Container::VecAddress(r) => {
+                sort_permutation!(r, |x: &AccountAddress, y| Ok(x.cmp(y)), |_| {
+                    LEGACY_ADDRESS_SIZE
+                })
+            }
+            Container::Vec(r) => {
+                sort_permutation!(r, |x: &ValueImpl, y| x.compare(y), |x: &ValueImpl| x
+                    .legacy_abstract_memory_size())
+            }
+            Container::Locals(_) | Container::Struct(_) => unreachable!(),
+        };
+
+        if let Some(err) = error {
+            return Err(err);
+        }
+        Ok((permutation, comparisons))
+    }
+
+    /// Reorders the elements of a vector container so that the `i`th element is the one
+    /// previously at index `permutation[i]`.
+    fn permute(&self, permutation: &[usize]) {
+        macro_rules! permute {
+            ($v: expr) => {{
+                let mut v = $v.borrow_mut();
+                let mut elems: Vec<_> = v.drain(..).map(Some).collect();
+                v.extend(permutation.iter().map(|i| elems[*i].take().unwrap()));
+            }};
+        }
+
+        match self {
+            Container::VecU8(r) => permute!(r),
+            Container::VecU64(r) => permute!(r),
+            Container::VecU128(r) => permute!(r),
+            Container::VecBool(r) => permute!(r),
+            Container::VecAddress(r) => permute!(r),
+            Container::Vec(r) => permute!(r),
+            Container::Locals(_) | Container::Struct(_) => unreachable!(),
+        }
+    }
 }
 
 impl Vector {
@@ -1832,6 +2183,10 @@ pub(crate) const LEGACY_REFERENCE_SIZE: AbstractMemorySize = AbstractMemorySize:
 /// The size of a struct in bytes
 pub(crate) const LEGACY_STRUCT_SIZE: AbstractMemorySize = AbstractMemorySize::new(2);
 
+/// The size in bytes of an address
+const LEGACY_ADDRESS_SIZE: AbstractMemorySize =
+    AbstractMemorySize::new(AccountAddress::LENGTH as u64);
+
 impl Container {
     #[cfg(test)]
     fn legacy_size(&self) -> AbstractMemorySize {
@@ -294,16 +294,22 @@ pub fn format_vm_error(e: &VMError) -> String {
         Location::Script => "script".to_owned(),
         Location::Module(id) => format!("0x{}::{}", id.address().short_str_lossless(), id.name()),
     };
+    // Only shown when present, so that the output for plain aborts is unchanged
+    let abort_message_string = match e.abort_message() {
+        Some(message) => format!("\n    abort_message: {:?},", message),
+        None => "".to_owned(),
+    };
     format!(
         "{{
     major_status: {major_status:?},
-    sub_status: {sub_status:?},
+    sub_status: {sub_status:?},{abort_message_string}
     location: {location_string},
     indices: {indices:?},
     offsets: {offsets:?},
 }}",
         major_status = e.major_status(),
         sub_status = e.sub_status(),
+        abort_message_string = abort_message_string,
         location_string = location_string,
         // TODO maybe include source map info?
         indices = e.indices(),
@@ -0,0 +1,58 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{bail, Context};
+use clap::*;
+use move_binary_format::{compatibility::Compatibility, normalized, CompiledModule};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Check that the module in `new` is a backward compatible update of the module in `old`, and
+/// report every breaking change.
+#[derive(Parser)]
+#[clap(name = "compat")]
+pub struct Compat {
+    /// Path to the bytecode of the old version of the module.
+    #[clap(name = "old", parse(from_os_str))]
+    pub old: PathBuf,
+    /// Path to the bytecode of the new version of the module.
+    #[clap(name = "new", parse(from_os_str))]
+    pub new: PathBuf,
+    /// Treat friend functions as private, i.e., allow them to be changed or removed.
+    #[clap(long = "no-friend-linking")]
+    pub no_friend_linking: bool,
+}
+
+impl Compat {
+    pub fn execute(self) -> anyhow::Result<()> {
+        let old = read_module(&self.old)?;
+        let new = read_module(&self.new)?;
+        let incompatibilities = Compatibility::incompatibilities(
+            !self.no_friend_linking,
+            &normalized::Module::new(&old),
+            &normalized::Module::new(&new),
+        );
+        if incompatibilities.is_empty() {
+            println!("The new module is compatible with the old one");
+            return Ok(());
+        }
+        let mut report = format!(
+            "The new module has {} breaking change(s):",
+            incompatibilities.len()
+        );
+        for incompatibility in &incompatibilities {
+            report.push_str("\n  - ");
+            report.push_str(&incompatibility.to_string());
+        }
+        bail!(report)
+    }
+}
+
+fn read_module(path: &Path) -> anyhow::Result<CompiledModule> {
+    let bytes =
+        fs::read(path).with_context(|| format!("Unable to read {}", path.to_string_lossy()))?;
+    CompiledModule::deserialize(&bytes)
+        .map_err(|e| anyhow::anyhow!("Unable to deserialize {}: {}", path.to_string_lossy(), e))
+}
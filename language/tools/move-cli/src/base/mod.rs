@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod build;
+pub mod compat;
 pub mod coverage;
 pub mod disassemble;
 pub mod docgen;
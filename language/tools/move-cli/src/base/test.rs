@@ -69,6 +69,9 @@ pub struct Test {
     /// the execution result from Move VM.
     #[clap(long = "stackless")]
     pub check_stackless_vm: bool,
+    /// Seed of the generator drawn from by `std::random`, which is reseeded for every test.
+    #[clap(long = "seed", default_value = "0")]
+    pub seed: u64,
     /// Verbose mode
     #[clap(long = "verbose")]
     pub verbose_mode: bool,
@@ -100,6 +103,7 @@ impl Test {
             report_storage_on_error,
             ignore_compile_warnings,
             check_stackless_vm,
+            seed,
             verbose_mode,
             compute_coverage,
             #[cfg(feature = "evm-backend")]
@@ -113,6 +117,7 @@ impl Test {
             report_statistics,
             report_storage_on_error,
             check_stackless_vm,
+            seed,
             verbose: verbose_mode,
             ignore_compile_warnings,
             #[cfg(feature = "evm-backend")]
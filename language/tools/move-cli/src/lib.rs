@@ -3,9 +3,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use base::{
-    build::Build, coverage::Coverage, disassemble::Disassemble, docgen::Docgen, errmap::Errmap,
-    info::Info, movey_login::MoveyLogin, movey_upload::MoveyUpload, new::New, prove::Prove,
-    test::Test,
+    build::Build, compat::Compat, coverage::Coverage, disassemble::Disassemble, docgen::Docgen,
+    errmap::Errmap, info::Info, movey_login::MoveyLogin, movey_upload::MoveyUpload, new::New,
+    prove::Prove, test::Test,
 };
 use move_package::BuildConfig;
 
@@ -65,6 +65,7 @@ pub struct MoveCLI {
 #[derive(Parser)]
 pub enum Command {
     Build(Build),
+    Compat(Compat),
     Coverage(Coverage),
     Disassemble(Disassemble),
     Docgen(Docgen),
@@ -110,6 +111,7 @@ pub fn run_cli(
     //         2. The CostTable only affects sandbox runs, but not unit tests, which use a unit cost table.
     match cmd {
         Command::Build(c) => c.execute(move_args.package_path, move_args.build_config),
+        Command::Compat(c) => c.execute(),
         Command::Coverage(c) => c.execute(move_args.package_path, move_args.build_config),
         Command::Disassemble(c) => c.execute(move_args.package_path, move_args.build_config),
         Command::Docgen(c) => c.execute(move_args.package_path, move_args.build_config),
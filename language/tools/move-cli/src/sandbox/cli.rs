@@ -12,7 +12,9 @@ use crate::{
 use anyhow::Result;
 use clap::Parser;
 use move_core_types::{
-    errmap::ErrorMapping, language_storage::TypeTag, parser,
+    errmap::ErrorMapping,
+    language_storage::{StructTag, TypeTag},
+    parser,
     transaction_argument::TransactionArgument,
 };
 use move_package::compilation::package_layout::CompiledPackageLayout;
@@ -36,6 +38,10 @@ pub enum SandboxCommand {
         /// Set this flag to ignore breaking changes checks and publish anyway.
         #[clap(long = "ignore-breaking-changes")]
         ignore_breaking_changes: bool,
+        /// Instead of publishing, report every breaking change of the modules with respect to
+        /// the versions already published in storage.
+        #[clap(long = "check-compat")]
+        check_compat: bool,
         /// If set, publish not only the modules in this package but also
         /// modules in all its dependencies.
         #[clap(long = "with-deps")]
@@ -80,8 +86,11 @@ pub enum SandboxCommand {
         /// bool literals (true, false),
         /// u64 literals (e.g., 10, 58),
         /// address literals (e.g., 0x12, 0x0000000000000000000000000000000f),
-        /// hexadecimal strings (e.g., x"0012" will parse as the vector<u8> value [00, 12]), and
-        /// ASCII strings (e.g., 'b"hi" will parse as the vector<u8> value [68, 69]).
+        /// hexadecimal strings (e.g., x"0012" will parse as the vector<u8> value [00, 12]),
+        /// ASCII strings (e.g., 'b"hi" will parse as the vector<u8> value [68, 69]),
+        /// UTF-8 strings (e.g., '"hi"' will parse as the std::string::String value "hi"), and
+        /// vectors of the above (e.g., 'vector[1, 2]'). An std::option::Option is passed as a
+        /// vector of at most one element.
         #[clap(
             long = "args",
             parse(try_from_str = parser::parse_transaction_argument),
@@ -109,6 +118,21 @@ pub enum SandboxCommand {
         /// deleted resources) will NOT be committed to disk.
         #[clap(long = "dry-run", short = 'n')]
         dry_run: bool,
+        /// Seed of the generator drawn from by `std::random`.
+        #[clap(long = "seed", default_value = "0")]
+        seed: u64,
+        /// If set, record the call and all the data it reads from storage into this file, so that
+        /// it can be replayed with `sandbox replay`.
+        #[clap(long = "record", parse(from_os_str))]
+        record: Option<PathBuf>,
+    },
+    /// Replay a call recorded with `sandbox run --record` against the recorded data only, and
+    /// report every difference with the recorded effects.
+    #[clap(name = "replay")]
+    Replay {
+        /// Path to the recording.
+        #[clap(name = "recording", parse(from_os_str))]
+        recording: PathBuf,
     },
     /// Run expected value tests using the given batch file.
     #[clap(name = "exp-test")]
@@ -126,8 +150,20 @@ pub enum SandboxCommand {
     #[clap(name = "view")]
     View {
         /// Path to a resource, events file, or module stored on disk.
-        #[clap(name = "file", parse(from_os_str))]
-        file: PathBuf,
+        #[clap(name = "file", parse(from_os_str), required_unless_present = "events")]
+        file: Option<PathBuf>,
+        /// View the payloads of events with their field names. Views all the events stored on
+        /// disk, unless `file` is set.
+        #[clap(long = "events")]
+        events: bool,
+        /// Only view the events of this type, e.g. `0x2::M::E`. A type without type arguments
+        /// matches all the instantiations of a generic type.
+        #[clap(
+            long = "type",
+            requires = "events",
+            parse(try_from_str = parser::parse_struct_tag)
+        )]
+        type_: Option<StructTag>,
     },
     /// Delete all resources, events, and modules stored on disk under `storage-dir`.
     /// Does *not* delete anything in `src`.
@@ -192,6 +228,7 @@ impl SandboxCommand {
             SandboxCommand::Publish {
                 no_republish,
                 ignore_breaking_changes,
+                check_compat,
                 with_deps,
                 bundle,
                 override_ordering,
@@ -206,6 +243,7 @@ impl SandboxCommand {
                     context.package(),
                     *no_republish,
                     *ignore_breaking_changes,
+                    *check_compat,
                     *with_deps,
                     *bundle,
                     override_ordering.as_ref().map(|o| o.as_slice()),
@@ -220,6 +258,8 @@ impl SandboxCommand {
                 type_args,
                 gas_budget,
                 dry_run,
+                seed,
+                record,
             } => {
                 let context =
                     PackageContext::new(&move_args.package_path, &move_args.build_config)?;
@@ -237,9 +277,14 @@ impl SandboxCommand {
                     type_args.to_vec(),
                     *gas_budget,
                     *dry_run,
+                    *seed,
+                    record.as_deref(),
                     move_args.verbose,
                 )
             }
+            SandboxCommand::Replay { recording } => {
+                sandbox::commands::replay(natives, cost_table, recording)
+            }
             SandboxCommand::Test {
                 use_temp_dir,
                 track_cov,
@@ -252,10 +297,18 @@ impl SandboxCommand {
                 *use_temp_dir,
                 *track_cov,
             ),
-            SandboxCommand::View { file } => {
+            SandboxCommand::View {
+                file,
+                events,
+                type_,
+            } => {
                 let state = PackageContext::new(&move_args.package_path, &move_args.build_config)?
                     .prepare_state(storage_dir)?;
-                sandbox::commands::view(&state, file)
+                if *events {
+                    sandbox::commands::view_events(&state, file.as_deref(), type_.as_ref())
+                } else {
+                    sandbox::commands::view(&state, file.as_ref().unwrap())
+                }
             }
             SandboxCommand::Clean {} => {
                 // delete storage
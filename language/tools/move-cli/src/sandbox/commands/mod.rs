@@ -5,12 +5,14 @@
 pub mod doctor;
 pub mod generate;
 pub mod publish;
+pub mod replay;
 pub mod run;
 pub mod test;
 pub mod view;
 
 pub use doctor::*;
 pub use publish::*;
+pub use replay::*;
 pub use run::*;
 pub use test::*;
 pub use view::*;
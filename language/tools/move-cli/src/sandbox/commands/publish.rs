@@ -10,7 +10,8 @@ use crate::{
     NativeFunctionRecord,
 };
 use anyhow::{bail, Result};
-use move_binary_format::errors::Location;
+use move_binary_format::{compatibility::Compatibility, errors::Location, normalized};
+use move_bytecode_utils::module_cache::GetModule;
 use move_command_line_common::env::get_bytecode_version_from_env;
 use move_package::compilation::compiled_package::CompiledPackage;
 use move_vm_runtime::move_vm::MoveVM;
@@ -24,6 +25,7 @@ pub fn publish(
     package: &CompiledPackage,
     no_republish: bool,
     ignore_breaking_changes: bool,
+    check_compat: bool,
     with_deps: bool,
     bundle: bool,
     override_ordering: Option<&[String]>,
@@ -61,6 +63,45 @@ pub fn publish(
         None => compiled_modules,
     };
 
+    if check_compat {
+        let mut breaking = vec![];
+        for unit in &modules_to_publish {
+            let new_module = module(&unit.unit)?;
+            let id = new_module.self_id();
+            let old_module = match state.get_module_by_id(&id)? {
+                Some(old_module) => old_module,
+                None => {
+                    println!("Module {} is not published yet", id);
+                    continue;
+                }
+            };
+            let incompatibilities = Compatibility::incompatibilities(
+                true,
+                &normalized::Module::new(&old_module),
+                &normalized::Module::new(new_module),
+            );
+            if incompatibilities.is_empty() {
+                println!("Module {} is compatible with the published version", id);
+            } else {
+                println!(
+                    "Module {} is not compatible with the published version:",
+                    id
+                );
+                for incompatibility in incompatibilities {
+                    println!("  - {}", incompatibility);
+                }
+                breaking.push(id.to_string());
+            }
+        }
+        if !breaking.is_empty() {
+            bail!(
+                "Breaking changes found in module(s): {}",
+                breaking.join(", ")
+            );
+        }
+        return Ok(());
+    }
+
     if no_republish {
         let republished = modules_to_publish
             .iter()
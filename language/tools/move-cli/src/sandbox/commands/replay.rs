@@ -0,0 +1,248 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{sandbox::utils::get_gas_status, NativeFunctionRecord};
+use anyhow::{bail, Context, Result};
+use move_binary_format::errors::VMResult;
+use move_core_types::{
+    account_address::AccountAddress,
+    effects::{ChangeSet, Event, Op},
+    identifier::Identifier,
+    language_storage::{ModuleId, StructTag, TypeTag},
+    resolver::MoveResolver,
+    vm_status::VMStatus,
+};
+use move_stdlib::natives::random::NativeRandomContext;
+use move_vm_runtime::{
+    move_vm::MoveVM, native_extensions::NativeContextExtensions, session::Session,
+};
+use move_vm_test_utils::{gas_schedule::CostTable, RecordedReads, ReplayStorage};
+use move_vm_types::gas::GasMeter;
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, fs, path::Path};
+
+/// A session recorded by `sandbox run --record`, with all the data it read from storage, so that
+/// it can be replayed without the storage it ran against.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SessionRecording {
+    /// The script or entry function which was executed.
+    pub entry: RecordedEntry,
+    pub type_args: Vec<TypeTag>,
+    /// The serialized arguments, signers included.
+    pub args: Vec<Vec<u8>>,
+    pub gas_budget: Option<u64>,
+    /// The seed of the generator drawn from by `std::random`.
+    pub seed: u64,
+    pub reads: RecordedReads,
+    /// The effects of the session, or the status it failed with.
+    pub outcome: Result<(ChangeSet, Vec<Event>), VMStatus>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum RecordedEntry {
+    Script(Vec<u8>),
+    Function { module: ModuleId, name: Identifier },
+}
+
+impl SessionRecording {
+    pub fn read(path: &Path) -> Result<Self> {
+        let bytes =
+            fs::read(path).with_context(|| format!("Unable to read {}", path.to_string_lossy()))?;
+        bcs::from_bytes(&bytes)
+            .with_context(|| format!("Unable to deserialize {}", path.to_string_lossy()))
+    }
+
+    pub fn write(&self, path: &Path) -> Result<()> {
+        fs::write(path, bcs::to_bytes(self)?)
+            .with_context(|| format!("Unable to write {}", path.to_string_lossy()))
+    }
+}
+
+/// Execute `entry` in `session`.
+pub(crate) fn execute_entry<S: MoveResolver>(
+    session: &mut Session<S>,
+    entry: &RecordedEntry,
+    type_args: Vec<TypeTag>,
+    args: Vec<Vec<u8>>,
+    gas_meter: &mut impl GasMeter,
+) -> VMResult<()> {
+    match entry {
+        RecordedEntry::Script(script) => session
+            .execute_script(script.clone(), type_args, args, gas_meter)
+            .map(|_| ()),
+        RecordedEntry::Function { module, name } => session
+            .execute_entry_function(module, name, type_args, args, gas_meter)
+            .map(|_| ()),
+    }
+}
+
+/// Replay the session recorded in `recording_path` against the data it read only. Fails with a
+/// report of every difference between the recorded effects and the effects of the replay, if
+/// there is any.
+pub fn replay(
+    natives: impl IntoIterator<Item = NativeFunctionRecord>,
+    cost_table: &CostTable,
+    recording_path: &Path,
+) -> Result<()> {
+    let SessionRecording {
+        entry,
+        type_args,
+        args,
+        gas_budget,
+        seed,
+        reads,
+        outcome,
+    } = SessionRecording::read(recording_path)?;
+
+    let storage = ReplayStorage::new(reads);
+    let vm = MoveVM::new(natives).unwrap();
+    let mut gas_status = get_gas_status(cost_table, gas_budget)?;
+    let mut extensions = NativeContextExtensions::default();
+    extensions.add(NativeRandomContext::new(seed));
+    let mut session = vm.new_session_with_extensions(&storage, extensions);
+    let replayed = match execute_entry(&mut session, &entry, type_args, args, &mut gas_status) {
+        Ok(()) => session.finish().map_err(|e| e.into_vm_status()),
+        Err(err) => Err(err.into_vm_status()),
+    };
+
+    let differences = diff_outcomes(&outcome, &replayed);
+    if differences.is_empty() {
+        println!("The replay matches the recording");
+        return Ok(());
+    }
+    let mut report = format!(
+        "The replay differs from the recording in {} place(s):",
+        differences.len()
+    );
+    for difference in &differences {
+        report.push_str("\n  - ");
+        report.push_str(difference);
+    }
+    bail!(report)
+}
+
+fn diff_outcomes(
+    recorded: &Result<(ChangeSet, Vec<Event>), VMStatus>,
+    replayed: &Result<(ChangeSet, Vec<Event>), VMStatus>,
+) -> Vec<String> {
+    match (recorded, replayed) {
+        (Ok((recorded_changes, recorded_events)), Ok((replayed_changes, replayed_events))) => {
+            let mut differences = diff_changes(recorded_changes, replayed_changes);
+            differences.extend(diff_events(recorded_events, replayed_events));
+            differences
+        }
+        (Err(recorded), Err(replayed)) if recorded == replayed => vec![],
+        (recorded, replayed) => vec![format!(
+            "outcome: recorded {}, replayed {}",
+            describe_outcome(recorded),
+            describe_outcome(replayed)
+        )],
+    }
+}
+
+fn describe_outcome(outcome: &Result<(ChangeSet, Vec<Event>), VMStatus>) -> String {
+    match outcome {
+        Ok(_) => "success".to_string(),
+        Err(status) => format!("{:?}", status),
+    }
+}
+
+fn diff_changes(recorded: &ChangeSet, replayed: &ChangeSet) -> Vec<String> {
+    let mut differences = diff_maps(
+        "module",
+        recorded
+            .modules()
+            .map(|(addr, name, op)| (ModuleId::new(addr, name.clone()), op))
+            .collect(),
+        replayed
+            .modules()
+            .map(|(addr, name, op)| (ModuleId::new(addr, name.clone()), op))
+            .collect(),
+    );
+    differences.extend(diff_maps(
+        "resource",
+        recorded
+            .resources()
+            .map(|(addr, tag, op)| (ResourceKey(addr, tag.clone()), op))
+            .collect(),
+        replayed
+            .resources()
+            .map(|(addr, tag, op)| (ResourceKey(addr, tag.clone()), op))
+            .collect(),
+    ));
+    differences
+}
+
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+struct ResourceKey(AccountAddress, StructTag);
+
+impl std::fmt::Display for ResourceKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} at {}", self.1, self.0)
+    }
+}
+
+fn diff_maps<K: Ord + std::fmt::Display>(
+    kind: &str,
+    recorded: BTreeMap<K, Op<&[u8]>>,
+    mut replayed: BTreeMap<K, Op<&[u8]>>,
+) -> Vec<String> {
+    let mut differences = vec![];
+    for (key, recorded_op) in recorded {
+        match replayed.remove(&key) {
+            Some(replayed_op) if replayed_op == recorded_op => (),
+            replayed_op => differences.push(format!(
+                "{} {}: recorded {}, replayed {}",
+                kind,
+                key,
+                describe_op(Some(recorded_op)),
+                describe_op(replayed_op)
+            )),
+        }
+    }
+    for (key, replayed_op) in replayed {
+        differences.push(format!(
+            "{} {}: recorded {}, replayed {}",
+            kind,
+            key,
+            describe_op(None),
+            describe_op(Some(replayed_op))
+        ))
+    }
+    differences
+}
+
+fn describe_op(op: Option<Op<&[u8]>>) -> String {
+    match op {
+        None => "no change".to_string(),
+        Some(Op::New(blob)) => format!("new {:?}", blob),
+        Some(Op::Modify(blob)) => format!("modified to {:?}", blob),
+        Some(Op::Delete) => "deleted".to_string(),
+    }
+}
+
+fn diff_events(recorded: &[Event], replayed: &[Event]) -> Vec<String> {
+    let mut differences = vec![];
+    for idx in 0..recorded.len().max(replayed.len()) {
+        let (recorded, replayed) = (recorded.get(idx), replayed.get(idx));
+        if recorded != replayed {
+            differences.push(format!(
+                "event {}: recorded {}, replayed {}",
+                idx,
+                describe_event(recorded),
+                describe_event(replayed)
+            ))
+        }
+    }
+    differences
+}
+
+fn describe_event(event: Option<&Event>) -> String {
+    match event {
+        None => "no event".to_string(),
+        Some((key, sequence_number, ty, data)) => format!(
+            "{} #{} to stream {:?} with data {:?}",
+            ty, sequence_number, key, data
+        ),
+    }
+}
@@ -3,9 +3,12 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    sandbox::utils::{
-        contains_module, explain_execution_effects, explain_execution_error, get_gas_status,
-        is_bytecode_file, maybe_commit_effects, on_disk_state_view::OnDiskStateView,
+    sandbox::{
+        commands::replay::{execute_entry, RecordedEntry, SessionRecording},
+        utils::{
+            contains_module, explain_execution_effects, explain_execution_error, get_gas_status,
+            is_bytecode_file, maybe_commit_effects, on_disk_state_view::OnDiskStateView,
+        },
     },
     NativeFunctionRecord,
 };
@@ -15,16 +18,18 @@ use move_command_line_common::env::get_bytecode_version_from_env;
 use move_core_types::{
     account_address::AccountAddress,
     errmap::ErrorMapping,
-    identifier::IdentStr,
+    identifier::Identifier,
     language_storage::TypeTag,
     transaction_argument::{convert_txn_args, TransactionArgument},
     value::MoveValue,
 };
 use move_package::compilation::compiled_package::CompiledPackage;
-use move_vm_runtime::move_vm::MoveVM;
-use move_vm_test_utils::gas_schedule::CostTable;
+use move_stdlib::natives::random::NativeRandomContext;
+use move_vm_runtime::{move_vm::MoveVM, native_extensions::NativeContextExtensions};
+use move_vm_test_utils::{gas_schedule::CostTable, RecordingStorage};
 use std::{fs, path::Path};
 
+#[allow(clippy::too_many_arguments)]
 pub fn run(
     natives: impl IntoIterator<Item = NativeFunctionRecord>,
     cost_table: &CostTable,
@@ -38,6 +43,8 @@ pub fn run(
     vm_type_args: Vec<TypeTag>,
     gas_budget: Option<u64>,
     dry_run: bool,
+    seed: u64,
+    record_path: Option<&Path>,
     verbose: bool,
 ) -> Result<()> {
     if !script_path.exists() {
@@ -76,12 +83,15 @@ move run` must be applied to a module inside `storage/`",
 
     let vm = MoveVM::new(natives).unwrap();
     let mut gas_status = get_gas_status(cost_table, gas_budget)?;
-    let mut session = vm.new_session(state);
+    let storage = RecordingStorage::new(state);
+    let mut extensions = NativeContextExtensions::default();
+    extensions.add(NativeRandomContext::new(seed));
+    let mut session = vm.new_session_with_extensions(&storage, extensions);
 
     let script_type_parameters = vec![];
     let script_parameters = vec![];
     // TODO rethink move-cli arguments for executing functions
-    let vm_args = signer_addresses
+    let vm_args: Vec<Vec<u8>> = signer_addresses
         .iter()
         .map(|a| {
             MoveValue::Signer(*a)
@@ -90,29 +100,47 @@ move run` must be applied to a module inside `storage/`",
         })
         .chain(vm_args)
         .collect();
-    let res = match script_name_opt {
+    let entry = match script_name_opt {
         Some(script_name) => {
             // script fun. parse module, extract script ID to pass to VM
             let module = CompiledModule::deserialize(&bytecode)
                 .map_err(|e| anyhow!("Error deserializing module: {:?}", e))?;
-            session.execute_entry_function(
-                &module.self_id(),
-                IdentStr::new(script_name)?,
-                vm_type_args.clone(),
-                vm_args,
-                &mut gas_status,
-            )
+            RecordedEntry::Function {
+                module: module.self_id(),
+                name: Identifier::new(script_name.as_str())?,
+            }
+        }
+        None => RecordedEntry::Script(bytecode),
+    };
+    let res = match execute_entry(
+        &mut session,
+        &entry,
+        vm_type_args.clone(),
+        vm_args.clone(),
+        &mut gas_status,
+    ) {
+        Ok(()) => Ok(session.finish().map_err(|e| e.into_vm_status())?),
+        Err(err) => {
+            drop(session);
+            Err(err)
         }
-        None => session.execute_script(
-            bytecode.to_vec(),
-            vm_type_args.clone(),
-            vm_args,
-            &mut gas_status,
-        ),
     };
 
-    if let Err(err) = res {
-        explain_execution_error(
+    if let Some(record_path) = record_path {
+        SessionRecording {
+            entry,
+            type_args: vm_type_args.clone(),
+            args: vm_args,
+            gas_budget,
+            seed,
+            reads: storage.into_reads(),
+            outcome: res.clone().map_err(|err| err.into_vm_status()),
+        }
+        .write(record_path)?;
+    }
+
+    match res {
+        Err(err) => explain_execution_error(
             error_descriptions,
             err,
             state,
@@ -121,12 +149,12 @@ move run` must be applied to a module inside `storage/`",
             &vm_type_args,
             &signer_addresses,
             txn_args,
-        )
-    } else {
-        let (changeset, events) = session.finish().map_err(|e| e.into_vm_status())?;
-        if verbose {
-            explain_execution_effects(&changeset, &events, state)?
+        ),
+        Ok((changeset, events)) => {
+            if verbose {
+                explain_execution_effects(&changeset, &events, state)?
+            }
+            maybe_commit_effects(!dry_run, changeset, events, state)
         }
-        maybe_commit_effects(!dry_run, changeset, events, state)
     }
 }
@@ -7,6 +7,7 @@ use crate::sandbox::utils::{
 };
 
 use anyhow::{bail, Result};
+use move_core_types::language_storage::StructTag;
 use std::path::Path;
 /// Print a module or resource stored in `file`
 pub fn view(state: &OnDiskStateView, path: &Path) -> Result<()> {
@@ -40,3 +41,26 @@ pub fn view(state: &OnDiskStateView, path: &Path) -> Result<()> {
     }
     Ok(())
 }
+
+/// Print the events stored in `path`, or all the events stored on disk if it is not set. If
+/// `type_` is set, only print the events of that type.
+pub fn view_events(
+    state: &OnDiskStateView,
+    path: Option<&Path>,
+    type_: Option<&StructTag>,
+) -> Result<()> {
+    if let Some(path) = path {
+        if !state.is_event_path(path) {
+            bail!("`move sandbox view --events <file>` must point to an events file under storage")
+        }
+    }
+    let events = state.view_annotated_events(path, type_)?;
+    if events.is_empty() {
+        println!("Events not found.")
+    } else {
+        for event in events {
+            println!("{}", event)
+        }
+    }
+    Ok(())
+}
@@ -11,7 +11,9 @@ use move_binary_format::{
     compatibility::Compatibility,
     errors::VMError,
     file_format::{AbilitySet, CompiledModule, FunctionDefinitionIndex, SignatureToken},
-    normalized, IndexKind,
+    normalized,
+    upgrade_policy::UpgradePolicy,
+    IndexKind,
 };
 use move_bytecode_utils::Modules;
 use move_command_line_common::files::{FileHash, MOVE_COMPILED_EXTENSION};
@@ -347,9 +349,10 @@ pub(crate) fn explain_publish_error(
             let old_module = state.get_module_by_id(&module_id)?.unwrap();
             let old_api = normalized::Module::new(&old_module);
             let new_api = normalized::Module::new(module);
-            let compat = Compatibility::check(true, &old_api, &new_api);
+            let incompatibilities = Compatibility::incompatibilities(true, &old_api, &new_api);
             // the only way we get this error code is compatibility checking failed, so assert here
-            assert!(!compat.is_fully_compatible());
+            assert!(!incompatibilities.is_empty());
+            let compat = Compatibility::check(true, &old_api, &new_api);
 
             if !compat.struct_layout {
                 // TODO: we could choose to make this more precise by walking the global state and looking for published
@@ -360,6 +363,37 @@ pub(crate) fn explain_publish_error(
                 // but this is not easy to check without walking the global state and looking for everything
                 println!("Linking API for structs/functions of module {} has changed. Need to redeploy all dependent modules.", module_id)
             }
+            for incompatibility in incompatibilities {
+                println!("  - {}", incompatibility);
+            }
+        }
+        VMStatus::Error(IMMUTABLE_MODULE_UPDATE) => {
+            println!(
+                "Module {} was published with the immutable upgrade policy and cannot be updated.",
+                module_id
+            );
+        }
+        VMStatus::Error(NON_ADDITIVE_MODULE_UPDATE) => {
+            println!(
+                "Module {} was published with the additive upgrade policy: only new \
+                 declarations can be added to it.",
+                module_id
+            );
+        }
+        VMStatus::Error(UPGRADE_POLICY_WEAKENED) => {
+            let old_module = state.get_module_by_id(&module_id)?.unwrap();
+            println!(
+                "The upgrade policy of module {} cannot be changed from {} to {}.",
+                module_id,
+                UpgradePolicy::from_module(&old_module).unwrap_or_default(),
+                UpgradePolicy::from_module(module).unwrap_or_default(),
+            );
+        }
+        VMStatus::Error(MALFORMED_UPGRADE_POLICY) => {
+            println!(
+                "The upgrade policy in the metadata of module {} is malformed.",
+                module_id
+            );
         }
         VMStatus::Error(CYCLIC_MODULE_DEPENDENCY) => {
             println!(
@@ -461,6 +495,7 @@ pub(crate) fn explain_execution_error(
     txn_args: &[TransactionArgument],
 ) -> Result<()> {
     use StatusCode::*;
+    let abort_message = error.abort_message().cloned();
     match error.into_vm_status() {
         VMStatus::MoveAbort(AbortLocation::Module(id), abort_code) => {
             // try to use move-explain to explain the abort
@@ -478,13 +513,19 @@ pub(crate) fn explain_execution_error(
             } else {
                 println!()
             }
+            if let Some(message) = abort_message {
+                println!("Abort message: {}", message)
+            }
         }
         VMStatus::MoveAbort(AbortLocation::Script, abort_code) => {
             // TODO: map to source code location
             println!(
                 "Execution aborted with code {} in transaction script",
                 abort_code
-            )
+            );
+            if let Some(message) = abort_message {
+                println!("Abort message: {}", message)
+            }
         }
         VMStatus::ExecutionFailure {
             status_code,
@@ -20,7 +20,9 @@ use move_core_types::{
 };
 use move_disassembler::disassembler::Disassembler;
 use move_ir_types::location::Spanned;
-use move_resource_viewer::{AnnotatedMoveStruct, AnnotatedMoveValue, MoveValueAnnotator};
+use move_resource_viewer::{
+    AnnotatedEvent, AnnotatedMoveStruct, AnnotatedMoveValue, MoveValueAnnotator,
+};
 use std::{
     convert::{TryFrom, TryInto},
     fs,
@@ -239,6 +241,37 @@ impl OnDiskStateView {
             .collect()
     }
 
+    /// View the events in `events_path`, or in all the event files if it is not set. If `tag` is
+    /// set, only the events of that type are returned.
+    pub fn view_annotated_events(
+        &self,
+        events_path: Option<&Path>,
+        tag: Option<&StructTag>,
+    ) -> Result<Vec<AnnotatedEvent>> {
+        let paths = match events_path {
+            Some(path) => vec![path.to_path_buf()],
+            None => {
+                let mut paths: Vec<_> = self.event_paths().collect();
+                paths.sort();
+                paths
+            }
+        };
+        let annotator = MoveValueAnnotator::new(self);
+        let mut annotated_events = vec![];
+        for path in paths {
+            let events = self.get_events(&path)?;
+            match tag {
+                Some(tag) => annotated_events.extend(annotator.view_events_of_type(&events, tag)?),
+                None => {
+                    for event in &events {
+                        annotated_events.push(annotator.view_event(event)?)
+                    }
+                }
+            }
+        }
+        Ok(annotated_events)
+    }
+
     fn view_bytecode(path: &Path, is_module: bool) -> Result<Option<String>> {
         if path.is_dir() {
             bail!("Bad bytecode path {:?}. Needed file, found directory", path)
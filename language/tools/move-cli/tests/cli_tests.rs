@@ -3,7 +3,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use httpmock::{prelude::*, Mock};
-use move_cli::sandbox::commands::test;
+use move_cli::sandbox::commands::{test, SessionRecording};
 use move_command_line_common::{
     files,
     movey_constants::{MOVEY_CREDENTIAL_PATH, MOVEY_URL},
@@ -69,6 +69,53 @@ fn cross_process_locking_git_deps() {
     handle.join().unwrap();
 }
 
+#[test]
+fn replay_reports_divergence() {
+    let cli_exe = env!("CARGO_BIN_EXE_move");
+    let package_path = "./tests/replay_tests/diverging_replay";
+    let move_cmd = |args: &[&str]| {
+        Command::new(cli_exe)
+            .current_dir(package_path)
+            .args(args)
+            .output()
+            .unwrap()
+    };
+    let recording_path = PathBuf::from(package_path).join("storage/roll.rec");
+
+    move_cmd(&["sandbox", "clean"]);
+    assert!(move_cmd(&["sandbox", "publish"]).status.success());
+    let output = move_cmd(&[
+        "sandbox",
+        "run",
+        "storage/0x00000000000000000000000000000002/modules/Dice.mv",
+        "roll",
+        "--signers",
+        "0xA",
+        "--seed",
+        "7",
+        "--record",
+        "storage/roll.rec",
+    ]);
+    assert!(output.status.success());
+    let output = move_cmd(&["sandbox", "replay", "storage/roll.rec"]);
+    assert!(output.status.success());
+
+    // with another seed, the replay rolls another value
+    let mut recording = SessionRecording::read(&recording_path).unwrap();
+    recording.seed = 3;
+    recording.write(&recording_path).unwrap();
+    let output = move_cmd(&["sandbox", "replay", "storage/roll.rec"]);
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("The replay differs from the recording in 1 place(s):"));
+    assert!(stderr.contains(
+        "  - resource 0x2::Dice::Roll at 0000000000000000000000000000000a: \
+        recorded new [3, 0, 0, 0, 0, 0, 0, 0], replayed new [1, 0, 0, 0, 0, 0, 0, 0]"
+    ));
+
+    move_cmd(&["sandbox", "clean"]);
+}
+
 const UPLOAD_PACKAGE_PATH: &str = "./tests/upload_tests";
 #[test]
 fn upload_package_to_movey_works() {
@@ -0,0 +1,9 @@
+module 0x2::Dice {
+    use std::random;
+
+    struct Roll has key { value: u64 }
+
+    public entry fun roll(account: signer) {
+        move_to(&account, Roll { value: random::u64_range(1, 7) });
+    }
+}
@@ -0,0 +1,7 @@
+module 0x42::M {
+    struct S has key, store { f: u64 }
+
+    public fun f(): u64 { 0 }
+
+    public fun g() {}
+}
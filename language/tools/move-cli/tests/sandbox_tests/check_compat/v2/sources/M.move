@@ -0,0 +1,5 @@
+module 0x42::M {
+    struct S has key { f: u64, g: bool }
+
+    public fun f(x: u64): u64 { x }
+}
@@ -0,0 +1,3 @@
+module 0x42::A {
+    public fun f(): u64 { 0 }
+}
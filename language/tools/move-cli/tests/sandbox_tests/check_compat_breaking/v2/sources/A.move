@@ -0,0 +1,5 @@
+module 0x42::A {
+    public fun f(): u64 { 0 }
+
+    public fun g(): u64 { 1 }
+}
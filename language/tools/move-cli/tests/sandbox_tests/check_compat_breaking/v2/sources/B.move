@@ -0,0 +1,3 @@
+module 0x42::B {
+    public fun f(x: u64): u64 { x }
+}
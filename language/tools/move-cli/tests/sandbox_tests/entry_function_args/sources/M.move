@@ -0,0 +1,23 @@
+module 0x2::M {
+    use std::debug;
+    use std::option::Option;
+    use std::string::String;
+
+    struct S has drop { x: u64 }
+
+    public entry fun take_string(s: String) {
+        debug::print(&s);
+    }
+
+    public entry fun take_option(o: Option<u64>) {
+        debug::print(&o);
+    }
+
+    public entry fun take_nested(v: vector<vector<String>>) {
+        debug::print(&v);
+    }
+
+    public entry fun take_struct(s: S) {
+        debug::print(&s);
+    }
+}
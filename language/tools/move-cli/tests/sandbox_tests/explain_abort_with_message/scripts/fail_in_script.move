@@ -0,0 +1,7 @@
+script {
+    use std::error;
+
+    fun main() {
+        error::abort_with_message(88, b"aborted in a script")
+    }
+}
@@ -0,0 +1,5 @@
+script {
+    fun main() {
+        0x2::Fail::f();
+    }
+}
@@ -0,0 +1,9 @@
+address 0x2 {
+module Fail {
+    use std::error;
+
+    public fun f() {
+        error::abort_with_message(77, b"the account is frozen")
+    }
+}
+}
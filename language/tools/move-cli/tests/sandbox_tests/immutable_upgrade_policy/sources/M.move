@@ -0,0 +1,3 @@
+module 0x42::M {
+    public fun f(): u64 { 1 }
+}
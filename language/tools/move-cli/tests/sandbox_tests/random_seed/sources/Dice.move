@@ -0,0 +1,18 @@
+module 0x2::Dice {
+    use std::debug;
+    use std::random;
+    use std::signer;
+
+    struct Roll has key { value: u64 }
+
+    public entry fun roll(account: signer) acquires Roll {
+        let value = random::u64_range(1, 7);
+        debug::print(&value);
+        let addr = signer::address_of(&account);
+        if (exists<Roll>(addr)) {
+            borrow_global_mut<Roll>(addr).value = value;
+        } else {
+            move_to(&account, Roll { value });
+        }
+    }
+}
@@ -0,0 +1,11 @@
+#[test_only]
+module 0x2::DiceTests {
+    use std::debug;
+    use std::random;
+
+    #[test]
+    fun print_draws() {
+        debug::print(&random::next_u64());
+        debug::print(&random::next_u64());
+    }
+}
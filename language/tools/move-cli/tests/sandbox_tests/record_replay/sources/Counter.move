@@ -0,0 +1,13 @@
+module 0x2::Counter {
+    struct Counter has key { i: u64 }
+
+    public entry fun incr(account: signer) acquires Counter {
+        if (!exists<Counter>(@0xA)) move_to(&account, Counter { i: 0 });
+        let counter = borrow_global_mut<Counter>(@0xA);
+        counter.i = counter.i + 1;
+    }
+
+    public entry fun check(expected: u64) acquires Counter {
+        assert!(borrow_global<Counter>(@0xA).i == expected, expected);
+    }
+}
@@ -0,0 +1,8 @@
+/// A struct named like `std::string::String`, but declared outside the standard library.
+module 0x2::string {
+    struct String has copy, drop { bytes: vector<u8> }
+
+    public fun new(bytes: vector<u8>): String {
+        String { bytes }
+    }
+}
@@ -0,0 +1,17 @@
+#[test_only]
+module 0x2::StringTests {
+    use std::string;
+    use std::string_utils;
+    use 0x2::string as lookalike_string;
+
+    #[test]
+    fun render_strings() {
+        let s = string::utf8(b"hi");
+        assert!(*string::bytes(&string_utils::to_string(&s)) == b"\"hi\"", 0);
+
+        // only the strings of the standard library are rendered as such
+        let s = lookalike_string::new(b"hi");
+        let rendered = string_utils::to_string(&s);
+        assert!(*string::bytes(&rendered) == b"String { bytes: 0x6869 }", 1);
+    }
+}
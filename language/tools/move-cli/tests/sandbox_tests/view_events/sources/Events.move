@@ -0,0 +1,31 @@
+module 0x2::Events {
+    use std::event;
+
+    struct Transfer<phantom T> has drop, store {
+        amount: u64,
+    }
+
+    struct Note has drop, store {
+        tag: u8,
+    }
+
+    struct Coin {}
+
+    struct Token {}
+
+    public entry fun emit(account: signer) {
+        let coin_handle = event::new_event_handle<Transfer<Coin>>(&account);
+        let token_handle = event::new_event_handle<Transfer<Token>>(&account);
+        let note_handle = event::new_event_handle<Note>(&account);
+        let count_handle = event::new_event_handle<u64>(&account);
+        event::emit_event(&mut coin_handle, Transfer { amount: 10 });
+        event::emit_event(&mut token_handle, Transfer { amount: 20 });
+        event::emit_event(&mut note_handle, Note { tag: 1 });
+        event::emit_event(&mut count_handle, 42);
+        event::emit_event(&mut coin_handle, Transfer { amount: 30 });
+        event::destroy_handle(coin_handle);
+        event::destroy_handle(token_handle);
+        event::destroy_handle(note_handle);
+        event::destroy_handle(count_handle);
+    }
+}
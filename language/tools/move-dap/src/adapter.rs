@@ -0,0 +1,611 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Handling of the debug requests, and the `Debugger` suspending the VM on breakpoints and
+//! steps.
+//!
+//! Requests are served on the thread reading them while the program runs on a separate thread.
+//! When the VM stops, it takes a snapshot of its call stack, which the inspection requests are
+//! answered from, and blocks until a resuming request is received.
+
+use crate::{
+    launch::{LaunchArguments, Program},
+    protocol::{arguments, Client, Request},
+    source_index::SourceIndex,
+};
+use anyhow::{anyhow, bail, Result};
+use move_core_types::value::{MoveStruct, MoveValue};
+use move_vm_runtime::debugger::{
+    clear_debugger, set_debugger, Debugger, ExecutionState, FrameSnapshot,
+};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::{
+    collections::BTreeSet,
+    io::{self, Write},
+    path::PathBuf,
+    sync::{
+        mpsc::{channel, Receiver, Sender},
+        Arc, Mutex,
+    },
+    thread::{self, JoinHandle},
+};
+
+// The VM runs on a single thread.
+const THREAD_ID: i64 = 1;
+
+/// How to resume a stopped program.
+enum Resume {
+    Continue,
+    StepOver,
+    StepIn,
+    StepOut,
+    /// Run to completion, ignoring breakpoints.
+    Detach,
+}
+
+/// Where the program is in the source: the depth of the call stack and the line being executed.
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct Position {
+    depth: usize,
+    file: usize,
+    line: u32,
+}
+
+impl Position {
+    fn same_line(&self, other: &Position) -> bool {
+        self.file == other.file && self.line == other.line
+    }
+}
+
+enum Mode {
+    Entry,
+    Continue,
+    StepOver(Position),
+    StepIn(Position),
+    StepOut(Position),
+    Detached,
+}
+
+/// A value shown in the variables view.
+#[derive(Clone)]
+struct Variable {
+    name: String,
+    type_name: String,
+    value: Option<MoveValue>,
+}
+
+struct Stopped {
+    frames: Vec<FrameSnapshot>,
+    /// Children of the structs and vectors expanded so far, variable references past the frames
+    /// index into it.
+    containers: Vec<Vec<Variable>>,
+}
+
+struct DebugState {
+    mode: Mode,
+    breakpoints: BTreeSet<(usize, u32)>,
+    last_position: Option<Position>,
+    stopped: Option<Stopped>,
+}
+
+/// State shared with the thread running the VM.
+struct Shared {
+    client: Arc<Client>,
+    index: SourceIndex,
+    state: Mutex<DebugState>,
+}
+
+impl Shared {
+    // Decide whether to stop at `here`, returning the reason to report if so.
+    fn stop_reason(&self, here: Position) -> Option<&'static str> {
+        let mut state = self.state.lock().unwrap();
+        let last_position = state.last_position.replace(here);
+        let step = match &state.mode {
+            Mode::Detached => return None,
+            Mode::Entry => true,
+            Mode::Continue => false,
+            Mode::StepOver(from) => {
+                here.depth < from.depth || (here.depth == from.depth && !here.same_line(from))
+            }
+            Mode::StepIn(from) => here.depth != from.depth || !here.same_line(from),
+            Mode::StepOut(from) => here.depth < from.depth,
+        };
+        if step {
+            return Some(match state.mode {
+                Mode::Entry => "entry",
+                _ => "step",
+            });
+        }
+        // stop once when reaching a line with a breakpoint, not on every instruction of the line
+        if state.breakpoints.contains(&(here.file, here.line)) && last_position != Some(here) {
+            return Some("breakpoint");
+        }
+        None
+    }
+}
+
+struct DapDebugger {
+    shared: Arc<Shared>,
+    resume: Receiver<Resume>,
+}
+
+impl Debugger for DapDebugger {
+    fn on_instruction(&mut self, state: &ExecutionState) {
+        let (file, line) =
+            match self
+                .shared
+                .index
+                .location(state.module_id(), state.function_index(), state.pc())
+            {
+                Some(location) => location,
+                None => return,
+            };
+        let here = Position {
+            depth: state.call_depth(),
+            file,
+            line,
+        };
+        let reason = match self.shared.stop_reason(here) {
+            Some(reason) => reason,
+            None => return,
+        };
+
+        self.shared.state.lock().unwrap().stopped = Some(Stopped {
+            frames: state.call_stack(),
+            containers: vec![],
+        });
+        let _ = self.shared.client.event(
+            "stopped",
+            json!({
+                "reason": reason,
+                "threadId": THREAD_ID,
+                "allThreadsStopped": true,
+            }),
+        );
+        // a closed channel means the client is gone
+        let resume = self.resume.recv().unwrap_or(Resume::Detach);
+
+        let mut debug_state = self.shared.state.lock().unwrap();
+        debug_state.stopped = None;
+        debug_state.mode = match resume {
+            Resume::Continue => Mode::Continue,
+            Resume::StepOver => Mode::StepOver(here),
+            Resume::StepIn => Mode::StepIn(here),
+            Resume::StepOut => Mode::StepOut(here),
+            Resume::Detach => Mode::Detached,
+        };
+    }
+}
+
+/// Forwards the output of the program to the debug console.
+struct OutputWriter(Arc<Client>);
+
+impl Write for OutputWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0
+            .output("stdout", &String::from_utf8_lossy(buf))
+            .map_err(|err| io::Error::new(io::ErrorKind::Other, err))?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[derive(Deserialize)]
+struct Source {
+    path: PathBuf,
+}
+
+#[derive(Deserialize)]
+struct SourceBreakpoint {
+    line: u32,
+}
+
+#[derive(Deserialize)]
+struct SetBreakpointsArguments {
+    source: Source,
+    #[serde(default)]
+    breakpoints: Vec<SourceBreakpoint>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ScopesArguments {
+    frame_id: usize,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct VariablesArguments {
+    variables_reference: usize,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EvaluateArguments {
+    expression: String,
+    frame_id: Option<usize>,
+}
+
+/// Serves the requests of a single debug session.
+pub struct Adapter {
+    client: Arc<Client>,
+    program: Option<Program>,
+    shared: Option<Arc<Shared>>,
+    resume: Option<Sender<Resume>>,
+    vm_thread: Option<JoinHandle<()>>,
+}
+
+impl Adapter {
+    pub fn new(client: Client) -> Self {
+        Self {
+            client: Arc::new(client),
+            program: None,
+            shared: None,
+            resume: None,
+            vm_thread: None,
+        }
+    }
+
+    /// Handle `request`, returning false once the session is over.
+    pub fn handle(&mut self, request: &Request) -> Result<bool> {
+        let result = match request.command.as_str() {
+            "initialize" => Ok(json!({
+                "supportsConfigurationDoneRequest": true,
+                "supportsEvaluateForHovers": true,
+            })),
+            "launch" => self.launch(request),
+            "setBreakpoints" => self.set_breakpoints(request),
+            "configurationDone" => self.configuration_done(),
+            "threads" => Ok(json!({
+                "threads": [{ "id": THREAD_ID, "name": "main" }],
+            })),
+            "stackTrace" => self.stack_trace(),
+            "scopes" => self.scopes(request),
+            "variables" => self.variables(request),
+            "evaluate" => self.evaluate(request),
+            "continue" => self
+                .resume(Resume::Continue)
+                .map(|_| json!({ "allThreadsContinued": true })),
+            "next" => self.resume(Resume::StepOver).map(|_| Value::Null),
+            "stepIn" => self.resume(Resume::StepIn).map(|_| Value::Null),
+            "stepOut" => self.resume(Resume::StepOut).map(|_| Value::Null),
+            "disconnect" | "terminate" => {
+                self.shutdown();
+                self.client.respond(request, Ok(Value::Null))?;
+                return Ok(false);
+            }
+            command => Err(anyhow!("Unsupported request {}", command)),
+        };
+        let launched = request.command == "launch" && result.is_ok();
+        self.client.respond(request, result)?;
+        if launched {
+            // the client sends the breakpoints once this is received
+            self.client.event("initialized", Value::Null)?;
+        }
+        Ok(true)
+    }
+
+    /// Let the program run to completion without stopping and wait for it.
+    pub fn shutdown(&mut self) {
+        if let Some(shared) = &self.shared {
+            shared.state.lock().unwrap().mode = Mode::Detached;
+        }
+        if let Some(resume) = self.resume.take() {
+            let _ = resume.send(Resume::Detach);
+        }
+        if let Some(vm_thread) = self.vm_thread.take() {
+            let _ = vm_thread.join();
+        }
+    }
+
+    fn launch(&mut self, request: &Request) -> Result<Value> {
+        if self.program.is_some() || self.vm_thread.is_some() {
+            bail!("A program was already launched")
+        }
+        let arguments: LaunchArguments = arguments(request)?;
+        let (program, index) = Program::new(arguments)?;
+        self.shared = Some(Arc::new(Shared {
+            client: Arc::clone(&self.client),
+            index,
+            state: Mutex::new(DebugState {
+                mode: if program.stop_on_entry() {
+                    Mode::Entry
+                } else {
+                    Mode::Continue
+                },
+                breakpoints: BTreeSet::new(),
+                last_position: None,
+                stopped: None,
+            }),
+        }));
+        self.program = Some(program);
+        Ok(Value::Null)
+    }
+
+    fn shared(&self) -> Result<&Arc<Shared>> {
+        self.shared
+            .as_ref()
+            .ok_or_else(|| anyhow!("No program was launched"))
+    }
+
+    fn set_breakpoints(&mut self, request: &Request) -> Result<Value> {
+        let arguments: SetBreakpointsArguments = arguments(request)?;
+        let shared = self.shared()?;
+        let file = shared.index.find_file(&arguments.source.path);
+        let mut state = shared.state.lock().unwrap();
+        if let Some(file) = file {
+            state.breakpoints.retain(|(bp_file, _)| *bp_file != file);
+        }
+        let breakpoints: Vec<_> = arguments
+            .breakpoints
+            .iter()
+            .map(|bp| {
+                match file
+                    .and_then(|file| Some((file, shared.index.breakpoint_line(file, bp.line)?)))
+                {
+                    Some((file, line)) => {
+                        state.breakpoints.insert((file, line));
+                        json!({ "verified": true, "line": line })
+                    }
+                    None => json!({
+                        "verified": false,
+                        "line": bp.line,
+                        "message": "No code at or after this line",
+                    }),
+                }
+            })
+            .collect();
+        Ok(json!({ "breakpoints": breakpoints }))
+    }
+
+    fn configuration_done(&mut self) -> Result<Value> {
+        let program = self
+            .program
+            .take()
+            .ok_or_else(|| anyhow!("No program was launched"))?;
+        let shared = Arc::clone(self.shared()?);
+        let (sender, receiver) = channel();
+        self.resume = Some(sender);
+        self.vm_thread = Some(thread::spawn(move || {
+            let client = Arc::clone(&shared.client);
+            set_debugger(Box::new(DapDebugger {
+                shared,
+                resume: receiver,
+            }));
+            let result = program.run(&mut OutputWriter(Arc::clone(&client)));
+            clear_debugger();
+            let exit_code = match result {
+                Ok(exit_code) => exit_code,
+                Err(err) => {
+                    let _ = client.output("stderr", &format!("{:#}\n", err));
+                    1
+                }
+            };
+            let _ = client.event("exited", json!({ "exitCode": exit_code }));
+            let _ = client.event("terminated", Value::Null);
+        }));
+        Ok(Value::Null)
+    }
+
+    fn resume(&self, resume: Resume) -> Result<()> {
+        let shared = self.shared()?;
+        if shared.state.lock().unwrap().stopped.is_none() {
+            bail!("The program is not stopped")
+        }
+        self.resume
+            .as_ref()
+            .ok_or_else(|| anyhow!("The program is not running"))?
+            .send(resume)
+            .map_err(|_| anyhow!("The program is not running"))
+    }
+
+    fn stack_trace(&self) -> Result<Value> {
+        let shared = self.shared()?;
+        let state = shared.state.lock().unwrap();
+        let stopped = state
+            .stopped
+            .as_ref()
+            .ok_or_else(|| anyhow!("The program is not stopped"))?;
+        let frames: Vec<_> = stopped
+            .frames
+            .iter()
+            .enumerate()
+            .map(|(idx, frame)| {
+                let name = match &frame.module_id {
+                    Some(module_id) => {
+                        format!(
+                            "{}::{}",
+                            module_id.short_str_lossless(),
+                            frame.function_name
+                        )
+                    }
+                    None => "script".to_string(),
+                };
+                let mut stack_frame = json!({
+                    "id": idx + 1,
+                    "name": name,
+                    "line": 0,
+                    "column": 0,
+                });
+                if let Some((file, line)) =
+                    shared
+                        .index
+                        .location(frame.module_id.as_ref(), frame.function_index, frame.pc)
+                {
+                    let path = &shared.index.file(file).path;
+                    stack_frame["source"] = json!({
+                        "name": path.file_name().map(|name| name.to_string_lossy()),
+                        "path": path,
+                    });
+                    stack_frame["line"] = json!(line);
+                    stack_frame["column"] = json!(1);
+                }
+                stack_frame
+            })
+            .collect();
+        Ok(json!({
+            "stackFrames": frames,
+            "totalFrames": frames.len(),
+        }))
+    }
+
+    fn scopes(&self, request: &Request) -> Result<Value> {
+        let arguments: ScopesArguments = arguments(request)?;
+        // the locals of a frame are referenced by the id of the frame
+        Ok(json!({
+            "scopes": [{
+                "name": "Locals",
+                "variablesReference": arguments.frame_id,
+                "expensive": false,
+            }],
+        }))
+    }
+
+    fn variables(&self, request: &Request) -> Result<Value> {
+        let arguments: VariablesArguments = arguments(request)?;
+        let shared = self.shared()?;
+        let mut state = shared.state.lock().unwrap();
+        let stopped = state
+            .stopped
+            .as_mut()
+            .ok_or_else(|| anyhow!("The program is not stopped"))?;
+        let reference = arguments.variables_reference;
+        let variables = if (1..=stopped.frames.len()).contains(&reference) {
+            frame_variables(&shared.index, &stopped.frames[reference - 1])
+        } else {
+            reference
+                .checked_sub(stopped.frames.len() + 1)
+                .and_then(|idx| stopped.containers.get(idx))
+                .ok_or_else(|| anyhow!("Unknown variables reference {}", reference))?
+                .clone()
+        };
+        let rendered: Vec<_> = variables
+            .iter()
+            .map(|variable| stopped.render(variable))
+            .collect();
+        Ok(json!({ "variables": rendered }))
+    }
+
+    fn evaluate(&self, request: &Request) -> Result<Value> {
+        let arguments: EvaluateArguments = arguments(request)?;
+        let shared = self.shared()?;
+        let mut state = shared.state.lock().unwrap();
+        let stopped = state
+            .stopped
+            .as_mut()
+            .ok_or_else(|| anyhow!("The program is not stopped"))?;
+        let frame = stopped
+            .frames
+            .get(arguments.frame_id.unwrap_or(1).saturating_sub(1))
+            .ok_or_else(|| anyhow!("Unknown frame"))?;
+        let name = arguments.expression.trim();
+        // the last local with this name holding a value is the one in scope
+        let variable = frame_variables(&shared.index, frame)
+            .into_iter()
+            .rev()
+            .find(|variable| variable.name == name && variable.value.is_some())
+            .ok_or_else(|| anyhow!("No local named {} holds a value", name))?;
+        let rendered = stopped.render(&variable);
+        Ok(json!({
+            "result": rendered["value"],
+            "type": rendered["type"],
+            "variablesReference": rendered["variablesReference"],
+        }))
+    }
+}
+
+fn frame_variables(index: &SourceIndex, frame: &FrameSnapshot) -> Vec<Variable> {
+    let names = index
+        .function(frame.module_id.as_ref(), frame.function_index)
+        .map(|function| function.local_names.as_slice())
+        .unwrap_or_default();
+    frame
+        .locals
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, local)| {
+            // without a source map, fall back to the index of the local
+            let name = match names.get(idx) {
+                Some(name) => name.clone()?,
+                None => format!("local{}", idx),
+            };
+            Some(Variable {
+                name,
+                type_name: local.type_name.clone(),
+                value: local.value.clone(),
+            })
+        })
+        .collect()
+}
+
+impl Stopped {
+    // Render `variable`, allocating a reference for its children if it has any.
+    fn render(&mut self, variable: &Variable) -> Value {
+        let children = match &variable.value {
+            Some(MoveValue::Vector(elems)) if !elems.is_empty() => {
+                let elem_type = variable
+                    .type_name
+                    .trim_start_matches('&')
+                    .trim_start_matches("mut ")
+                    .strip_prefix("vector<")
+                    .and_then(|ty| ty.strip_suffix('>'))
+                    .unwrap_or_default();
+                elems
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, elem)| Variable {
+                        name: format!("[{}]", idx),
+                        type_name: elem_type.to_string(),
+                        value: Some(elem.clone()),
+                    })
+                    .collect()
+            }
+            Some(MoveValue::Struct(MoveStruct::WithTypes { fields, .. }))
+            | Some(MoveValue::Struct(MoveStruct::WithFields(fields))) => fields
+                .iter()
+                .map(|(name, value)| Variable {
+                    name: name.to_string(),
+                    type_name: value_type_name(value),
+                    value: Some(value.clone()),
+                })
+                .collect(),
+            _ => vec![],
+        };
+        let reference = if children.is_empty() {
+            0
+        } else {
+            self.containers.push(children);
+            self.frames.len() + self.containers.len()
+        };
+        json!({
+            "name": variable.name,
+            "type": variable.type_name,
+            "value": match &variable.value {
+                Some(value) => value.to_string(),
+                None => "<unavailable>".to_string(),
+            },
+            "variablesReference": reference,
+        })
+    }
+}
+
+fn value_type_name(value: &MoveValue) -> String {
+    match value {
+        MoveValue::U8(_) => "u8".to_string(),
+        MoveValue::U64(_) => "u64".to_string(),
+        MoveValue::U128(_) => "u128".to_string(),
+        MoveValue::Bool(_) => "bool".to_string(),
+        MoveValue::Address(_) => "address".to_string(),
+        MoveValue::Signer(_) => "signer".to_string(),
+        MoveValue::Vector(elems) => match elems.first() {
+            Some(elem) => format!("vector<{}>", value_type_name(elem)),
+            None => "vector".to_string(),
+        },
+        MoveValue::Struct(MoveStruct::WithTypes { type_, .. }) => type_.to_string(),
+        MoveValue::Struct(_) => "struct".to_string(),
+    }
+}
@@ -0,0 +1,264 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! The programs which can be debugged: the unit tests of a package, or a script or entry
+//! function run against the sandbox storage.
+
+use crate::source_index::SourceIndex;
+use anyhow::{anyhow, bail, Result};
+use move_cli::{
+    base::test::{run_move_unit_tests, UnitTestResult},
+    sandbox::utils::PackageContext,
+    DEFAULT_STORAGE_DIR,
+};
+use move_core_types::{
+    account_address::AccountAddress,
+    identifier::Identifier,
+    language_storage::{ModuleId, TypeTag},
+    parser::{parse_transaction_argument, parse_type_tag},
+    transaction_argument::convert_txn_args,
+    value::MoveValue,
+};
+use move_package::BuildConfig;
+use move_stdlib::natives::{
+    all_natives, nursery_natives, random::NativeRandomContext, GasParameters, NurseryGasParameters,
+};
+use move_unit_test::UnitTestingConfig;
+use move_vm_runtime::{
+    move_vm::MoveVM, native_extensions::NativeContextExtensions,
+    native_functions::NativeFunctionTable,
+};
+use move_vm_types::gas::UnmeteredGasMeter;
+use serde::Deserialize;
+use std::{io::Write, path::PathBuf};
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum LaunchMode {
+    /// Run the unit tests of the package, like `move test`.
+    #[default]
+    Test,
+    /// Run a script or an entry function, like `move sandbox run`.
+    Run,
+}
+
+/// The arguments of the `launch` request.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LaunchArguments {
+    /// Path to the package to debug.
+    pub package_path: PathBuf,
+    #[serde(default)]
+    pub mode: LaunchMode,
+    /// In test mode, only run the tests whose name contains this string.
+    pub filter: Option<String>,
+    /// In run mode, the script to run.
+    pub script: Option<PathBuf>,
+    /// In run mode, the entry function to run, e.g. `0x2::M::f`, when not running a script.
+    pub function: Option<String>,
+    #[serde(default)]
+    pub signers: Vec<String>,
+    #[serde(default)]
+    pub args: Vec<String>,
+    #[serde(default)]
+    pub type_args: Vec<String>,
+    /// In run mode, the sandbox storage directory, `storage` in the package by default.
+    pub storage_dir: Option<PathBuf>,
+    /// Seed of the generator drawn from by `std::random`, like `--seed`.
+    #[serde(default)]
+    pub seed: u64,
+    /// Suspend execution before the first instruction.
+    #[serde(default)]
+    pub stop_on_entry: bool,
+}
+
+/// A compiled program, ready to be run.
+pub struct Program {
+    arguments: LaunchArguments,
+    build_config: BuildConfig,
+    context: Option<PackageContext>,
+    script: Option<Vec<u8>>,
+}
+
+impl Program {
+    /// Compile the package and index its source maps.
+    pub fn new(arguments: LaunchArguments) -> Result<(Self, SourceIndex)> {
+        let mut index = SourceIndex::default();
+        let mut build_config = BuildConfig::default();
+        match arguments.mode {
+            LaunchMode::Test => {
+                build_config.test_mode = true;
+                build_config.dev_mode = true;
+                let package = build_config
+                    .clone()
+                    .compile_package(&arguments.package_path, &mut Vec::new())?;
+                for unit in package.all_modules() {
+                    index.add_module(unit)?;
+                }
+                Ok((
+                    Self {
+                        arguments,
+                        build_config,
+                        context: None,
+                        script: None,
+                    },
+                    index,
+                ))
+            }
+            LaunchMode::Run => {
+                let context =
+                    PackageContext::new(&Some(arguments.package_path.clone()), &build_config)?;
+                for unit in context.package().all_modules() {
+                    index.add_module(unit)?;
+                }
+                let script = match (&arguments.script, &arguments.function) {
+                    (Some(path), None) => {
+                        let path = path.canonicalize()?;
+                        let unit = context
+                            .package()
+                            .scripts()
+                            .find(|unit| unit.source_path.canonicalize().ok() == Some(path.clone()))
+                            .ok_or_else(|| anyhow!("No script in {:?}", path))?;
+                        index.set_script(unit)?;
+                        Some(unit.unit.serialize(None))
+                    }
+                    (None, Some(_)) => None,
+                    _ => bail!("Exactly one of `script` and `function` must be set in run mode"),
+                };
+                Ok((
+                    Self {
+                        arguments,
+                        build_config,
+                        context: Some(context),
+                        script,
+                    },
+                    index,
+                ))
+            }
+        }
+    }
+
+    pub fn stop_on_entry(&self) -> bool {
+        self.arguments.stop_on_entry
+    }
+
+    /// Run the program, writing its output to `writer`. Returns the exit code.
+    pub fn run<W: Write + Send>(&self, writer: &mut W) -> Result<i64> {
+        match &self.context {
+            None => self.run_tests(writer),
+            Some(context) => self.run_program(context, writer),
+        }
+    }
+
+    fn run_tests<W: Write + Send>(&self, writer: &mut W) -> Result<i64> {
+        let unit_test_config = UnitTestingConfig {
+            filter: self.arguments.filter.clone(),
+            num_threads: 1,
+            seed: self.arguments.seed,
+            ..UnitTestingConfig::default_with_bound(None)
+        };
+        let result = run_move_unit_tests(
+            &self.arguments.package_path,
+            self.build_config.clone(),
+            unit_test_config,
+            natives(),
+            false,
+            writer,
+        )?;
+        Ok(match result {
+            UnitTestResult::Success => 0,
+            UnitTestResult::Failure => 1,
+        })
+    }
+
+    // Effects of the execution are not committed, so the program can be debugged again from the
+    // same state.
+    fn run_program<W: Write>(&self, context: &PackageContext, writer: &mut W) -> Result<i64> {
+        let storage_dir = match &self.arguments.storage_dir {
+            Some(storage_dir) => storage_dir.clone(),
+            None => self.arguments.package_path.join(DEFAULT_STORAGE_DIR),
+        };
+        let state = context.prepare_state(&storage_dir)?;
+
+        let signers = self
+            .arguments
+            .signers
+            .iter()
+            .map(|signer| {
+                let address = AccountAddress::from_hex_literal(signer)?;
+                Ok(MoveValue::Signer(address).simple_serialize().unwrap())
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let txn_args = self
+            .arguments
+            .args
+            .iter()
+            .map(|arg| parse_transaction_argument(arg))
+            .collect::<Result<Vec<_>>>()?;
+        let args: Vec<Vec<u8>> = signers
+            .into_iter()
+            .chain(convert_txn_args(&txn_args))
+            .collect();
+        let type_args = self
+            .arguments
+            .type_args
+            .iter()
+            .map(|ty| parse_type_tag(ty))
+            .collect::<Result<Vec<TypeTag>>>()?;
+
+        let vm = MoveVM::new(natives())?;
+        let mut extensions = NativeContextExtensions::default();
+        extensions.add(NativeRandomContext::new(self.arguments.seed));
+        let mut session = vm.new_session_with_extensions(&state, extensions);
+        let result = match (&self.script, &self.arguments.function) {
+            (Some(script), _) => {
+                session.execute_script(script.clone(), type_args, args, &mut UnmeteredGasMeter)
+            }
+            (None, Some(function)) => {
+                let (module_id, function) = parse_function(function)?;
+                session.execute_entry_function(
+                    &module_id,
+                    &function,
+                    type_args,
+                    args,
+                    &mut UnmeteredGasMeter,
+                )
+            }
+            (None, None) => unreachable!(),
+        };
+        match result {
+            Ok(_) => {
+                writeln!(writer, "Execution succeeded, changes were not committed")?;
+                Ok(0)
+            }
+            Err(err) => {
+                writeln!(writer, "Execution failed: {:?}", err.into_vm_status())?;
+                Ok(1)
+            }
+        }
+    }
+}
+
+fn natives() -> NativeFunctionTable {
+    let addr = AccountAddress::from_hex_literal("0x1").unwrap();
+    all_natives(addr, GasParameters::zeros())
+        .into_iter()
+        .chain(nursery_natives(addr, NurseryGasParameters::zeros()))
+        .collect()
+}
+
+// Parse `<address>::<module>::<function>`.
+fn parse_function(function: &str) -> Result<(ModuleId, Identifier)> {
+    let parts: Vec<_> = function.split("::").collect();
+    if parts.len() != 3 {
+        bail!(
+            "Invalid function {}, expected <address>::<module>::<function>",
+            function
+        )
+    }
+    let address = AccountAddress::from_hex_literal(parts[0])?;
+    Ok((
+        ModuleId::new(address, Identifier::new(parts[1])?),
+        Identifier::new(parts[2])?,
+    ))
+}
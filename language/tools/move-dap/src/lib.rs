@@ -0,0 +1,43 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! A [Debug Adapter Protocol](https://microsoft.github.io/debug-adapter-protocol/) server for
+//! the Move VM, to debug the unit tests of a package or a script run against the sandbox storage
+//! from an editor.
+//!
+//! Breakpoints are set on source lines, resolved to bytecode offsets through the source maps of
+//! the compiled package. Execution is suspended by the `Debugger` hook of the VM, which is only
+//! available with the `debugging` feature of `move-vm-runtime`.
+
+#![forbid(unsafe_code)]
+
+pub mod adapter;
+pub mod launch;
+pub mod protocol;
+pub mod source_index;
+
+use crate::{
+    adapter::Adapter,
+    protocol::{read_message, Client, Request},
+};
+use anyhow::Result;
+use std::io::{BufReader, Read, Write};
+
+/// Serve a debug session, reading requests from `input` and writing responses and events to
+/// `output`, until the client disconnects.
+pub fn serve<R: Read, W: Write + Send + 'static>(input: R, output: W) -> Result<()> {
+    let mut reader = BufReader::new(input);
+    let mut adapter = Adapter::new(Client::new(output));
+    while let Some(message) = read_message(&mut reader)? {
+        // only requests are sent by the client
+        let request: Request = match serde_json::from_value(message) {
+            Ok(request) => request,
+            Err(_) => continue,
+        };
+        if !adapter.handle(&request)? {
+            return Ok(());
+        }
+    }
+    adapter.shutdown();
+    Ok(())
+}
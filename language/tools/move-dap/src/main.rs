@@ -0,0 +1,34 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+#![forbid(unsafe_code)]
+
+use anyhow::Result;
+use clap::Parser;
+use std::{io, net::TcpListener};
+
+#[derive(Debug, Parser)]
+#[clap(
+    name = "move-dap",
+    about = "Debug Adapter Protocol server for the Move VM",
+    author,
+    version
+)]
+struct Args {
+    /// Serve a single session on this TCP port instead of stdin/stdout. Required to debug
+    /// programs printing to stdout, e.g. through `std::debug::print`.
+    #[clap(long = "port", short = 'p')]
+    pub port: Option<u16>,
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    match args.port {
+        Some(port) => {
+            let listener = TcpListener::bind(("127.0.0.1", port))?;
+            let (stream, _) = listener.accept()?;
+            move_dap::serve(stream.try_clone()?, stream)
+        }
+        None => move_dap::serve(io::stdin(), io::stdout()),
+    }
+}
@@ -0,0 +1,134 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Wire format of the Debug Adapter Protocol: JSON messages preceded by a `Content-Length`
+//! header.
+
+use anyhow::{anyhow, bail, Result};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::{
+    io::{BufRead, Write},
+    sync::Mutex,
+};
+
+/// A request sent by the client.
+#[derive(Debug, Deserialize)]
+pub struct Request {
+    pub seq: i64,
+    pub command: String,
+    #[serde(default)]
+    pub arguments: Value,
+}
+
+/// Read the next message, returning `None` once the input is exhausted.
+pub fn read_message<R: BufRead>(reader: &mut R) -> Result<Option<Value>> {
+    let mut content_length = None;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("Content-Length") {
+                content_length = Some(value.trim().parse::<usize>()?);
+            }
+        }
+    }
+    let content_length =
+        content_length.ok_or_else(|| anyhow!("Message without a Content-Length header"))?;
+    let mut content = vec![0; content_length];
+    reader.read_exact(&mut content)?;
+    Ok(Some(serde_json::from_slice(&content)?))
+}
+
+/// Write `message` with its header.
+pub fn write_message<W: Write + ?Sized>(writer: &mut W, message: &Value) -> Result<()> {
+    let content = serde_json::to_vec(message)?;
+    write!(writer, "Content-Length: {}\r\n\r\n", content.len())?;
+    writer.write_all(&content)?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// The sending half of a connection, shared between the thread serving requests and the one
+/// running the VM.
+pub struct Client {
+    inner: Mutex<ClientInner>,
+}
+
+struct ClientInner {
+    writer: Box<dyn Write + Send>,
+    seq: i64,
+}
+
+impl Client {
+    pub fn new<W: Write + Send + 'static>(writer: W) -> Self {
+        Self {
+            inner: Mutex::new(ClientInner {
+                writer: Box::new(writer),
+                seq: 0,
+            }),
+        }
+    }
+
+    fn send(&self, mut message: Value) -> Result<()> {
+        let mut inner = self.inner.lock().unwrap();
+        inner.seq += 1;
+        message["seq"] = json!(inner.seq);
+        write_message(&mut inner.writer, &message)
+    }
+
+    /// Answer `request`, with the body of the response or the reason it failed.
+    pub fn respond(&self, request: &Request, result: Result<Value>) -> Result<()> {
+        let mut response = json!({
+            "type": "response",
+            "request_seq": request.seq,
+            "command": request.command,
+        });
+        match result {
+            Ok(body) => {
+                response["success"] = json!(true);
+                if !body.is_null() {
+                    response["body"] = body;
+                }
+            }
+            Err(err) => {
+                response["success"] = json!(false);
+                response["message"] = json!(err.to_string());
+            }
+        }
+        self.send(response)
+    }
+
+    pub fn event(&self, event: &str, body: Value) -> Result<()> {
+        self.send(json!({
+            "type": "event",
+            "event": event,
+            "body": body,
+        }))
+    }
+
+    /// Send `text` to the debug console.
+    pub fn output(&self, category: &str, text: &str) -> Result<()> {
+        self.event(
+            "output",
+            json!({
+                "category": category,
+                "output": text,
+            }),
+        )
+    }
+}
+
+/// Parse the arguments of a request, failing if they are malformed.
+pub fn arguments<'a, T: Deserialize<'a>>(request: &'a Request) -> Result<T> {
+    match T::deserialize(&request.arguments) {
+        Ok(arguments) => Ok(arguments),
+        Err(err) => bail!("Invalid arguments for {}: {}", request.command, err),
+    }
+}
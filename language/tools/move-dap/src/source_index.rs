@@ -0,0 +1,184 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Mapping from bytecode offsets to source lines, built from the source maps of a compiled
+//! package.
+
+use anyhow::Result;
+use move_binary_format::file_format::FunctionDefinitionIndex;
+use move_bytecode_source_map::source_map::SourceMap;
+use move_compiler::compiled_unit::{CompiledUnitEnum, NamedCompiledModule, NamedCompiledScript};
+use move_core_types::language_storage::ModuleId;
+use move_package::compilation::compiled_package::CompiledUnitWithSource;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// A source file of the package.
+pub struct SourceFile {
+    pub path: PathBuf,
+    /// Byte offset of the start of each line.
+    line_starts: Vec<usize>,
+    /// Lines, 1-based, on which some code starts.
+    code_lines: BTreeSet<u32>,
+}
+
+impl SourceFile {
+    /// The 1-based line containing the byte at `offset`.
+    fn line(&self, offset: usize) -> u32 {
+        self.line_starts.partition_point(|start| *start <= offset) as u32
+    }
+}
+
+/// What the debugger knows about a function.
+pub struct FunctionInfo {
+    /// Index of the file defining the function.
+    pub file: usize,
+    /// The line of each instruction.
+    pub lines: Vec<Option<u32>>,
+    /// The source names of the parameters followed by the locals, `None` for temporaries
+    /// introduced by the compiler.
+    pub local_names: Vec<Option<String>>,
+}
+
+#[derive(Default)]
+pub struct SourceIndex {
+    files: Vec<SourceFile>,
+    modules: BTreeMap<ModuleId, BTreeMap<FunctionDefinitionIndex, FunctionInfo>>,
+    script: Option<FunctionInfo>,
+}
+
+impl SourceIndex {
+    /// Index the functions of a module.
+    pub fn add_module(&mut self, unit: &CompiledUnitWithSource) -> Result<()> {
+        let (module, source_map) = match &unit.unit {
+            CompiledUnitEnum::Module(NamedCompiledModule {
+                module, source_map, ..
+            }) => (module, source_map),
+            CompiledUnitEnum::Script(_) => return Ok(()),
+        };
+        let file = self.add_file(&unit.source_path)?;
+        let mut functions = BTreeMap::new();
+        for (idx, def) in module.function_defs.iter().enumerate() {
+            let idx = FunctionDefinitionIndex::new(idx as u16);
+            if let Some(code) = &def.code {
+                let info = self.function_info(file, source_map, idx, code.code.len());
+                functions.insert(idx, info);
+            }
+        }
+        self.modules.insert(module.self_id(), functions);
+        Ok(())
+    }
+
+    /// Index the main function of the script which is going to be executed.
+    pub fn set_script(&mut self, unit: &CompiledUnitWithSource) -> Result<()> {
+        if let CompiledUnitEnum::Script(NamedCompiledScript {
+            script, source_map, ..
+        }) = &unit.unit
+        {
+            let file = self.add_file(&unit.source_path)?;
+            let idx = FunctionDefinitionIndex::new(0);
+            self.script = Some(self.function_info(file, source_map, idx, script.code.code.len()));
+        }
+        Ok(())
+    }
+
+    fn add_file(&mut self, path: &Path) -> Result<usize> {
+        let path = path.canonicalize()?;
+        if let Some(idx) = self.files.iter().position(|file| file.path == path) {
+            return Ok(idx);
+        }
+        let contents = fs::read_to_string(&path)?;
+        let line_starts = std::iter::once(0)
+            .chain(contents.match_indices('\n').map(|(idx, _)| idx + 1))
+            .collect();
+        self.files.push(SourceFile {
+            path,
+            line_starts,
+            code_lines: BTreeSet::new(),
+        });
+        Ok(self.files.len() - 1)
+    }
+
+    fn function_info(
+        &mut self,
+        file: usize,
+        source_map: &SourceMap,
+        idx: FunctionDefinitionIndex,
+        code_len: usize,
+    ) -> FunctionInfo {
+        let source_file = &mut self.files[file];
+        let lines = (0..code_len)
+            .map(|pc| {
+                let loc = source_map.get_code_location(idx, pc as u16).ok()?;
+                let line = source_file.line(loc.start() as usize);
+                source_file.code_lines.insert(line);
+                Some(line)
+            })
+            .collect();
+        let local_names = match source_map.get_function_source_map(idx) {
+            Ok(function_map) => function_map
+                .parameters
+                .iter()
+                .chain(function_map.locals.iter())
+                .map(|(name, _)| local_name(name))
+                .collect(),
+            Err(_) => vec![],
+        };
+        FunctionInfo {
+            file,
+            lines,
+            local_names,
+        }
+    }
+
+    /// The function defined at `idx` in `module_id`, or the script when `module_id` is `None`.
+    pub fn function(
+        &self,
+        module_id: Option<&ModuleId>,
+        idx: FunctionDefinitionIndex,
+    ) -> Option<&FunctionInfo> {
+        match module_id {
+            Some(module_id) => self.modules.get(module_id)?.get(&idx),
+            None => self.script.as_ref(),
+        }
+    }
+
+    /// The file and line of the instruction at `pc`.
+    pub fn location(
+        &self,
+        module_id: Option<&ModuleId>,
+        idx: FunctionDefinitionIndex,
+        pc: u16,
+    ) -> Option<(usize, u32)> {
+        let function = self.function(module_id, idx)?;
+        let line = (*function.lines.get(pc as usize)?)?;
+        Some((function.file, line))
+    }
+
+    pub fn file(&self, idx: usize) -> &SourceFile {
+        &self.files[idx]
+    }
+
+    pub fn find_file(&self, path: &Path) -> Option<usize> {
+        let path = path.canonicalize().ok()?;
+        self.files.iter().position(|file| file.path == path)
+    }
+
+    /// The line a breakpoint requested on `line` is moved to: the first line at or after it on
+    /// which some code starts.
+    pub fn breakpoint_line(&self, file: usize, line: u32) -> Option<u32> {
+        self.files[file].code_lines.range(line..).next().copied()
+    }
+}
+
+// The compiler suffixes locals with their block and shadowing index, e.g. `x#1#0`, and names its
+// own temporaries `%#1`.
+fn local_name(name: &str) -> Option<String> {
+    if name.starts_with('%') {
+        return None;
+    }
+    Some(name.split('#').next().unwrap_or(name).to_string())
+}
@@ -0,0 +1,202 @@
+// Copyright (c) The Move Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use move_dap::protocol::{read_message, write_message};
+use serde_json::{json, Value};
+use std::{
+    fs,
+    io::BufReader,
+    net::{TcpListener, TcpStream},
+    path::Path,
+    thread,
+};
+
+// Test mode requires the `UnitTest` module of the standard library.
+fn move_toml() -> String {
+    let stdlib_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../move-stdlib");
+    format!(
+        r#"
+[package]
+name = "Debuggee"
+version = "0.0.0"
+
+[addresses]
+std = "0x1"
+debuggee = "0x2"
+
+[dependencies]
+MoveStdlib = {{ local = "{}" }}
+"#,
+        stdlib_path.display()
+    )
+}
+
+const SOURCE: &str = r#"module debuggee::M {
+    struct Point has drop { x: u64, y: u64 }
+
+    fun sum(p: &Point): u64 {
+        let s = p.x + p.y;
+        s
+    }
+
+    #[test]
+    fun test_sum() {
+        let p = Point { x: 1, y: 2 };
+        let s = sum(&p);
+        assert!(s == 3, 0);
+    }
+}
+"#;
+
+// Line of `let s = p.x + p.y;`
+const BREAKPOINT_LINE: u64 = 5;
+
+struct TestClient {
+    reader: BufReader<TcpStream>,
+    writer: TcpStream,
+    seq: i64,
+}
+
+impl TestClient {
+    fn request(&mut self, command: &str, arguments: Value) -> Value {
+        self.seq += 1;
+        let seq = self.seq;
+        write_message(
+            &mut self.writer,
+            &json!({
+                "seq": seq,
+                "type": "request",
+                "command": command,
+                "arguments": arguments,
+            }),
+        )
+        .unwrap();
+        let response = self.wait_for(|msg| msg["type"] == "response" && msg["request_seq"] == seq);
+        assert_eq!(
+            response["success"], true,
+            "{} failed: {}",
+            command, response
+        );
+        response["body"].clone()
+    }
+
+    fn event(&mut self, event: &str) -> Value {
+        self.wait_for(|msg| msg["type"] == "event" && msg["event"] == event)["body"].clone()
+    }
+
+    fn wait_for(&mut self, pred: impl Fn(&Value) -> bool) -> Value {
+        loop {
+            let msg = read_message(&mut self.reader)
+                .unwrap()
+                .expect("connection closed");
+            if pred(&msg) {
+                return msg;
+            }
+        }
+    }
+
+    fn top_frame(&mut self) -> Value {
+        self.request("stackTrace", json!({ "threadId": 1 }))["stackFrames"][0].clone()
+    }
+}
+
+fn variable<'a>(variables: &'a Value, name: &str) -> &'a Value {
+    variables["variables"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|var| var["name"] == name)
+        .unwrap_or_else(|| panic!("no variable {} in {}", name, variables))
+}
+
+#[test]
+fn debug_unit_test() {
+    let dir = tempfile::tempdir().unwrap();
+    let package_path = dir.path();
+    fs::write(package_path.join("Move.toml"), move_toml()).unwrap();
+    fs::create_dir(package_path.join("sources")).unwrap();
+    let source_path = package_path.join("sources").join("M.move");
+    fs::write(&source_path, SOURCE).unwrap();
+
+    let listener = TcpListener::bind(("127.0.0.1", 0)).unwrap();
+    let addr = listener.local_addr().unwrap();
+    let server = thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        move_dap::serve(stream.try_clone().unwrap(), stream).unwrap();
+    });
+    let stream = TcpStream::connect(addr).unwrap();
+    let mut client = TestClient {
+        reader: BufReader::new(stream.try_clone().unwrap()),
+        writer: stream,
+        seq: 0,
+    };
+
+    client.request("initialize", json!({ "adapterID": "move" }));
+    client.request(
+        "launch",
+        json!({ "packagePath": package_path, "mode": "test" }),
+    );
+    client.event("initialized");
+
+    // a breakpoint on a line without code moves to the next line with code
+    let breakpoints = client.request(
+        "setBreakpoints",
+        json!({
+            "source": { "path": source_path },
+            "breakpoints": [{ "line": BREAKPOINT_LINE - 2 }],
+        }),
+    );
+    assert_eq!(breakpoints["breakpoints"][0]["verified"], true);
+    assert_eq!(breakpoints["breakpoints"][0]["line"], BREAKPOINT_LINE);
+    client.request("configurationDone", Value::Null);
+
+    assert_eq!(client.event("stopped")["reason"], "breakpoint");
+    let stack = client.request("stackTrace", json!({ "threadId": 1 }));
+    let frames = stack["stackFrames"].as_array().unwrap();
+    assert_eq!(frames.len(), 2);
+    assert_eq!(frames[0]["name"], "0x2::M::sum");
+    assert_eq!(frames[0]["line"], BREAKPOINT_LINE);
+    assert_eq!(frames[1]["name"], "0x2::M::test_sum");
+    assert_eq!(frames[1]["line"], 12);
+    assert_eq!(
+        Path::new(frames[0]["source"]["path"].as_str().unwrap()),
+        source_path.canonicalize().unwrap()
+    );
+
+    // `p` is a reference to a struct, expanded into its fields
+    let scopes = client.request("scopes", json!({ "frameId": 1 }));
+    let locals = client.request(
+        "variables",
+        json!({ "variablesReference": scopes["scopes"][0]["variablesReference"] }),
+    );
+    let p = variable(&locals, "p");
+    assert_eq!(p["type"], "&0x2::M::Point");
+    let fields = client.request(
+        "variables",
+        json!({ "variablesReference": p["variablesReference"] }),
+    );
+    assert_eq!(variable(&fields, "x")["value"], "1u64");
+    assert_eq!(variable(&fields, "y")["value"], "2u64");
+
+    let result = client.request("evaluate", json!({ "expression": "p", "frameId": 2 }));
+    assert_eq!(result["type"], "0x2::M::Point");
+    assert!(result["variablesReference"].as_u64().unwrap() > 0);
+
+    client.request("next", json!({ "threadId": 1 }));
+    assert_eq!(client.event("stopped")["reason"], "step");
+    assert_eq!(client.top_frame()["line"], BREAKPOINT_LINE + 1);
+    let locals = client.request("variables", json!({ "variablesReference": 1 }));
+    assert_eq!(variable(&locals, "s")["value"], "3u64");
+
+    client.request("stepOut", json!({ "threadId": 1 }));
+    assert_eq!(client.event("stopped")["reason"], "step");
+    let frame = client.top_frame();
+    assert_eq!(frame["name"], "0x2::M::test_sum");
+    assert_eq!(frame["line"], 12);
+
+    client.request("continue", json!({ "threadId": 1 }));
+    assert_eq!(client.event("exited")["exitCode"], 0);
+    client.event("terminated");
+    client.request("disconnect", Value::Null);
+    server.join().unwrap();
+}
@@ -589,10 +589,18 @@ impl CompiledPackage {
                 compiled_unit::CompiledUnitEnum::Module(m) => m.named_module.package_name.unwrap(),
                 compiled_unit::CompiledUnitEnum::Script(s) => s.named_script.package_name.unwrap(),
             };
-            let unit = CompiledUnitWithSource {
-                unit: annot_unit.into_compiled_unit(),
-                source_path,
-            };
+            let mut unit = annot_unit.into_compiled_unit();
+            // Modules carry the upgrade policy declared by their package in their metadata
+            let upgrade_policy = resolution_graph.package_table[&package_name]
+                .source_package
+                .package
+                .upgrade_policy;
+            if let (compiled_unit::CompiledUnitEnum::Module(m), Some(policy)) =
+                (&mut unit, upgrade_policy)
+            {
+                policy.set_in_module(&mut m.module);
+            }
+            let unit = CompiledUnitWithSource { unit, source_path };
             if package_name == root_package_name {
                 root_compiled_units.push(unit)
             } else {
@@ -111,7 +111,7 @@ pub fn parse_package_info(tval: TV) -> Result<PM::PackageInfo> {
         TV::Table(mut table) => {
             check_for_required_field_names(&table, &["name", "version"])?;
             let hook_names = package_hooks::custom_package_info_fields();
-            let known_names = ["name", "version", "authors", "license"]
+            let known_names = ["name", "version", "authors", "license", "upgrade_policy"]
                 .into_iter()
                 .chain(hook_names.iter().map(|s| s.as_str()))
                 .collect::<Vec<_>>();
@@ -128,6 +128,15 @@ pub fn parse_package_info(tval: TV) -> Result<PM::PackageInfo> {
             let name = PM::PackageName::from(name);
             let version = parse_version(version)?;
             let license = table.remove("license").map(|x| Symbol::from(x.to_string()));
+            let upgrade_policy = match table.remove("upgrade_policy") {
+                None => None,
+                Some(policy) => Some(
+                    policy
+                        .as_str()
+                        .ok_or_else(|| format_err!("Upgrade policy must be a string"))?
+                        .parse()?,
+                ),
+            };
             let authors = match table.remove("authors") {
                 None => Vec::new(),
                 Some(arr) => {
@@ -165,6 +174,7 @@ pub fn parse_package_info(tval: TV) -> Result<PM::PackageInfo> {
                 version,
                 authors,
                 license,
+                upgrade_policy,
                 custom_properties,
             })
         }
@@ -3,6 +3,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::Architecture;
+use move_binary_format::upgrade_policy::UpgradePolicy;
 use move_core_types::account_address::AccountAddress;
 use move_symbol_pool::symbol::Symbol;
 use std::{collections::BTreeMap, path::PathBuf};
@@ -34,6 +35,8 @@ pub struct PackageInfo {
     pub version: Version,
     pub authors: Vec<Symbol>,
     pub license: Option<Symbol>,
+    /// The upgrade policy of the modules of the package, stored in their metadata.
+    pub upgrade_policy: Option<UpgradePolicy>,
     pub custom_properties: BTreeMap<Symbol, String>,
 }
 
@@ -15,6 +15,7 @@ use move_binary_format::{
 use move_bytecode_utils::layout::TypeLayoutBuilder;
 use move_core_types::{
     account_address::AccountAddress,
+    effects::Event,
     identifier::{IdentStr, Identifier},
     language_storage::{ModuleId, StructTag, TypeTag},
     resolver::MoveResolver,
@@ -71,6 +72,16 @@ impl AnnotatedMoveValue {
     }
 }
 
+/// An event emitted by a session, with its payload annotated.
+#[derive(Clone, Debug)]
+pub struct AnnotatedEvent {
+    /// The key of the event stream the event was emitted to.
+    pub key: Vec<u8>,
+    /// The position of the event in its stream.
+    pub sequence_number: u64,
+    pub payload: AnnotatedMoveValue,
+}
+
 pub struct MoveValueAnnotator<'a, T: ?Sized> {
     cache: Resolver<'a, T>,
 }
@@ -138,6 +149,38 @@ impl<'a, T: MoveResolver + ?Sized> MoveValueAnnotator<'a, T> {
         self.annotate_struct(&move_struct, &ty)
     }
 
+    /// Annotate an event, whose payload may be of any type.
+    pub fn view_event(&self, event: &Event) -> Result<AnnotatedEvent> {
+        let (key, sequence_number, ty, data) = event;
+        Ok(AnnotatedEvent {
+            key: key.clone(),
+            sequence_number: *sequence_number,
+            payload: self.view_value(ty, data)?,
+        })
+    }
+
+    /// Annotate the events whose payload is of type `tag`. A tag without type arguments matches
+    /// all the instantiations of a generic struct.
+    pub fn view_events_of_type<'e>(
+        &self,
+        events: impl IntoIterator<Item = &'e Event>,
+        tag: &StructTag,
+    ) -> Result<Vec<AnnotatedEvent>> {
+        events
+            .into_iter()
+            .filter(|(_, _, ty, _)| match ty {
+                TypeTag::Struct(event_tag) => {
+                    event_tag.address == tag.address
+                        && event_tag.module == tag.module
+                        && event_tag.name == tag.name
+                        && (tag.type_params.is_empty() || event_tag.type_params == tag.type_params)
+                }
+                _ => false,
+            })
+            .map(|event| self.view_event(event))
+            .collect()
+    }
+
     pub fn move_struct_fields(
         &self,
         tag: &StructTag,
@@ -359,3 +402,15 @@ impl Display for AnnotatedMoveStruct {
         pretty_print_struct(f, self, 0)
     }
 }
+
+impl Display for AnnotatedEvent {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "event #{} of stream {}: ",
+            self.sequence_number,
+            hex::encode(&self.key)
+        )?;
+        pretty_print_value(f, &self.payload, 0)
+    }
+}
@@ -6,6 +6,7 @@
 //! Such extensions are enabled by cfg features and must be compiled into the test
 //! to be usable.
 
+use move_stdlib::natives::random::NativeRandomContext;
 use move_vm_runtime::native_extensions::NativeContextExtensions;
 use once_cell::sync::Lazy;
 use std::{fmt::Write, sync::Mutex};
@@ -31,19 +32,20 @@ static EXTENSION_HOOK: Lazy<
 /// (b) Before `cli::run_move_unit_tests` if unit tests are called programmatically from Rust.
 /// You may want to define a new function `my_cli::run_move_unit_tests` which does this.
 ///
-/// Note that the table extension is handled already internally, and does not need to added via
-/// this hook.
+/// Note that the table and random extensions are handled already internally, and do not need to
+/// be added via this hook.
 pub fn set_extension_hook(p: Box<dyn Fn(&mut NativeContextExtensions<'_>) + Send + Sync>) {
     *EXTENSION_HOOK.lock().unwrap() = Some(p)
 }
 
-/// Create all available native context extensions.
+/// Create all available native context extensions, with `seed` for the random extension.
 #[allow(unused_mut, clippy::let_and_return)]
-pub(crate) fn new_extensions<'a>() -> NativeContextExtensions<'a> {
+pub(crate) fn new_extensions<'a>(seed: u64) -> NativeContextExtensions<'a> {
     let mut e = NativeContextExtensions::default();
     if let Some(h) = &*EXTENSION_HOOK.lock().unwrap() {
         (*h)(&mut e)
     }
+    e.add(NativeRandomContext::new(seed));
     #[cfg(feature = "table-extension")]
     create_table_extension(&mut e);
     e
@@ -109,7 +111,7 @@ mod tests {
     #[test]
     fn test_extension_hook() {
         set_extension_hook(Box::new(my_hook));
-        let ext = new_extensions();
+        let ext = new_extensions(0);
         let _e = ext.get::<TestExtension>();
     }
 
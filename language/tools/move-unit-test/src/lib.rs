@@ -106,6 +106,10 @@ pub struct UnitTestingConfig {
     #[clap(long = "stackless")]
     pub check_stackless_vm: bool,
 
+    /// Seed of the generator drawn from by `std::random`, which is reseeded for every test.
+    #[clap(long = "seed", default_value = "0")]
+    pub seed: u64,
+
     /// Verbose mode
     #[clap(short = 'v', long = "verbose")]
     pub verbose: bool,
@@ -143,6 +147,7 @@ impl UnitTestingConfig {
             source_files: vec![],
             dep_files: vec![],
             check_stackless_vm: false,
+            seed: 0,
             verbose: false,
             list: false,
             named_address_values: vec![],
@@ -243,6 +248,7 @@ impl UnitTestingConfig {
                 .unwrap_or(DEFAULT_EXECUTION_BOUND),
             self.num_threads,
             self.check_stackless_vm,
+            self.seed,
             self.verbose,
             self.report_storage_on_error,
             self.report_stacktrace_on_abort,
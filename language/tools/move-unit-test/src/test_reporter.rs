@@ -159,13 +159,21 @@ impl TestFailure {
             FailureReason::Timeout(message) => message.to_string(),
             FailureReason::WrongAbort(message, expected_code, other_code) => {
                 let base_message = format!(
-                    "{}. Expected test to abort with {} but instead it aborted with {} here",
-                    message, expected_code, other_code,
+                    "{}. Expected test to abort with {} but instead it aborted with {}{} here",
+                    message,
+                    expected_code,
+                    other_code,
+                    self.abort_message_suffix(),
                 );
                 Self::report_error_with_location(test_plan, base_message, &self.vm_error)
             }
             FailureReason::Aborted(message, code) => {
-                let base_message = format!("{} but it aborted with {} here", message, code);
+                let base_message = format!(
+                    "{} but it aborted with {}{} here",
+                    message,
+                    code,
+                    self.abort_message_suffix()
+                );
                 Self::report_error_with_location(test_plan, base_message, &self.vm_error)
             }
             FailureReason::Mismatch {
@@ -229,6 +237,14 @@ impl TestFailure {
         }
     }
 
+    // The message attached to the abort, if any, e.g. by `std::error::abort_with_message`.
+    fn abort_message_suffix(&self) -> String {
+        match self.vm_error.as_ref().and_then(|err| err.abort_message()) {
+            Some(message) => format!(" and message {:?}", message),
+            None => "".to_string(),
+        }
+    }
+
     fn get_line_number(
         loc: &Loc,
         files: &SimpleFiles<Symbol, &str>,
@@ -62,6 +62,7 @@ pub struct SharedTestingConfig {
     source_files: Vec<String>,
     named_address_values: BTreeMap<String, NumericalAddress>,
     check_stackless_vm: bool,
+    seed: u64,
     verbose: bool,
     record_writeset: bool,
 
@@ -137,6 +138,7 @@ impl TestRunner {
         execution_bound: u64,
         num_threads: usize,
         check_stackless_vm: bool,
+        seed: u64,
         verbose: bool,
         save_storage_state_on_failure: bool,
         report_stacktrace_on_abort: bool,
@@ -176,6 +178,7 @@ impl TestRunner {
                 cost_table: unit_cost_table(),
                 source_files,
                 check_stackless_vm,
+                seed,
                 verbose,
                 named_address_values,
                 record_writeset,
@@ -277,7 +280,7 @@ impl SharedTestingConfig {
         TestRunInfo,
     ) {
         let move_vm = MoveVM::new(self.native_function_table.clone()).unwrap();
-        let extensions = extensions::new_extensions();
+        let extensions = extensions::new_extensions(self.seed);
         let mut session =
             move_vm.new_session_with_extensions(&self.starting_storage_state, extensions);
         let mut gas_meter = GasStatus::new(&self.cost_table, Gas::new(self.execution_bound));
@@ -0,0 +1,20 @@
+module 0x1::A {
+    use std::error;
+
+    #[test]
+    fun abort_with_message_unexpected_abort() {
+        error::abort_with_message(1, b"something went wrong");
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0)]
+    fun abort_with_message_wrong_code() {
+        error::abort_with_message(1, b"something went wrong");
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 1)]
+    fun abort_with_message_right_code() {
+        error::abort_with_message(1, b"something went wrong");
+    }
+}